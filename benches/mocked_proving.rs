@@ -2,7 +2,10 @@ use criterion::{
     criterion_group, criterion_main, BatchSize, BenchmarkGroup, BenchmarkId, Criterion, Throughput,
 };
 use criterion::measurement::WallTime;
-use blake2b_halo2::usage_utils::circuit_runner::CircuitRunner;
+use blake2b_halo2::usage_utils::circuit_runner::KzgCircuitRunner;
+use midnight_curves::bls12_381::Bls12;
+#[cfg(feature = "dev-curves")]
+use midnight_curves::bn256::Bn256;
 
 pub mod utils;
 use utils::*;
@@ -11,18 +14,28 @@ criterion_group!(mocked_prover, benchmark_mocked_proving);
 criterion_main!(mocked_prover);
 
 pub fn benchmark_mocked_proving(c: &mut Criterion) {
-    let mut group = c.benchmark_group("optimization_comparison");
+    benchmark_mocked_proving_over::<Bls12>(c, "optimization_comparison");
+    #[cfg(feature = "dev-curves")]
+    benchmark_mocked_proving_over::<Bn256>(c, "optimization_comparison_bn254");
+}
+
+fn benchmark_mocked_proving_over<E: BenchmarkEngine>(c: &mut Criterion, group_name: &str) {
+    let mut group = c.benchmark_group(group_name);
     configure_group(&mut group);
 
     for amount_of_blocks in benchmarking_block_sizes() {
         group.throughput(Throughput::Bytes(amount_of_blocks as u64));
 
-        benchmark_optimization_with_amount_of_blocks(&mut group, amount_of_blocks, "opt_recycle");
+        benchmark_optimization_with_amount_of_blocks::<E>(
+            &mut group,
+            amount_of_blocks,
+            "opt_recycle",
+        );
     }
     group.finish()
 }
 
-fn benchmark_optimization_with_amount_of_blocks(
+fn benchmark_optimization_with_amount_of_blocks<E: BenchmarkEngine>(
     group: &mut BenchmarkGroup<WallTime>,
     amount_of_blocks: usize,
     optimization_name: &str,
@@ -31,11 +44,11 @@ fn benchmark_optimization_with_amount_of_blocks(
         b.iter_batched(
             || {
                 let ci = random_input_for_desired_blocks(amount_of_blocks);
-                let circuit = CircuitRunner::create_circuit_for_packed_inputs(ci.clone());
+                let circuit = KzgCircuitRunner::<E>::create_circuit_for_packed_inputs(ci.clone());
                 (circuit, ci.4)
             },
             |(circuit, expected)| {
-                CircuitRunner::mock_prove_with_public_inputs_ref(&expected, &circuit)
+                KzgCircuitRunner::<E>::mock_prove_with_public_inputs_ref(&expected, &circuit)
             },
             BatchSize::SmallInput,
         )
@@ -1,7 +1,9 @@
-use blake2b_halo2::usage_utils::circuit_runner::CircuitRunner;
+use blake2b_halo2::usage_utils::circuit_runner::KzgCircuitRunner;
 use criterion::measurement::WallTime;
 use criterion::{criterion_group, criterion_main, BenchmarkGroup, BenchmarkId, Criterion, Throughput};
 use midnight_curves::bls12_381::Bls12;
+#[cfg(feature = "dev-curves")]
+use midnight_curves::bn256::Bn256;
 use midnight_proofs::poly::kzg::params::ParamsKZG;
 
 pub mod utils;
@@ -11,10 +13,16 @@ criterion_group!(pk, benchmark_proving_key_generation);
 criterion_main!(pk);
 
 pub fn benchmark_proving_key_generation(c: &mut Criterion) {
-    let mut group = c.benchmark_group("proving_key");
+    benchmark_proving_key_generation_over::<Bls12>(c, "proving_key");
+    #[cfg(feature = "dev-curves")]
+    benchmark_proving_key_generation_over::<Bn256>(c, "proving_key_bn254");
+}
+
+fn benchmark_proving_key_generation_over<E: BenchmarkEngine>(c: &mut Criterion, group_name: &str) {
+    let mut group = c.benchmark_group(group_name);
     configure_group(&mut group);
 
-    let params = ParamsKZG::<Bls12>::unsafe_setup(17, &mut rand::thread_rng());
+    let params = ParamsKZG::<E>::unsafe_setup(17, &mut rand::thread_rng());
 
     for amount_of_blocks in benchmarking_block_sizes() {
         group.throughput(Throughput::Bytes(amount_of_blocks as u64));
@@ -24,17 +32,17 @@ pub fn benchmark_proving_key_generation(c: &mut Criterion) {
     group.finish()
 }
 
-fn benchmark_proving_key(
-    params: &ParamsKZG<Bls12>,
+fn benchmark_proving_key<E: BenchmarkEngine>(
+    params: &ParamsKZG<E>,
     group: &mut BenchmarkGroup<WallTime>,
     amount_of_blocks: usize,
     name: &str,
 ) {
     let ci = random_input_for_desired_blocks(amount_of_blocks);
-    let circuit = CircuitRunner::create_circuit_for_packed_inputs(ci);
-    let vk = CircuitRunner::create_vk(&circuit, params);
+    let circuit = KzgCircuitRunner::<E>::create_circuit_for_packed_inputs(ci);
+    let vk = KzgCircuitRunner::create_vk(&circuit, params);
 
     group.bench_function(BenchmarkId::new(name, amount_of_blocks), |b| {
-        b.iter(|| CircuitRunner::create_pk(&circuit, vk.clone()))
+        b.iter(|| KzgCircuitRunner::create_pk(&circuit, vk.clone()))
     });
 }
@@ -1,7 +1,9 @@
-use blake2b_halo2::usage_utils::circuit_runner::CircuitRunner;
+use blake2b_halo2::usage_utils::circuit_runner::KzgCircuitRunner;
 use criterion::measurement::WallTime;
 use criterion::{criterion_group, criterion_main, BenchmarkGroup, BenchmarkId, Criterion, Throughput};
 use midnight_curves::bls12_381::Bls12;
+#[cfg(feature = "dev-curves")]
+use midnight_curves::bn256::Bn256;
 use midnight_proofs::poly::kzg::params::ParamsKZG;
 
 pub mod utils;
@@ -11,10 +13,16 @@ criterion_group!(proof, benchmark_proof_generation);
 criterion_main!(proof);
 
 pub fn benchmark_proof_generation(c: &mut Criterion) {
-    let mut group = c.benchmark_group("proof");
+    benchmark_proof_generation_over::<Bls12>(c, "proof");
+    #[cfg(feature = "dev-curves")]
+    benchmark_proof_generation_over::<Bn256>(c, "proof_bn254");
+}
+
+fn benchmark_proof_generation_over<E: BenchmarkEngine>(c: &mut Criterion, group_name: &str) {
+    let mut group = c.benchmark_group(group_name);
     configure_group(&mut group);
 
-    let params = ParamsKZG::<Bls12>::unsafe_setup(17, &mut rand::thread_rng());
+    let params = ParamsKZG::<E>::unsafe_setup(17, &mut rand::thread_rng());
 
     for amount_of_blocks in benchmarking_block_sizes() {
         group.throughput(Throughput::Bytes(amount_of_blocks as u64));
@@ -24,8 +32,8 @@ pub fn benchmark_proof_generation(c: &mut Criterion) {
     group.finish()
 }
 
-fn benchmark_proof(
-    params: &ParamsKZG<Bls12>,
+fn benchmark_proof<E: BenchmarkEngine>(
+    params: &ParamsKZG<E>,
     group: &mut BenchmarkGroup<WallTime>,
     amount_of_blocks: usize,
     name: &str,
@@ -33,13 +41,13 @@ fn benchmark_proof(
     let ci = random_input_for_desired_blocks(amount_of_blocks);
     let expected_output_fields = ci.4;
 
-    let circuit = CircuitRunner::create_circuit_for_packed_inputs(ci);
-    let vk = CircuitRunner::create_vk(&circuit, params);
-    let pk = CircuitRunner::create_pk(&circuit, vk.clone());
+    let circuit = KzgCircuitRunner::<E>::create_circuit_for_packed_inputs(ci);
+    let vk = KzgCircuitRunner::create_vk(&circuit, params);
+    let pk = KzgCircuitRunner::create_pk(&circuit, vk.clone());
 
     group.bench_function(BenchmarkId::new(name, amount_of_blocks), |b| {
         b.iter(|| {
-            CircuitRunner::create_proof(&expected_output_fields, circuit.clone(), params, &pk)
+            KzgCircuitRunner::create_proof(&expected_output_fields, circuit.clone(), params, &pk)
         })
     });
 }
@@ -1,10 +1,46 @@
+use std::fmt::Debug;
+use std::hash::Hash;
 use criterion::{BenchmarkGroup, SamplingMode};
 use criterion::measurement::WallTime;
+use ff::{FromUniformBytes, PrimeField, WithSmallOrderMulGroup};
 use midnight_proofs::circuit::Value;
-use midnight_curves::bls12_381::Fq;
+use midnight_proofs::transcript::{Hashable, Sampleable};
+use midnight_proofs::utils::helpers::ProcessedSerdeObject;
+use midnight_curves::bls12_381::Bls12;
+#[cfg(feature = "dev-curves")]
+use midnight_curves::bn256::Bn256;
+use midnight_curves::pairing::MultiMillerLoop;
+use midnight_curves::{CurveAffine, CurveExt};
 use rand::Rng;
 use blake2b_halo2::usage_utils::circuit_runner::Blake2bCircuitInputs;
 use blake2_rfc::blake2b::blake2b;
+use blake2b_simd::State as TranscriptHashState;
+
+/// Pairing engines the benchmarks run over, which are the ones the real prover of the
+/// [KzgCircuitRunner] works with.
+///
+/// [KzgCircuitRunner]: blake2b_halo2::usage_utils::circuit_runner::KzgCircuitRunner
+pub trait BenchmarkEngine:
+    MultiMillerLoop<
+        Fr: WithSmallOrderMulGroup<3>
+                + FromUniformBytes<64>
+                + Ord
+                + Hash
+                + Hashable<TranscriptHashState>
+                + Sampleable<TranscriptHashState>,
+        G1: Default
+                + CurveExt<ScalarExt = Self::Fr>
+                + ProcessedSerdeObject
+                + Hashable<TranscriptHashState>,
+        G1Affine: Default + CurveAffine<ScalarExt = Self::Fr, CurveExt = Self::G1>,
+    > + Debug
+{
+}
+
+impl BenchmarkEngine for Bls12 {}
+
+#[cfg(feature = "dev-curves")]
+impl BenchmarkEngine for Bn256 {}
 
 pub fn benchmarking_block_sizes() -> Vec<usize> {
     vec![1, 5, 10, 20, 30]
@@ -20,7 +56,9 @@ pub fn configure_group(group: &mut BenchmarkGroup<WallTime>) {
     //group.measurement_time(Duration::from_secs(1000));
 }
 
-pub fn random_input_for_desired_blocks(amount_of_blocks: usize) -> Blake2bCircuitInputs {
+pub fn random_input_for_desired_blocks<F: PrimeField>(
+    amount_of_blocks: usize,
+) -> Blake2bCircuitInputs<F> {
     let mut rng = rand::thread_rng();
 
     let input_size = amount_of_blocks * 128;
@@ -32,12 +70,12 @@ pub fn random_input_for_desired_blocks(amount_of_blocks: usize) -> Blake2bCircui
 
     let hash_result = run_blake2b(random_inputs, key, output_size);
 
-    let expected_output_: Vec<Fq> = hash_result.iter().map(|byte| Fq::from(*byte as u64)).collect();
-    let expected_output: [Fq; OUTPUT_SIZE] = expected_output_.try_into().unwrap();
-    let input_values: Vec<Value<Fq>> =
-        random_input_bytes.iter().map(|x| Value::known(Fq::from(*x as u64))).collect();
+    let expected_output_: Vec<F> = hash_result.iter().map(|byte| F::from(*byte as u64)).collect();
+    let expected_output: [F; OUTPUT_SIZE] = expected_output_.try_into().unwrap();
+    let input_values: Vec<Value<F>> =
+        random_input_bytes.iter().map(|x| Value::known(F::from(*x as u64))).collect();
     let key_size = 0;
-    let key_values: Vec<Value<Fq>> = vec![];
+    let key_values: Vec<Value<F>> = vec![];
 
     (input_values, input_size, key_values, key_size, expected_output, OUTPUT_SIZE)
 }
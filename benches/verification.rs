@@ -1,7 +1,9 @@
-use blake2b_halo2::usage_utils::circuit_runner::CircuitRunner;
+use blake2b_halo2::usage_utils::circuit_runner::KzgCircuitRunner;
 use criterion::measurement::WallTime;
 use criterion::{criterion_group, criterion_main, BenchmarkGroup, BenchmarkId, Criterion, Throughput};
 use midnight_curves::bls12_381::Bls12;
+#[cfg(feature = "dev-curves")]
+use midnight_curves::bn256::Bn256;
 use midnight_proofs::poly::kzg::params::ParamsKZG;
 
 pub mod utils;
@@ -11,10 +13,16 @@ criterion_group!(verify, benchmark_verification);
 criterion_main!(verify);
 
 pub fn benchmark_verification(c: &mut Criterion) {
-    let mut group = c.benchmark_group("verify");
+    benchmark_verification_over::<Bls12>(c, "verify");
+    #[cfg(feature = "dev-curves")]
+    benchmark_verification_over::<Bn256>(c, "verify_bn254");
+}
+
+fn benchmark_verification_over<E: BenchmarkEngine>(c: &mut Criterion, group_name: &str) {
+    let mut group = c.benchmark_group(group_name);
     configure_group(&mut group);
 
-    let params = ParamsKZG::<Bls12>::unsafe_setup(17, &mut rand::thread_rng());
+    let params = ParamsKZG::<E>::unsafe_setup(17, &mut rand::thread_rng());
 
     for amount_of_blocks in benchmarking_block_sizes() {
         group.throughput(Throughput::Bytes(amount_of_blocks as u64));
@@ -24,8 +32,8 @@ pub fn benchmark_verification(c: &mut Criterion) {
     group.finish()
 }
 
-fn benchmark_verification_iteration(
-    params: &ParamsKZG<Bls12>,
+fn benchmark_verification_iteration<E: BenchmarkEngine>(
+    params: &ParamsKZG<E>,
     group: &mut BenchmarkGroup<WallTime>,
     amount_of_blocks: usize,
     name: &str,
@@ -33,12 +41,13 @@ fn benchmark_verification_iteration(
     let ci = random_input_for_desired_blocks(amount_of_blocks);
     let expected_output_fields = ci.4;
 
-    let circuit = CircuitRunner::create_circuit_for_packed_inputs(ci);
-    let vk = CircuitRunner::create_vk(&circuit, params);
-    let pk = CircuitRunner::create_pk(&circuit, vk.clone());
-    let proof = CircuitRunner::create_proof(&expected_output_fields, circuit.clone(), params, &pk);
+    let circuit = KzgCircuitRunner::<E>::create_circuit_for_packed_inputs(ci);
+    let vk = KzgCircuitRunner::create_vk(&circuit, params);
+    let pk = KzgCircuitRunner::create_pk(&circuit, vk.clone());
+    let proof =
+        KzgCircuitRunner::create_proof(&expected_output_fields, circuit.clone(), params, &pk);
 
     group.bench_function(BenchmarkId::new(name, amount_of_blocks), |b| {
-        b.iter(|| CircuitRunner::verify(&expected_output_fields, params, pk.clone(), &proof))
+        b.iter(|| KzgCircuitRunner::verify(&expected_output_fields, params, pk.clone(), &proof))
     });
 }
@@ -1,9 +1,11 @@
-use blake2b_halo2::usage_utils::circuit_runner::CircuitRunner;
+use blake2b_halo2::usage_utils::circuit_runner::KzgCircuitRunner;
 use criterion::measurement::WallTime;
 use criterion::{
     criterion_group, criterion_main, BatchSize, BenchmarkGroup, BenchmarkId, Criterion, Throughput,
 };
 use midnight_curves::bls12_381::Bls12;
+#[cfg(feature = "dev-curves")]
+use midnight_curves::bn256::Bn256;
 use midnight_proofs::poly::kzg::params::ParamsKZG;
 
 pub mod utils;
@@ -13,10 +15,19 @@ criterion_group!(vk, benchmark_verification_key_generation);
 criterion_main!(vk);
 
 pub fn benchmark_verification_key_generation(c: &mut Criterion) {
-    let mut group = c.benchmark_group("verification_key");
+    benchmark_verification_key_generation_over::<Bls12>(c, "verification_key");
+    #[cfg(feature = "dev-curves")]
+    benchmark_verification_key_generation_over::<Bn256>(c, "verification_key_bn254");
+}
+
+fn benchmark_verification_key_generation_over<E: BenchmarkEngine>(
+    c: &mut Criterion,
+    group_name: &str,
+) {
+    let mut group = c.benchmark_group(group_name);
     configure_group(&mut group);
 
-    let params = ParamsKZG::<Bls12>::unsafe_setup(17, &mut rand::thread_rng());
+    let params = ParamsKZG::<E>::unsafe_setup(17, &mut rand::thread_rng());
 
     for amount_of_blocks in benchmarking_block_sizes() {
         group.throughput(Throughput::Bytes(amount_of_blocks as u64));
@@ -26,8 +37,8 @@ pub fn benchmark_verification_key_generation(c: &mut Criterion) {
     group.finish()
 }
 
-fn benchmark_verification_key(
-    params: &ParamsKZG<Bls12>,
+fn benchmark_verification_key<E: BenchmarkEngine>(
+    params: &ParamsKZG<E>,
     group: &mut BenchmarkGroup<WallTime>,
     amount_of_blocks: usize,
     name: &str,
@@ -36,9 +47,9 @@ fn benchmark_verification_key(
         b.iter_batched(
             || {
                 let ci = random_input_for_desired_blocks(amount_of_blocks);
-                CircuitRunner::create_circuit_for_packed_inputs(ci.clone())
+                KzgCircuitRunner::<E>::create_circuit_for_packed_inputs(ci.clone())
             },
-            |circuit| CircuitRunner::create_vk(&circuit, params),
+            |circuit| KzgCircuitRunner::create_vk(&circuit, params),
             BatchSize::SmallInput,
         )
     });
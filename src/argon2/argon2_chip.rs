@@ -0,0 +1,513 @@
+use crate::argon2::params::Argon2Params;
+use crate::argon2::utils::{
+    data_independent_pseudo_rands, le32, permutation_indexes, reference_area_size,
+    reference_start_position, relative_position, ARGON2ID_TYPE, ARGON2_BLOCK_SIZE, ARGON2_LANES,
+    ARGON2_PREHASH_DIGEST_LENGTH, ARGON2_QWORDS_IN_BLOCK, ARGON2_SYNC_POINTS, ARGON2_VERSION,
+};
+use crate::base_operations::blamka::BlaMkaConfig;
+use crate::base_operations::reference_index::ReferenceIndexConfig;
+use crate::blake2b::blake2b_chip::{Blake2bChip, Blake2bConfig};
+use crate::blake2b::utils::{ABCD, BLAKE2B_BLOCK_SIZE};
+use crate::blake2b::NB_BLAKE2B_ADVICE_COLS;
+use crate::types::blake2b_word::AssignedBlake2bWord;
+use crate::types::byte::AssignedByte;
+use crate::types::row::AssignedRow;
+use crate::types::AssignedNative;
+use ff::PrimeField;
+use midnight_proofs::circuit::{Chip, Layouter, Region};
+use midnight_proofs::plonk::{Advice, Column, ConstraintSystem, Error, Fixed};
+
+/// A block of the memory of Argon2, made of 128 words of 64 bits.
+type Argon2Block<F> = [AssignedBlake2bWord<F>; ARGON2_QWORDS_IN_BLOCK];
+
+/// Selectors and columns for the Argon2 chip implementation.
+#[derive(Clone, Debug)]
+pub struct Argon2Config {
+    /// Config of the Blake2b chip, which is used for the H and H' functions and provides the
+    /// decomposition, xor, rotation and selection base operations.
+    blake2b_config: Blake2bConfig,
+    /// Base operations configs that are specific to Argon2
+    blamka_config: BlaMkaConfig,
+    reference_index_config: ReferenceIndexConfig,
+    /// Columns for the limbs, shared with the Blake2b chip.
+    pub limbs: [Column<Advice>; 8],
+}
+
+/// This is the chip for the Argon2id memory-hard function, as defined in RFC 9106, with a single
+/// lane. It proves that a tag is the Argon2id hash of a password and a salt, for parameters known
+/// at circuit building time.
+///
+/// The Blake2b hashes H and H' of the specification are computed with the [Blake2bChip], and the
+/// compression function G is computed over the same trace: it's the round of Blake2b without
+/// message words, where the additions are replaced by the BlaMka step of the [BlaMkaConfig].
+/// The reference blocks of the first half of the first pass are computed outside the circuit,
+/// since they don't depend on the password. The rest of the reference blocks depend on the
+/// previous block, so they're selected among all the possible ones with the bits computed by the
+/// [ReferenceIndexConfig], which makes every block cost a selection per word for every block of
+/// the reference area. The size of the circuit grows with the memory size and the amount of
+/// iterations, so the chip is meant for small memory sizes, of at most 256 blocks.
+#[derive(Clone, Debug)]
+pub struct Argon2Chip<F: PrimeField> {
+    config: Argon2Config,
+    blake2b_chip: Blake2bChip<F>,
+}
+
+impl<F: PrimeField> Chip<F> for Argon2Chip<F> {
+    type Config = Argon2Config;
+    type Loaded = ();
+
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+impl<F: PrimeField> Argon2Chip<F> {
+    /// Generation of a fresh circuit from a configuration.
+    pub fn new(config: &Argon2Config) -> Self {
+        Self {
+            config: config.clone(),
+            blake2b_chip: Blake2bChip::new(&config.blake2b_config),
+        }
+    }
+
+    /// Configuration of the circuit, this includes the configuration of the Blake2b chip and the
+    /// gates of the compression function of Argon2. It should be called in the configuration of
+    /// the user circuit before instantiating the Argon2 gadget.
+    ///
+    /// Note: as in the Blake2b chip, this function enables equality on all necessary columns,
+    /// i.e., it should not be done manually before calling this function.
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        constants: Column<Fixed>,
+        full_number_u64: Column<Advice>,
+        limbs: [Column<Advice>; NB_BLAKE2B_ADVICE_COLS - 1],
+    ) -> <Self as Chip<F>>::Config {
+        let blake2b_config = Blake2bChip::configure(meta, constants, full_number_u64, limbs);
+        let blamka_config = BlaMkaConfig::configure(
+            meta,
+            full_number_u64,
+            limbs,
+            blake2b_config.q_decompose,
+            blake2b_config.q_range,
+        );
+        let reference_index_config = ReferenceIndexConfig::configure(
+            meta,
+            full_number_u64,
+            limbs,
+            blake2b_config.q_decompose,
+            blake2b_config.q_range,
+        );
+
+        Argon2Config {
+            blake2b_config,
+            blamka_config,
+            reference_index_config,
+            limbs,
+        }
+    }
+
+    /// Loading the tables used in the chip, which are the ones of the Blake2b chip.
+    pub fn load(&self, layouter: &mut impl Layouter<F>) -> Result<(), Error> {
+        self.blake2b_chip.load(layouter)
+    }
+
+    /// Main method of the chip. It computes the Argon2id tag of the given password and salt, with
+    /// the given parameters and without secret value nor associated data. The 'password' and
+    /// 'salt' cells should be filled with byte values, and their lengths must be known at circuit
+    /// building time. The tag has 'params.tag_length' bytes.
+    pub fn hash(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        password: &[AssignedNative<F>],
+        salt: &[AssignedNative<F>],
+        params: &Argon2Params,
+    ) -> Result<Vec<AssignedByte<F>>, Error> {
+        let initial_hash = self.initial_hash(layouter, password, salt, params)?;
+        let memory_blocks = params.memory_blocks();
+        let segment_length = params.segment_length();
+
+        // The first two blocks of the lane are computed with H' from the initial hash
+        let mut memory: Vec<Argon2Block<F>> = Vec::with_capacity(memory_blocks);
+        for position in 0..2 {
+            let mut input = initial_hash.clone();
+            input.extend(
+                self.blake2b_chip
+                    .assign_constant_bytes(layouter, &[le32(position), le32(0)].concat())?,
+            );
+            let block_bytes = self.variable_length_hash(layouter, &input, ARGON2_BLOCK_SIZE)?;
+            memory.push(self.block_from_bytes(layouter, &block_bytes)?);
+        }
+
+        for pass in 0..params.iterations as usize {
+            for slice in 0..ARGON2_SYNC_POINTS {
+                // Argon2id uses data-independent addressing in the first half of the first pass
+                let pseudo_rands = (pass == 0 && slice < ARGON2_SYNC_POINTS / 2)
+                    .then(|| data_independent_pseudo_rands(params, pass, slice));
+                for index in 0..segment_length {
+                    let position = slice * segment_length + index;
+                    if pass == 0 && position < 2 {
+                        continue;
+                    }
+                    let previous_position = (position + memory_blocks - 1) % memory_blocks;
+                    let area_size = reference_area_size(params, pass, slice, index);
+                    let start_position = reference_start_position(params, pass, slice);
+
+                    let reference_block = match &pseudo_rands {
+                        Some(pseudo_rands) => {
+                            let relative = relative_position(area_size, pseudo_rands[index]);
+                            memory[(start_position + relative) % memory_blocks].clone()
+                        }
+                        None => {
+                            // The candidate at index y is the one selected when the mapping of
+                            // the first word of the previous block results in y
+                            let candidates: Vec<&Argon2Block<F>> = (0..area_size)
+                                .map(|y| {
+                                    &memory[(start_position + area_size - 1 - y) % memory_blocks]
+                                })
+                                .collect();
+                            self.select_reference_block(
+                                layouter,
+                                &memory[previous_position][0],
+                                area_size,
+                                &candidates,
+                            )?
+                        }
+                    };
+
+                    // From the second pass on, the new block is xored with the one it replaces
+                    let block = self.compress_blocks(
+                        layouter,
+                        &memory[previous_position],
+                        &reference_block,
+                        (pass > 0).then(|| &memory[position]),
+                    )?;
+                    if pass == 0 {
+                        memory.push(block);
+                    } else {
+                        memory[position] = block;
+                    }
+                }
+            }
+        }
+
+        let last_block_bytes = self.block_to_bytes(layouter, &memory[memory_blocks - 1])?;
+        self.variable_length_hash(layouter, &last_block_bytes, params.tag_length)
+    }
+}
+
+impl<F: PrimeField> Argon2Chip<F> {
+    /// Computes the initial hash H0 of the specification, a 64-byte Blake2b digest of the
+    /// parameters, the password and the salt.
+    fn initial_hash(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        password: &[AssignedNative<F>],
+        salt: &[AssignedNative<F>],
+        params: &Argon2Params,
+    ) -> Result<Vec<AssignedNative<F>>, Error> {
+        let parameter_bytes = [
+            le32(ARGON2_LANES),
+            le32(params.tag_length),
+            le32(params.memory_size as usize),
+            le32(params.iterations as usize),
+            le32(ARGON2_VERSION),
+            le32(ARGON2ID_TYPE),
+            le32(password.len()),
+        ]
+        .concat();
+        let mut input = self.blake2b_chip.assign_constant_bytes(layouter, &parameter_bytes)?;
+        input.extend_from_slice(password);
+        input.extend(self.blake2b_chip.assign_constant_bytes(layouter, &le32(salt.len()))?);
+        input.extend_from_slice(salt);
+        // The lengths of the empty secret value and associated data
+        input.extend(
+            self.blake2b_chip.assign_constant_bytes(layouter, &[le32(0), le32(0)].concat())?,
+        );
+
+        let digest = self.blake2b_chip.hash(
+            layouter,
+            &input,
+            &[],
+            ARGON2_PREHASH_DIGEST_LENGTH,
+            None,
+            None,
+        )?;
+        Ok(digest.iter().map(AssignedByte::to_native).collect())
+    }
+
+    /// Computes the variable-length hash function H' of the specification, which chains Blake2b
+    /// digests when the output is longer than 64 bytes.
+    fn variable_length_hash(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        input: &[AssignedNative<F>],
+        output_size: usize,
+    ) -> Result<Vec<AssignedByte<F>>, Error> {
+        let mut message = self.blake2b_chip.assign_constant_bytes(layouter, &le32(output_size))?;
+        message.extend_from_slice(input);
+        if output_size <= 64 {
+            let digest =
+                self.blake2b_chip.hash(layouter, &message, &[], output_size, None, None)?;
+            return Ok(digest[..output_size].to_vec());
+        }
+
+        // Every digest but the last one contributes its first 32 bytes to the output
+        let digests_count = output_size.div_ceil(32) - 2;
+        let mut output = Vec::with_capacity(output_size);
+        let mut digest = self.blake2b_chip.hash(layouter, &message, &[], 64, None, None)?;
+        for _ in 1..digests_count {
+            output.extend_from_slice(&digest[..32]);
+            let digest_bytes: Vec<AssignedNative<F>> =
+                digest.iter().map(AssignedByte::to_native).collect();
+            digest = self.blake2b_chip.hash(layouter, &digest_bytes, &[], 64, None, None)?;
+        }
+        output.extend_from_slice(&digest[..32]);
+
+        let last_digest_size = output_size - 32 * digests_count;
+        let digest_bytes: Vec<AssignedNative<F>> =
+            digest.iter().map(AssignedByte::to_native).collect();
+        let last_digest =
+            self.blake2b_chip.hash(layouter, &digest_bytes, &[], last_digest_size, None, None)?;
+        output.extend_from_slice(&last_digest[..last_digest_size]);
+        Ok(output)
+    }
+
+    /// Computes the compression function G over the previous block and the reference block. When
+    /// an 'overwritten' block is given, the result is xored with it, as done from the second pass
+    /// on. Every call works in its own region.
+    fn compress_blocks(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        previous: &Argon2Block<F>,
+        reference: &Argon2Block<F>,
+        overwritten: Option<&Argon2Block<F>>,
+    ) -> Result<Argon2Block<F>, Error> {
+        layouter.assign_region(
+            || "argon2 compression",
+            |mut region| {
+                let mut offset: usize = 0;
+                let xor_blocks = |lhs: &[AssignedBlake2bWord<F>],
+                                  rhs: &[AssignedBlake2bWord<F>],
+                                  region: &mut Region<'_, F>,
+                                  offset: &mut usize|
+                 -> Result<Vec<AssignedBlake2bWord<F>>, Error> {
+                    lhs.iter()
+                        .zip(rhs.iter())
+                        .map(|(x, y)| Ok(self.blake2b_chip.xor(x, y, region, offset)?.full_number))
+                        .collect()
+                };
+
+                let r = xor_blocks(previous, reference, &mut region, &mut offset)?;
+                let block_to_xor = match overwritten {
+                    Some(overwritten) => xor_blocks(&r, overwritten, &mut region, &mut offset)?,
+                    None => r.clone(),
+                };
+
+                let mut state = r;
+                for indexes in permutation_indexes() {
+                    self.permute(&mut state, indexes, &mut region, &mut offset)?;
+                }
+                let result = xor_blocks(&state, &block_to_xor, &mut region, &mut offset)?;
+                Ok(result.try_into().unwrap())
+            },
+        )
+    }
+
+    /// Applies the permutation P of the specification to the 16 words of the state at the given
+    /// indexes. It's a round of Blake2b without message words.
+    fn permute(
+        &self,
+        state: &mut [AssignedBlake2bWord<F>],
+        indexes: [usize; 16],
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+    ) -> Result<(), Error> {
+        let mut v: [AssignedBlake2bWord<F>; 16] = indexes.map(|i| state[i].clone());
+        for state_indexes in ABCD {
+            self.mix(state_indexes, &mut v, region, offset)?;
+        }
+        for (k, i) in indexes.into_iter().enumerate() {
+            state[i] = v[k].clone();
+        }
+        Ok(())
+    }
+
+    /// The mixing function GB of the specification. It's the mixing function of Blake2b without
+    /// message words, where every addition is replaced by a BlaMka step.
+    fn mix(
+        &self,
+        state_indexes: [usize; 4],
+        state: &mut [AssignedBlake2bWord<F>; 16],
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+    ) -> Result<(), Error> {
+        let chip = &self.blake2b_chip;
+        let v_a = &state[state_indexes[0]];
+        let v_b = &state[state_indexes[1]];
+        let v_c = &state[state_indexes[2]];
+        let v_d = &state[state_indexes[3]];
+
+        // v[a] = v[a] + v[b] + 2 * lo(v[a]) * lo(v[b])
+        let a = self.blamka(v_a, v_b, false, region, offset)?;
+
+        // v[d] = rotr_64(v[d] ^ v[a], 32);
+        let d_xor_a = chip.xor_copying_one_parameter(&a, v_d, region, offset)?;
+        let d = chip.rotate_right_32(d_xor_a, region, offset)?;
+
+        // v[c] = v[c] + v[d] + 2 * lo(v[c]) * lo(v[d])
+        let c = self.blamka(&d, v_c, true, region, offset)?;
+
+        // v[b] = rotr_64(v[b] ^ v[c], 24);
+        let b_xor_c = chip.xor_copying_one_parameter(&c, v_b, region, offset)?;
+        let b = chip.rotate_right_24(b_xor_c, region, offset)?;
+
+        // v[a] = v[a] + v[b] + 2 * lo(v[a]) * lo(v[b])
+        let a = self.blamka(&b, &a.full_number, true, region, offset)?;
+
+        // v[d] = rotr_64(v[d] ^ v[a], 16);
+        let d_xor_a = chip.xor_copying_one_parameter(&a, &d, region, offset)?;
+        let d = chip.rotate_right_16(d_xor_a, region, offset)?;
+
+        // v[c] = v[c] + v[d] + 2 * lo(v[c]) * lo(v[d])
+        let c = self.blamka(&d, &c.full_number, true, region, offset)?;
+
+        // v[b] = rotr_64(v[b] ^ v[c], 63);
+        let b_xor_c = chip.xor_copying_one_parameter(&c, &b, region, offset)?;
+        let b = chip.rotate_right_63(b_xor_c.full_number, region, offset)?;
+
+        state[state_indexes[0]] = a.full_number;
+        state[state_indexes[1]] = b;
+        state[state_indexes[2]] = c.full_number;
+        state[state_indexes[3]] = d;
+
+        Ok(())
+    }
+
+    /// BlaMka step of the mixing function. It's implemented through a [BlaMkaConfig], and like
+    /// the addition of the Blake2b chip, it can reuse the last row of the trace as its first
+    /// operand, since the step is symmetric.
+    fn blamka(
+        &self,
+        previous_cell: &AssignedBlake2bWord<F>,
+        cell_to_copy: &AssignedBlake2bWord<F>,
+        use_last_row_as_first_operand: bool,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+    ) -> Result<AssignedRow<F>, Error> {
+        self.config.blamka_config.generate_blamka_rows_from_cells(
+            region,
+            offset,
+            previous_cell,
+            cell_to_copy,
+            use_last_row_as_first_operand,
+            self.config.blake2b_config.full_number_u64,
+            self.config.limbs,
+        )
+    }
+
+    /// Selects the reference block of a data-dependent position. The bits of the mapping of the
+    /// first word of the previous block are computed with the [ReferenceIndexConfig], and every
+    /// word of the reference block is selected among the candidates with a tree of selections,
+    /// where the candidate at index y is the one that corresponds to the mapping y.
+    fn select_reference_block(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        first_word_of_previous_block: &AssignedBlake2bWord<F>,
+        reference_area_size: usize,
+        candidates: &[&Argon2Block<F>],
+    ) -> Result<Argon2Block<F>, Error> {
+        layouter.assign_region(
+            || "argon2 reference block",
+            |mut region| {
+                let mut offset: usize = 0;
+                let bits =
+                    self.config.reference_index_config.generate_reference_index_rows_from_cell(
+                        &mut region,
+                        &mut offset,
+                        first_word_of_previous_block,
+                        reference_area_size as u64,
+                        self.config.blake2b_config.full_number_u64,
+                        self.config.limbs,
+                    )?;
+
+                let mut block = Vec::with_capacity(ARGON2_QWORDS_IN_BLOCK);
+                for word_index in 0..ARGON2_QWORDS_IN_BLOCK {
+                    let mut level: Vec<AssignedBlake2bWord<F>> =
+                        candidates.iter().map(|candidate| candidate[word_index].clone()).collect();
+                    for bit in bits.iter() {
+                        if level.len() == 1 {
+                            break;
+                        }
+                        level = level
+                            .chunks(2)
+                            .map(|pair| match pair {
+                                [when_false, when_true] => self.blake2b_chip.select(
+                                    bit,
+                                    when_false,
+                                    when_true,
+                                    &mut region,
+                                    &mut offset,
+                                ),
+                                _ => Ok(pair[0].clone()),
+                            })
+                            .collect::<Result<_, Error>>()?;
+                    }
+                    block.push(level.pop().unwrap());
+                }
+                Ok(block.try_into().unwrap())
+            },
+        )
+    }
+
+    /// Builds a block of the memory from the 1024 bytes of the output of H', which are
+    /// range-checked in the rows of the words.
+    fn block_from_bytes(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        bytes: &[AssignedByte<F>],
+    ) -> Result<Argon2Block<F>, Error> {
+        layouter.assign_region(
+            || "argon2 block from bytes",
+            |mut region| {
+                let mut offset: usize = 0;
+                let mut block = Vec::with_capacity(ARGON2_QWORDS_IN_BLOCK);
+                for chunk in bytes.chunks(BLAKE2B_BLOCK_SIZE) {
+                    let chunk: Vec<AssignedNative<F>> =
+                        chunk.iter().map(AssignedByte::to_native).collect();
+                    let rows = self.blake2b_chip.block_words_from_bytes(
+                        &mut region,
+                        &mut offset,
+                        chunk.try_into().unwrap(),
+                    )?;
+                    block.extend(rows.into_iter().map(|row| row.full_number));
+                }
+                Ok(block.try_into().unwrap())
+            },
+        )
+    }
+
+    /// Returns the 1024 bytes of a block of the memory, in little endian order.
+    fn block_to_bytes(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        block: &Argon2Block<F>,
+    ) -> Result<Vec<AssignedNative<F>>, Error> {
+        layouter.assign_region(
+            || "argon2 block to bytes",
+            |mut region| {
+                let mut offset: usize = 0;
+                let mut bytes = Vec::with_capacity(ARGON2_BLOCK_SIZE);
+                for word in block.iter() {
+                    let row =
+                        self.blake2b_chip.new_row_from_word(word, &mut region, &mut offset)?;
+                    bytes.extend(row.limbs.iter().map(AssignedByte::to_native));
+                }
+                Ok(bytes)
+            },
+        )
+    }
+}
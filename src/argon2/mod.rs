@@ -0,0 +1,21 @@
+//! A chip defining an Argon2id hash invocation, as defined in RFC 9106, for a single lane and
+//! small memory sizes. This interface works with in/out consisting of AssignedNative. The
+//! algorithm expects its values to be in the range of a Byte, and will fail if they're not.
+//!
+//! The chip is built over the [Blake2bChip], which computes the H and H' functions, and adds the
+//! gates needed by the compression function G of Argon2:
+//! * [Argon2Chip] This chip uses the lookup table of size `2**16` of the Blake2b chip, and every
+//!   block of the memory costs a few thousand rows, besides the Blake2b digests of H', so the
+//!   circuits instantiating this chip are usually of at least `2**18` rows.
+//!
+//! [Blake2bChip]: crate::blake2b::blake2b_chip::Blake2bChip
+//! [Argon2Chip]: crate::argon2::argon2_chip::Argon2Chip
+
+/// Basic definitions, constants and the computations of Argon2 that are done outside the circuit.
+pub(crate) mod utils;
+
+/// Cost parameters of Argon2id.
+pub mod params;
+
+/// The Argon2 chip.
+pub mod argon2_chip;
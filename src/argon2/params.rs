@@ -0,0 +1,45 @@
+use crate::argon2::utils::{ARGON2_SYNC_POINTS, MAX_MEMORY_BLOCKS, MIN_MEMORY_SIZE, MIN_TAG_LENGTH};
+
+/// Cost parameters of an Argon2id hash, as defined in RFC 9106. The degree of parallelism is
+/// always 1, since the gadget only supports a single lane, and the version is always 0x13.
+///
+/// All the parameters are known at circuit building time, and the size of the circuit grows with
+/// the memory size and the amount of iterations, so the gadget is meant for small memory sizes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Argon2Params {
+    pub(crate) memory_size: u32,
+    pub(crate) iterations: u32,
+    pub(crate) tag_length: usize,
+}
+
+impl Argon2Params {
+    /// Creates the parameters of a hash with the given memory size in KiB, amount of iterations
+    /// over the memory and length of the tag in bytes. The memory size must be at least 8 KiB,
+    /// and the memory that is actually used, which is the memory size rounded down to a multiple
+    /// of 4 KiB, must fit in 256 blocks of 1 KiB.
+    pub fn new(memory_size: u32, iterations: u32, tag_length: usize) -> Self {
+        assert!(memory_size >= MIN_MEMORY_SIZE, "Memory size must be at least 8 KiB");
+        assert!(
+            memory_size as usize / ARGON2_SYNC_POINTS * ARGON2_SYNC_POINTS <= MAX_MEMORY_BLOCKS,
+            "Memory size must be of at most 256 blocks"
+        );
+        assert!(iterations >= 1, "There must be at least one iteration");
+        assert!(tag_length >= MIN_TAG_LENGTH, "Tag length must be at least 4 bytes");
+        Self {
+            memory_size,
+            iterations,
+            tag_length,
+        }
+    }
+
+    /// Amount of blocks of 1 KiB of the memory, which is the memory size rounded down to a
+    /// multiple of the amount of slices.
+    pub(crate) fn memory_blocks(&self) -> usize {
+        self.memory_size as usize / ARGON2_SYNC_POINTS * ARGON2_SYNC_POINTS
+    }
+
+    /// Amount of blocks of each slice of the memory.
+    pub(crate) fn segment_length(&self) -> usize {
+        self.memory_blocks() / ARGON2_SYNC_POINTS
+    }
+}
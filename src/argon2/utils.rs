@@ -0,0 +1,139 @@
+use crate::argon2::params::Argon2Params;
+use crate::blake2b::utils::ABCD;
+
+/// Computes the pseudo-random values of the data-independent addressing of a segment outside the
+/// circuit, since they only depend on the parameters of the hash. As in the reference
+/// implementation, an address block with 128 values is computed as G(0, G(0, Z)), where Z holds
+/// the position of the segment, the parameters and a counter of address blocks. There's a value
+/// for every block of the segment, but the ones of the first two blocks of the memory are not
+/// used, since those blocks are not computed with the compression function.
+pub(crate) fn data_independent_pseudo_rands(
+    params: &Argon2Params,
+    pass: usize,
+    slice: usize,
+) -> Vec<u64> {
+    let zero_block = [0u64; ARGON2_QWORDS_IN_BLOCK];
+    let mut input_block = [0u64; ARGON2_QWORDS_IN_BLOCK];
+    input_block[0] = pass as u64;
+    input_block[2] = slice as u64;
+    input_block[3] = params.memory_blocks() as u64;
+    input_block[4] = params.iterations as u64;
+    input_block[5] = ARGON2ID_TYPE as u64;
+
+    let mut address_block = zero_block;
+    let mut pseudo_rands = Vec::with_capacity(params.segment_length());
+    for index in 0..params.segment_length() {
+        if index % ARGON2_QWORDS_IN_BLOCK == 0 {
+            input_block[6] += 1;
+            address_block = compress_blocks_native(
+                &zero_block,
+                &compress_blocks_native(&zero_block, &input_block),
+            );
+        }
+        pseudo_rands.push(address_block[index % ARGON2_QWORDS_IN_BLOCK]);
+    }
+    pseudo_rands
+}
+
+/// Amount of blocks among which the reference block of the given position is chosen. In the
+/// first pass, these are all the blocks that were already computed except the previous one,
+/// and in the next passes, all the blocks except the ones of the current segment that weren't
+/// computed yet in this pass and the previous one.
+pub(crate) fn reference_area_size(
+    params: &Argon2Params,
+    pass: usize,
+    slice: usize,
+    index: usize,
+) -> usize {
+    if pass == 0 {
+        slice * params.segment_length() + index - 1
+    } else {
+        params.memory_blocks() - params.segment_length() + index - 1
+    }
+}
+
+/// Position of the first block of the reference area. In the first pass it's the first block of
+/// the memory, and in the next passes it's the first block of the next segment.
+pub(crate) fn reference_start_position(params: &Argon2Params, pass: usize, slice: usize) -> usize {
+    if pass == 0 {
+        0
+    } else {
+        (slice + 1) % ARGON2_SYNC_POINTS * params.segment_length()
+    }
+}
+
+/// Maps the 32 least significant bits of a pseudo-random value to a position relative to the
+/// start of the reference area, as defined in RFC 9106.
+pub(crate) fn relative_position(reference_area_size: usize, pseudo_rand: u64) -> usize {
+    let j1 = pseudo_rand & 0xFFFFFFFF;
+    let x = (j1 * j1) >> 32;
+    let y = (reference_area_size as u64 * x) >> 32;
+    reference_area_size - 1 - y as usize
+}
+
+/// Compression function G of Argon2 over two blocks, computed outside the circuit.
+fn compress_blocks_native(
+    x: &[u64; ARGON2_QWORDS_IN_BLOCK],
+    y: &[u64; ARGON2_QWORDS_IN_BLOCK],
+) -> [u64; ARGON2_QWORDS_IN_BLOCK] {
+    let r: [u64; ARGON2_QWORDS_IN_BLOCK] = std::array::from_fn(|i| x[i] ^ y[i]);
+    let mut z = r;
+    for indexes in permutation_indexes() {
+        let mut v: [u64; 16] = indexes.map(|i| z[i]);
+        for [a, b, c, d] in ABCD {
+            v[a] = blamka_native(v[a], v[b]);
+            v[d] = (v[d] ^ v[a]).rotate_right(32);
+            v[c] = blamka_native(v[c], v[d]);
+            v[b] = (v[b] ^ v[c]).rotate_right(24);
+            v[a] = blamka_native(v[a], v[b]);
+            v[d] = (v[d] ^ v[a]).rotate_right(16);
+            v[c] = blamka_native(v[c], v[d]);
+            v[b] = (v[b] ^ v[c]).rotate_right(63);
+        }
+        for (k, i) in indexes.into_iter().enumerate() {
+            z[i] = v[k];
+        }
+    }
+    std::array::from_fn(|i| z[i] ^ r[i])
+}
+
+fn blamka_native(x: u64, y: u64) -> u64 {
+    let low_product = (x & 0xFFFFFFFF) * (y & 0xFFFFFFFF);
+    x.wrapping_add(y).wrapping_add(low_product.wrapping_mul(2))
+}
+
+/// Indexes of the words of a block that are permuted together by the compression function G.
+/// The block is seen as a matrix of 8x8 registers of 16 bytes, and the permutation is applied
+/// first to every row of the matrix, and then to every column.
+pub(crate) fn permutation_indexes() -> Vec<[usize; 16]> {
+    let rows = (0..8).map(|row| std::array::from_fn(|k| 16 * row + k));
+    let columns = (0..8).map(|column| std::array::from_fn(|k| 2 * column + 16 * (k / 2) + k % 2));
+    rows.chain(columns).collect()
+}
+
+/// Serializes the given number as the 4 little endian bytes used in the inputs of Argon2.
+pub(crate) fn le32(number: usize) -> [u8; 4] {
+    (number as u32).to_le_bytes()
+}
+
+// ----- Argon2 constants -----
+
+pub(crate) const ARGON2_VERSION: usize = 0x13;
+
+pub(crate) const ARGON2ID_TYPE: usize = 2;
+
+pub(crate) const ARGON2_LANES: usize = 1;
+
+pub(crate) const ARGON2_SYNC_POINTS: usize = 4;
+
+pub(crate) const ARGON2_BLOCK_SIZE: usize = 1024;
+
+pub(crate) const ARGON2_QWORDS_IN_BLOCK: usize = 128;
+
+pub(crate) const ARGON2_PREHASH_DIGEST_LENGTH: usize = 64;
+
+pub(crate) const MIN_MEMORY_SIZE: u32 = 8;
+
+pub(crate) const MAX_MEMORY_BLOCKS: usize = 256;
+
+pub(crate) const MIN_TAG_LENGTH: usize = 4;
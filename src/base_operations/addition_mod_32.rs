@@ -0,0 +1,149 @@
+use midnight_proofs::plonk::Constraints;
+use super::*;
+use crate::base_operations::types::bit::AssignedBit;
+use crate::base_operations::types::blake2b_word::{AssignedBlake2bWord, Blake2bWord};
+use crate::base_operations::types::row::AssignedRow;
+
+/// Config used to constrain addition mod 32-bits. It works like the [AdditionMod64Config], over
+/// 32-bit numbers that are held in the same rows as the 64-bit ones, with the 4 most significant
+/// limbs set to zero. It generates a decomposed result in limbs.
+///
+/// [AdditionMod64Config]: crate::base_operations::addition_mod_64::AdditionMod64Config
+#[derive(Clone, Debug)]
+pub(crate) struct AdditionMod32Config {
+    carry: Column<Advice>,
+    pub(crate) q_add: Selector,
+    q_decompose: Selector,
+    q_range: Selector,
+}
+
+impl AdditionMod32Config {
+    /// Creates the necessary gate for the operation to be constrained
+    /// The gate that will be used to check the sum of two numbers mod 2^32
+    /// The gate is defined as:
+    ///     sum mod 2 ^ 32 = full_number_result - full_number_x - full_number_y
+    ///                     + carry * (1 << 32)
+    ///    carry = carry * (1 - carry)
+    ///    limb_i_result = 0, for i in [4, 7]
+    ///
+    /// Note that the full number is range checked to be a 32-bit number because we are using
+    /// 8-bit limbs, the q_decompose and q_range selectors below, and the most significant limbs
+    /// are constrained to be zero.
+    pub(crate) fn configure<F: PrimeField>(
+        meta: &mut ConstraintSystem<F>,
+        full_number_u64: Column<Advice>,
+        limbs: [Column<Advice>; 8],
+        q_decompose: Selector,
+        q_range: Selector,
+    ) -> Self {
+        let q_add = meta.complex_selector();
+        // For the carry column we reuse the first limb column, as in the 64-bit addition
+        let carry = limbs[0];
+
+        meta.create_gate("sum mod 2 ^ 32", |meta| {
+            let q_add = meta.query_selector(q_add);
+            let full_number_x = meta.query_advice(full_number_u64, Rotation(0));
+            let full_number_y = meta.query_advice(full_number_u64, Rotation(1));
+            let full_number_result = meta.query_advice(full_number_u64, Rotation(2));
+            let carry = meta.query_advice(carry, Rotation(1));
+
+            let mut constraints = vec![
+                q_add.clone()
+                    * (full_number_result - full_number_x - full_number_y
+                        + carry.clone() * (Expression::Constant(F::from(1u64 << 32)))),
+                q_add.clone() * carry.clone() * (Expression::Constant(F::ONE) - carry),
+            ];
+            for limb in &limbs[4..] {
+                constraints.push(q_add.clone() * meta.query_advice(*limb, Rotation(2)));
+            }
+
+            Constraints::without_selector(constraints)
+        });
+
+        Self {
+            carry,
+            q_add,
+            q_decompose,
+            q_range,
+        }
+    }
+
+    /// This method receives two cells, copies the values of the cells to the trace and then
+    /// calculates the result and carry of the addition and write it in a third row.
+    ///
+    /// As in the 64-bit addition, when the first operand is the last cell that was generated in
+    /// the circuit, setting [use_last_cell_as_first_operand] to [true] avoids copying it again.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn generate_addition_rows_from_cells<F: PrimeField>(
+        &self,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+        previous_cell: &AssignedBlake2bWord<F>,
+        cell_to_copy: &AssignedBlake2bWord<F>,
+        use_last_cell_as_first_operand: bool,
+        full_number_u64_column: Column<Advice>,
+        limbs: [Column<Advice>; 8],
+    ) -> Result<(AssignedRow<F>, AssignedBit<F>), Error> {
+        let (result_value, carry_value) =
+            Self::calculate_result_and_carry(previous_cell.value(), cell_to_copy.value());
+        let offset_to_enable = *offset - if use_last_cell_as_first_operand { 1 } else { 0 };
+        self.q_add.enable(region, offset_to_enable)?;
+
+        if !use_last_cell_as_first_operand {
+            AssignedBlake2bWord::copy_advice_word(
+                previous_cell,
+                region,
+                full_number_u64_column,
+                *offset,
+                "Sum first operand",
+            )?;
+            *offset += 1;
+        }
+        AssignedBlake2bWord::copy_advice_word(
+            cell_to_copy,
+            region,
+            full_number_u64_column,
+            *offset,
+            "Sum second operand",
+        )?;
+
+        let carry_cell =
+            AssignedBit::assign_advice_bit(region, "carry", self.carry, *offset, carry_value)?;
+        *offset += 1;
+
+        self.q_decompose.enable(region, *offset)?;
+        self.q_range.enable(region, *offset)?;
+        let result_row = generate_row_from_word_value(
+            region,
+            result_value,
+            *offset,
+            full_number_u64_column,
+            limbs,
+        )?;
+        *offset += 1;
+
+        Ok((result_row, carry_cell))
+    }
+
+    /// Given 2 operand values, known at proof generation time, returns the values holding the
+    /// result of that sum mod 2^32 and the carry value, which must be 0 or 1. Both ranges will be
+    /// constrained by this gate.
+    fn calculate_result_and_carry<F: PrimeField>(
+        lhs: Value<Blake2bWord>,
+        rhs: Value<Blake2bWord>,
+    ) -> (Value<Blake2bWord>, Value<F>) {
+        let result_value = lhs.and_then(|l| rhs.and_then(|r| Value::known(Self::sum_mod_32(l, r))));
+        let carry_value =
+            lhs.and_then(|l| rhs.and_then(|r| Value::known(Self::carry_mod_32(l, r))));
+        (result_value, carry_value)
+    }
+
+    fn sum_mod_32(a: Blake2bWord, b: Blake2bWord) -> Blake2bWord {
+        ((a.0 + b.0) % (1u64 << 32)).into()
+    }
+
+    fn carry_mod_32<F: PrimeField>(a: Blake2bWord, b: Blake2bWord) -> F {
+        let carry = (a.0 + b.0) / (1u64 << 32);
+        F::from(carry)
+    }
+}
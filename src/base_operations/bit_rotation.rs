@@ -0,0 +1,186 @@
+use midnight_proofs::plonk::{Constraints, VirtualCells};
+use super::*;
+use crate::base_operations::types::blake2b_word::{AssignedBlake2bWord, Blake2bWord};
+
+/// Bitwise operations by less than a limb handled by the [BitRotationConfig].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum BitShift {
+    RotateRight,
+    ShiftRight,
+    ShiftLeft,
+}
+
+/// This config handles the rotations and shifts of a 64-bit number by 1 to 7 bits, which are
+/// combined with the limb rotations and shifts of [LimbRotation] to rotate or shift by any amount.
+/// The limb at the boundary of the operation, the least significant one for the right rotations
+/// and shifts and the most significant one for the left shifts, is split in two parts:
+///
+///    limb = low + power * high
+///
+/// where 'power' is 2^bits for the operations to the right, and 2^(8 - bits) for the shift to the
+/// left. The parts are range checked to 8 bits like any other limb, and they're bounded by their
+/// powers by looking up 'low * co_power' and 'high * power' in the same 8-bit range table, where
+/// 'power * co_power = 2^8'. The output is placed in the row right after the input, whose limbs
+/// must be range checked, with the parts and the powers in its limbs:
+///
+/// | full_number_input  | limb_0 | limb_1 | limb_2 | limb_3   | ... | limb_7 |
+/// | full_number_output | low    | high   | power  | co_power |     |        |
+///
+/// The powers are copied from the constants, and the output is fully determined by the gate, so it
+/// doesn't need a decomposition of its own to be a 64-bit number.
+///
+/// [LimbRotation]: crate::base_operations::generic_limb_rotation::LimbRotation
+#[derive(Clone, Debug)]
+pub(crate) struct BitRotationConfig {
+    pub(crate) q_rotate_right: Selector,
+    pub(crate) q_shift_right: Selector,
+    pub(crate) q_shift_left: Selector,
+    q_range: Selector,
+}
+
+impl BitRotationConfig {
+    /// The gates that will be used to rotate or shift a number by less than a limb
+    /// The gates are defined as:
+    ///    0 = power * co_power - 2^8
+    ///    0 = split_limb - low - power * high
+    ///    rotate right: 0 = power * output - input + low - low * 2^64, splitting limb_0
+    ///    shift right:  0 = power * output - input + low, splitting limb_0
+    ///    shift left:   0 = output - co_power * input + high * 2^64, splitting limb_7
+    pub(crate) fn configure<F: PrimeField>(
+        meta: &mut ConstraintSystem<F>,
+        full_number_u64: Column<Advice>,
+        limbs: [Column<Advice>; 8],
+        q_range: Selector,
+        t_range: TableColumn,
+    ) -> Self {
+        let q_rotate_right = meta.complex_selector();
+        let q_shift_right = meta.complex_selector();
+        let q_shift_left = meta.complex_selector();
+
+        meta.create_gate("bit rotation", |meta| {
+            let q_rotate_right = meta.query_selector(q_rotate_right);
+            let q_shift_right = meta.query_selector(q_shift_right);
+            let q_shift_left = meta.query_selector(q_shift_left);
+            let input = meta.query_advice(full_number_u64, Rotation(-1));
+            let least_significant_limb = meta.query_advice(limbs[0], Rotation(-1));
+            let most_significant_limb = meta.query_advice(limbs[7], Rotation(-1));
+            let output = meta.query_advice(full_number_u64, Rotation(0));
+            let low = meta.query_advice(limbs[0], Rotation(0));
+            let high = meta.query_advice(limbs[1], Rotation(0));
+            let power = meta.query_advice(limbs[2], Rotation(0));
+            let co_power = meta.query_advice(limbs[3], Rotation(0));
+            let two_pow_64 = Expression::Constant(F::from_u128(1 << 64));
+
+            let split = |limb: Expression<F>| limb - low.clone() - power.clone() * high.clone();
+            let q_any = q_rotate_right.clone() + q_shift_right.clone() + q_shift_left.clone();
+            let constraints = vec![
+                q_any * (power.clone() * co_power.clone() - Expression::Constant(F::from(1 << 8))),
+                (q_rotate_right.clone() + q_shift_right.clone()) * split(least_significant_limb),
+                q_shift_left.clone() * split(most_significant_limb),
+                q_rotate_right
+                    * (power.clone() * output.clone() - input.clone() + low.clone()
+                        - low.clone() * two_pow_64.clone()),
+                q_shift_right * (power * output.clone() - input.clone() + low),
+                q_shift_left * (output - co_power * input + high * two_pow_64),
+            ];
+            Constraints::without_selector(constraints)
+        });
+
+        meta.lookup("bit rotation low part", |meta| {
+            let q_any = Self::query_any_selector(meta, q_rotate_right, q_shift_right, q_shift_left);
+            let low = meta.query_advice(limbs[0], Rotation(0));
+            let co_power = meta.query_advice(limbs[3], Rotation(0));
+            vec![(q_any * low * co_power, t_range)]
+        });
+        meta.lookup("bit rotation high part", |meta| {
+            let q_any = Self::query_any_selector(meta, q_rotate_right, q_shift_right, q_shift_left);
+            let high = meta.query_advice(limbs[1], Rotation(0));
+            let power = meta.query_advice(limbs[2], Rotation(0));
+            vec![(q_any * high * power, t_range)]
+        });
+
+        Self {
+            q_rotate_right,
+            q_shift_right,
+            q_shift_left,
+            q_range,
+        }
+    }
+
+    /// This method rotates or shifts the input by the given amount of bits, between 1 and 7, and
+    /// puts the output in a new row of the trace. For this method to work, the input must be the
+    /// full number of the last row of the trace at the moment the method is called, and its limbs
+    /// must be range checked.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn generate_bit_rotation_row_from_input<F: PrimeField>(
+        &self,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+        input: &AssignedBlake2bWord<F>,
+        operation: BitShift,
+        bits: usize,
+        full_number_u64: Column<Advice>,
+        limbs: [Column<Advice>; 8],
+    ) -> Result<AssignedBlake2bWord<F>, Error> {
+        assert!((1..8).contains(&bits), "Bit rotations must be between 1 and 7 bits");
+        let (selector, power) = match operation {
+            BitShift::RotateRight => (self.q_rotate_right, 1u64 << bits),
+            BitShift::ShiftRight => (self.q_shift_right, 1u64 << bits),
+            BitShift::ShiftLeft => (self.q_shift_left, 1u64 << (8 - bits)),
+        };
+        selector.enable(region, *offset)?;
+        self.q_range.enable(region, *offset)?;
+
+        let split_limb = input.value().map(|input| match operation {
+            BitShift::ShiftLeft => input.0 >> 56,
+            _ => input.0 & 0xff,
+        });
+        let output_value = input.value().map(|input| {
+            Blake2bWord(match operation {
+                BitShift::RotateRight => input.0.rotate_right(bits as u32),
+                BitShift::ShiftRight => input.0 >> bits,
+                BitShift::ShiftLeft => input.0 << bits,
+            })
+        });
+
+        let output = AssignedBlake2bWord::assign_advice_word(
+            region,
+            "Bit rotation output",
+            full_number_u64,
+            *offset,
+            output_value,
+        )?;
+        region.assign_advice(
+            || "Bit rotation low part",
+            limbs[0],
+            *offset,
+            || split_limb.map(|limb| F::from(limb % power)),
+        )?;
+        region.assign_advice(
+            || "Bit rotation high part",
+            limbs[1],
+            *offset,
+            || split_limb.map(|limb| F::from(limb / power)),
+        )?;
+        region.assign_advice_from_constant(|| "power", limbs[2], *offset, F::from(power))?;
+        region.assign_advice_from_constant(
+            || "co power",
+            limbs[3],
+            *offset,
+            F::from(256 / power),
+        )?;
+        *offset += 1;
+        Ok(output)
+    }
+
+    fn query_any_selector<F: PrimeField>(
+        meta: &mut VirtualCells<'_, F>,
+        q_rotate_right: Selector,
+        q_shift_right: Selector,
+        q_shift_left: Selector,
+    ) -> Expression<F> {
+        meta.query_selector(q_rotate_right)
+            + meta.query_selector(q_shift_right)
+            + meta.query_selector(q_shift_left)
+    }
+}
@@ -0,0 +1,206 @@
+use super::*;
+use crate::base_operations::types::blake2b_word::{AssignedBlake2bWord, Blake2bWord};
+use crate::base_operations::types::byte::Byte;
+use crate::base_operations::types::row::AssignedRow;
+
+/// Bitwise operations handled by the [BitwiseConfig], besides the xor of the [XorConfig].
+///
+/// [XorConfig]: crate::base_operations::xor::XorConfig
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum BitwiseOperation {
+    And,
+    Or,
+    /// The negation of the left-hand side and the right-hand side, '!lhs & rhs'.
+    AndNot,
+}
+
+impl BitwiseOperation {
+    fn apply(&self, lhs: u64, rhs: u64) -> u64 {
+        match self {
+            Self::And => lhs & rhs,
+            Self::Or => lhs | rhs,
+            Self::AndNot => !lhs & rhs,
+        }
+    }
+}
+
+/// This config handles the and, or and and-not operations in the trace, like the [XorConfig]
+/// does with the xor. It uses a lookup table with a column for each operation:
+///
+/// | lhs | rhs | lhs and rhs | lhs or rhs | !lhs and rhs |
+/// |  0  |  0  |      0      |      0     |       0      |
+/// |  0  |  1  |      0      |      1     |       1      |
+/// ...
+/// | 255 | 255 |     255     |     255    |       0      |
+///
+/// The table has 2^8 * 2^8 = 2^16 rows, and every operation looks up its operands and its result
+/// in the columns of the operands and in the column of the operation. The rows of the trace have
+/// the same layout as the ones of the xor:
+///
+/// | full_number_lhs    | limb_0_lhs    | limb_1_lhs    | ... | limb_7_lhs    |
+/// | full_number_rhs    | limb_0_rhs    | limb_1_rhs    | ... | limb_7_rhs    |
+/// | full_number_result | limb_0_result | limb_1_result | ... | limb_7_result |
+///
+/// [XorConfig]: crate::base_operations::xor::XorConfig
+#[derive(Clone, Debug)]
+pub(crate) struct BitwiseConfig {
+    /// Lookup table columns
+    t_left: TableColumn,
+    t_right: TableColumn,
+    t_and: TableColumn,
+    t_or: TableColumn,
+    t_and_not: TableColumn,
+
+    /// Selectors for the operations
+    q_and: Selector,
+    q_or: Selector,
+    q_and_not: Selector,
+
+    /// Involved columns
+    full_number_u64: Column<Advice>,
+    limbs: [Column<Advice>; 8],
+
+    /// Selector for the decomposition
+    q_decompose: Selector,
+}
+
+impl BitwiseConfig {
+    pub(crate) fn configure<F: PrimeField>(
+        meta: &mut ConstraintSystem<F>,
+        full_number_u64: Column<Advice>,
+        limbs: [Column<Advice>; 8],
+        q_decompose: Selector,
+    ) -> Self {
+        let t_left = meta.lookup_table_column();
+        let t_right = meta.lookup_table_column();
+        let t_and = meta.lookup_table_column();
+        let t_or = meta.lookup_table_column();
+        let t_and_not = meta.lookup_table_column();
+        let q_and = meta.complex_selector();
+        let q_or = meta.complex_selector();
+        let q_and_not = meta.complex_selector();
+
+        // We need to perform a lookup for each limb and each operation
+        for (name, selector, t_out) in
+            [("and", q_and, t_and), ("or", q_or, t_or), ("and not", q_and_not, t_and_not)]
+        {
+            for limb in limbs {
+                meta.lookup(format!("{name} lookup limb {limb:?}"), |meta| {
+                    let left: Expression<F> = meta.query_advice(limb, Rotation(0));
+                    let right: Expression<F> = meta.query_advice(limb, Rotation(1));
+                    let out: Expression<F> = meta.query_advice(limb, Rotation(2));
+                    let selector = meta.query_selector(selector);
+                    vec![
+                        (selector.clone() * left, t_left),
+                        (selector.clone() * right, t_right),
+                        (selector * out, t_out),
+                    ]
+                });
+            }
+        }
+
+        Self {
+            t_left,
+            t_right,
+            t_and,
+            t_or,
+            t_and_not,
+            q_and,
+            q_or,
+            q_and_not,
+            full_number_u64,
+            limbs,
+            q_decompose,
+        }
+    }
+
+    /// Method that populates the lookup table. Must be called only once in the user circuit.
+    pub(crate) fn populate_bitwise_lookup_table<F: PrimeField>(
+        &self,
+        layouter: &mut impl Layouter<F>,
+    ) -> Result<(), Error> {
+        layouter.assign_table(
+            || "bitwise operations table",
+            |mut table| {
+                for left in 0..256u64 {
+                    for right in 0..256u64 {
+                        let index = (left * 256 + right) as usize;
+                        let columns = [
+                            (self.t_left, left),
+                            (self.t_right, right),
+                            (self.t_and, BitwiseOperation::And.apply(left, right)),
+                            (self.t_or, BitwiseOperation::Or.apply(left, right)),
+                            (self.t_and_not, BitwiseOperation::AndNot.apply(left, right) & 0xff),
+                        ];
+                        for (column, value) in columns {
+                            table.assign_cell(
+                                || "bitwise table value",
+                                column,
+                                index,
+                                || Value::known(F::from(value)),
+                            )?;
+                        }
+                    }
+                }
+                Ok(())
+            },
+        )
+    }
+
+    /// This method generates the rows of a bitwise operation in the trace. Copying both operands
+    /// into new rows on the trace and then performing the operation on the row limbs. Each limb of
+    /// the result is looked up in the table, with the corresponding limbs of the operands.
+    pub(crate) fn generate_bitwise_rows_from_cells<F: PrimeField>(
+        &self,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+        operation: BitwiseOperation,
+        lhs: &AssignedBlake2bWord<F>,
+        rhs: &AssignedBlake2bWord<F>,
+    ) -> Result<AssignedRow<F>, Error> {
+        let selector = match operation {
+            BitwiseOperation::And => self.q_and,
+            BitwiseOperation::Or => self.q_or,
+            BitwiseOperation::AndNot => self.q_and_not,
+        };
+        selector.enable(region, *offset)?;
+
+        // We only enable decomposition because the range-checks are performed by the lookups of
+        // the gate
+        let mut operand_rows = Vec::with_capacity(2);
+        for operand in [lhs, rhs] {
+            self.q_decompose.enable(region, *offset)?;
+            operand_rows.push(generate_row_from_cell(
+                region,
+                operand,
+                *offset,
+                self.full_number_u64,
+                self.limbs,
+            )?);
+            *offset += 1;
+        }
+
+        let result_value = lhs
+            .value()
+            .zip(rhs.value())
+            .map(|(lhs, rhs)| Blake2bWord(operation.apply(lhs.0, rhs.0)));
+        let result_limb_values: [Value<Byte>; 8] = std::array::from_fn(|i| {
+            operand_rows[0].limbs[i]
+                .value()
+                .zip(operand_rows[1].limbs[i].value())
+                .map(|(lhs, rhs)| Byte(operation.apply(lhs.0 as u64, rhs.0 as u64) as u8))
+        });
+
+        self.q_decompose.enable(region, *offset)?;
+        let result_row = create_row_with_word_and_limbs(
+            region,
+            result_value,
+            result_limb_values,
+            *offset,
+            self.full_number_u64,
+            self.limbs,
+        )?;
+        *offset += 1;
+        Ok(result_row)
+    }
+}
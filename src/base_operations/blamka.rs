@@ -0,0 +1,152 @@
+use midnight_proofs::plonk::{Constraints, VirtualCells};
+use super::*;
+use crate::base_operations::types::bit::AssignedBit;
+use crate::base_operations::types::blake2b_word::{AssignedBlake2bWord, Blake2bWord};
+use crate::base_operations::types::row::AssignedRow;
+
+/// Config used to constrain the BlaMka mixing step of Argon2, which replaces the additions of the
+/// Blake2b mixing function:
+///
+///    x + y + 2 * lo(x) * lo(y) mod 2^64
+///
+/// where lo(x) is the number formed by the 32 least significant bits of x. It works like the
+/// [AdditionMod64Config], but the operands need their limbs to compute lo(x) and lo(y), and the
+/// sum can be up to 2^66, so the carry takes 2 bits that are placed in a row of their own:
+///
+/// | full_number_x      | limb_0_x      | ... | limb_7_x      |
+/// | full_number_y      | limb_0_y      | ... | limb_7_y      |
+/// |                    | carry_0       | carry_1   |         |
+/// | full_number_result | limb_0_result | ... | limb_7_result |
+///
+/// [AdditionMod64Config]: crate::base_operations::addition_mod_64::AdditionMod64Config
+#[derive(Clone, Debug)]
+pub(crate) struct BlaMkaConfig {
+    q_blamka: Selector,
+    q_decompose: Selector,
+    q_range: Selector,
+}
+
+impl BlaMkaConfig {
+    /// Creates the necessary gate for the operation to be constrained
+    /// The gate is defined as:
+    ///    0 = full_number_result - full_number_x - full_number_y - 2 * lo(x) * lo(y)
+    ///        + (carry_0 + 2 * carry_1) * (1 << 64)
+    ///    0 = carry_i * (1 - carry_i), for i in [0, 1]
+    /// where lo(x) = sum [i=0..3] -> limb_i_x * (1 << (8*i))
+    ///
+    /// The operands must be decomposed and range checked in their rows, which is done by this
+    /// config when they're copied, and the result is range checked by the q_decompose and
+    /// q_range selectors below.
+    pub(crate) fn configure<F: PrimeField>(
+        meta: &mut ConstraintSystem<F>,
+        full_number_u64: Column<Advice>,
+        limbs: [Column<Advice>; 8],
+        q_decompose: Selector,
+        q_range: Selector,
+    ) -> Self {
+        let q_blamka = meta.complex_selector();
+
+        meta.create_gate("blamka", |meta| {
+            let q_blamka = meta.query_selector(q_blamka);
+            let full_number_x = meta.query_advice(full_number_u64, Rotation(0));
+            let full_number_y = meta.query_advice(full_number_u64, Rotation(1));
+            let carry_0 = meta.query_advice(limbs[0], Rotation(2));
+            let carry_1 = meta.query_advice(limbs[1], Rotation(2));
+            let full_number_result = meta.query_advice(full_number_u64, Rotation(3));
+            let low_half = |meta: &mut VirtualCells<'_, F>, rotation: i32| {
+                (0..4).fold(Expression::Constant(F::ZERO), |sum, i| {
+                    sum + meta.query_advice(limbs[i], Rotation(rotation))
+                        * Expression::Constant(F::from(1 << (8 * i)))
+                })
+            };
+            let low_x = low_half(meta, 0);
+            let low_y = low_half(meta, 1);
+            let one = Expression::Constant(F::ONE);
+
+            let constraints = vec![
+                q_blamka.clone()
+                    * (full_number_result
+                        - full_number_x
+                        - full_number_y
+                        - Expression::Constant(F::from(2)) * low_x * low_y
+                        + (carry_0.clone() + Expression::Constant(F::from(2)) * carry_1.clone())
+                            * Expression::Constant(F::from_u128(1u128 << 64))),
+                q_blamka.clone() * carry_0.clone() * (one.clone() - carry_0),
+                q_blamka * carry_1.clone() * (one - carry_1),
+            ];
+
+            Constraints::without_selector(constraints)
+        });
+
+        Self {
+            q_blamka,
+            q_decompose,
+            q_range,
+        }
+    }
+
+    /// This method receives two cells, copies them to decomposed rows of the trace and then
+    /// calculates the result and the carry of the BlaMka step, which are written in the next rows.
+    ///
+    /// As in the [AdditionMod64Config], when the first operand is the last row that was generated
+    /// in the circuit, setting [use_last_row_as_first_operand] to [true] avoids copying it again.
+    /// In that case, the last row must hold the limbs of the operand, range checked.
+    ///
+    /// [AdditionMod64Config]: crate::base_operations::addition_mod_64::AdditionMod64Config
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn generate_blamka_rows_from_cells<F: PrimeField>(
+        &self,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+        previous_cell: &AssignedBlake2bWord<F>,
+        cell_to_copy: &AssignedBlake2bWord<F>,
+        use_last_row_as_first_operand: bool,
+        full_number_u64: Column<Advice>,
+        limbs: [Column<Advice>; 8],
+    ) -> Result<AssignedRow<F>, Error> {
+        let result_and_carry = previous_cell
+            .value()
+            .zip(cell_to_copy.value())
+            .map(|(x, y)| Self::blamka_with_carry(x, y));
+        let offset_to_enable = *offset - if use_last_row_as_first_operand { 1 } else { 0 };
+        self.q_blamka.enable(region, offset_to_enable)?;
+
+        if !use_last_row_as_first_operand {
+            self.q_decompose.enable(region, *offset)?;
+            self.q_range.enable(region, *offset)?;
+            generate_row_from_cell(region, previous_cell, *offset, full_number_u64, limbs)?;
+            *offset += 1;
+        }
+        self.q_decompose.enable(region, *offset)?;
+        self.q_range.enable(region, *offset)?;
+        generate_row_from_cell(region, cell_to_copy, *offset, full_number_u64, limbs)?;
+        *offset += 1;
+
+        for (i, limb) in limbs.iter().take(2).enumerate() {
+            let carry_bit = result_and_carry.map(|(_, carry)| F::from((carry >> i) & 1));
+            AssignedBit::assign_advice_bit(region, "blamka carry", *limb, *offset, carry_bit)?;
+        }
+        *offset += 1;
+
+        self.q_decompose.enable(region, *offset)?;
+        self.q_range.enable(region, *offset)?;
+        let result_row = generate_row_from_word_value(
+            region,
+            result_and_carry.map(|(result, _)| result),
+            *offset,
+            full_number_u64,
+            limbs,
+        )?;
+        *offset += 1;
+
+        Ok(result_row)
+    }
+
+    /// Given 2 operands, returns the result of the BlaMka step mod 2^64 and its carry, which is in
+    /// the range [0, 3].
+    fn blamka_with_carry(x: Blake2bWord, y: Blake2bWord) -> (Blake2bWord, u64) {
+        let low_product = (x.0 as u32 as u128) * (y.0 as u32 as u128);
+        let sum = x.0 as u128 + y.0 as u128 + 2 * low_product;
+        ((sum as u64).into(), (sum >> 64) as u64)
+    }
+}
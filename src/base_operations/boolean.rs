@@ -0,0 +1,63 @@
+use midnight_proofs::plonk::Constraints;
+use super::*;
+use crate::base_operations::types::bit::AssignedBit;
+
+/// This config constrains the limbs of a row to hold bits, which are witnessed by the chips that
+/// need them, such as the path bits of a Merkle proof. Up to eight bits fit in a row, and the
+/// limbs that are left are filled with zeros:
+///
+/// | | bit_0 | bit_1 | bit_2 | bit_3 | bit_4 | bit_5 | bit_6 | bit_7 |
+#[derive(Clone, Debug)]
+pub(crate) struct BooleanConfig {
+    q_boolean: Selector,
+}
+
+impl BooleanConfig {
+    /// Creates the gate of the config
+    /// The gate is defined as:
+    ///    0 = bit_i * (1 - bit_i), for i in [0, 7]
+    pub(crate) fn configure<F: PrimeField>(
+        meta: &mut ConstraintSystem<F>,
+        limbs: [Column<Advice>; 8],
+    ) -> Self {
+        let q_boolean = meta.complex_selector();
+
+        meta.create_gate("boolean", |meta| {
+            let q_boolean = meta.query_selector(q_boolean);
+            let constraints = limbs
+                .iter()
+                .map(|limb| {
+                    let bit = meta.query_advice(*limb, Rotation::cur());
+                    q_boolean.clone() * bit.clone() * (Expression::Constant(F::ONE) - bit)
+                })
+                .collect::<Vec<_>>();
+
+            Constraints::without_selector(constraints)
+        });
+
+        Self { q_boolean }
+    }
+
+    /// This method assigns up to eight bits to a new row of the trace and constrains them to be
+    /// bits.
+    pub(crate) fn generate_bits_row<F: PrimeField>(
+        &self,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+        bits: &[Value<F>],
+        limbs: [Column<Advice>; 8],
+    ) -> Result<Vec<AssignedBit<F>>, Error> {
+        assert!(bits.len() <= 8, "A row holds up to eight bits");
+        self.q_boolean.enable(region, *offset)?;
+        let mut assigned_bits = Vec::with_capacity(bits.len());
+        for (index, limb) in limbs.iter().enumerate() {
+            let bit = bits.get(index).copied().unwrap_or(Value::known(F::ZERO));
+            let assigned_bit = AssignedBit::assign_advice_bit(region, "bit", *limb, *offset, bit)?;
+            if index < bits.len() {
+                assigned_bits.push(assigned_bit);
+            }
+        }
+        *offset += 1;
+        Ok(assigned_bits)
+    }
+}
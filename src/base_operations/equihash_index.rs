@@ -0,0 +1,128 @@
+use midnight_proofs::plonk::Constraints;
+use super::*;
+use crate::base_operations::nibble::NibbleConfig;
+use crate::base_operations::types::bit::AssignedBit;
+use crate::base_operations::types::blake2b_word::{AssignedBlake2bWord, Blake2bWord};
+use crate::base_operations::types::byte::{AssignedByte, Byte};
+
+/// This config handles the indices of an Equihash (200, 9) solution, which are numbers of 21
+/// bits. Every Blake2b digest of the puzzle holds the strings of two indices, so the index i is
+/// split in the index of its hash, i / 2, which is hashed as 4 little endian bytes, and the half
+/// of the digest that holds its string, i % 2:
+///
+/// | index | hash_index_0 | hash_index_1 | hash_index_2 | hash_index_3 | half | 0 | 0 | 0 |
+/// |       | hash_index_2 |     ...      |
+///
+/// The limbs are range checked, and the second row bounds the third byte of the hash index with
+/// the [NibbleConfig], so the hash index has 20 bits and the index has 21.
+#[derive(Clone, Debug)]
+pub(crate) struct EquihashIndexConfig {
+    q_equihash_index: Selector,
+    q_range: Selector,
+    nibble_config: NibbleConfig,
+}
+
+impl EquihashIndexConfig {
+    /// Creates the gate of the config
+    /// The gate is defined as:
+    ///    0 = index - half - 2 * sum [i=0..3] -> hash_index_i * (1 << (8*i))
+    ///    0 = half * (1 - half)
+    ///    0 = hash_index_3
+    pub(crate) fn configure<F: PrimeField>(
+        meta: &mut ConstraintSystem<F>,
+        full_number_u64: Column<Advice>,
+        limbs: [Column<Advice>; 8],
+        q_range: Selector,
+        nibble_config: NibbleConfig,
+    ) -> Self {
+        let q_equihash_index = meta.complex_selector();
+
+        meta.create_gate("equihash index", |meta| {
+            let q_equihash_index = meta.query_selector(q_equihash_index);
+            let index = meta.query_advice(full_number_u64, Rotation::cur());
+            let hash_index = (0..4).fold(Expression::Constant(F::ZERO), |sum, i| {
+                sum + meta.query_advice(limbs[i], Rotation::cur())
+                    * Expression::Constant(F::from(1 << (8 * i)))
+            });
+            let half = meta.query_advice(limbs[4], Rotation::cur());
+            let hash_index_3 = meta.query_advice(limbs[3], Rotation::cur());
+
+            let constraints = vec![
+                q_equihash_index.clone()
+                    * (index - half.clone() - Expression::Constant(F::from(2)) * hash_index),
+                q_equihash_index.clone() * half.clone() * (Expression::Constant(F::ONE) - half),
+                q_equihash_index * hash_index_3,
+            ];
+
+            Constraints::without_selector(constraints)
+        });
+
+        Self {
+            q_equihash_index,
+            q_range,
+            nibble_config,
+        }
+    }
+
+    /// This method receives the value of an index and returns the assigned index, the 4 bytes of
+    /// its hash index, from the least significant to the most significant one, and the bit of the
+    /// half of the digest.
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn generate_index_rows<F: PrimeField>(
+        &self,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+        index: Value<Blake2bWord>,
+        full_number_u64: Column<Advice>,
+        limbs: [Column<Advice>; 8],
+    ) -> Result<(AssignedBlake2bWord<F>, [AssignedByte<F>; 4], AssignedBit<F>), Error> {
+        self.q_equihash_index.enable(region, *offset)?;
+        self.q_range.enable(region, *offset)?;
+
+        let assigned_index = AssignedBlake2bWord::assign_advice_word(
+            region,
+            "equihash index",
+            full_number_u64,
+            *offset,
+            index,
+        )?;
+        let hash_index_bytes = (0..4)
+            .map(|i| {
+                let byte_value = index.map(|index| Byte((index.0 >> 1).to_le_bytes()[i]));
+                AssignedByte::assign_advice_byte(
+                    region,
+                    "hash index",
+                    limbs[i],
+                    *offset,
+                    byte_value,
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let half = AssignedBit::assign_advice_bit(
+            region,
+            "half of the digest",
+            limbs[4],
+            *offset,
+            index.map(|index| F::from(index.0 & 1)),
+        )?;
+        for limb in &limbs[5..] {
+            AssignedByte::assign_advice_byte(
+                region,
+                "zero",
+                *limb,
+                *offset,
+                Value::known(Byte(0)),
+            )?;
+        }
+        *offset += 1;
+
+        self.nibble_config.generate_nibble_row_from_byte(
+            region,
+            offset,
+            &hash_index_bytes[2],
+            limbs,
+        )?;
+
+        Ok((assigned_index, hash_index_bytes.try_into().unwrap(), half))
+    }
+}
@@ -7,6 +7,10 @@ use midnight_proofs::circuit::Value;
 
 /// This gate rotates the limbs of a number to the right and uses copy constrains to ensure that
 /// the rotation is correct. It's used in our circuit to implement 16-bit, 24-bit and 32-bit rotations.
+/// It also shifts the limbs of a number, filling the limbs that are shifted in with zeros, which
+/// together with the [BitRotationConfig] gives the rotations and shifts by any amount.
+///
+/// [BitRotationConfig]: crate::base_operations::bit_rotation::BitRotationConfig
 #[derive(Clone, Debug)]
 pub(crate) struct LimbRotation {
     q_decompose: Selector,
@@ -63,6 +67,153 @@ impl LimbRotation {
         Ok(result_cell)
     }
 
+    /// Same as [LimbRotation::generate_rotation_rows_from_input_row], but for 32-bit numbers that
+    /// are held with the 4 most significant limbs set to zero. The 4 least significant limbs are
+    /// rotated among themselves, and the zero limbs are copied in place.
+    /// For this method to work, the input_row must be the last row of the trace at the moment
+    /// the method is called
+    pub(crate) fn generate_32_bit_rotation_rows_from_input_row<F: PrimeField>(
+        &self,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+        input_row: AssignedRow<F>,
+        limbs_to_rotate_to_the_right: usize,
+        full_number_u64_column: Column<Advice>,
+        limbs: [Column<Advice>; 8],
+    ) -> Result<AssignedBlake2bWord<F>, Error> {
+        let result_value = input_row
+            .full_number
+            .value()
+            .map(|input| rotate_right_32_bit_word(input, limbs_to_rotate_to_the_right * 8));
+
+        let result_cell = region
+            .assign_advice(
+                || "Full number rotation output",
+                full_number_u64_column,
+                *offset,
+                || result_value,
+            )?
+            .into();
+
+        self.q_decompose.enable(region, *offset)?;
+
+        for i in 0..8 {
+            let out_limb_index = if i < 4 { (4 + i - limbs_to_rotate_to_the_right) % 4 } else { i };
+            AssignedByte::copy_advice_byte(
+                region,
+                "Limb rotation output",
+                limbs[out_limb_index],
+                *offset,
+                input_row.limbs[i].clone(),
+            )?;
+        }
+
+        *offset += 1;
+        Ok(result_cell)
+    }
+
+    /// This method receives a row of cells, and shifts the limbs to the right by the number
+    /// specified in the limbs_to_shift parameter, with zeros in the most significant limbs. It
+    /// then constrains the output to be the correct shift of the input.
+    pub(crate) fn generate_right_shift_rows_from_input_row<F: PrimeField>(
+        &self,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+        input_row: AssignedRow<F>,
+        limbs_to_shift: usize,
+        full_number_u64_column: Column<Advice>,
+        limbs: [Column<Advice>; 8],
+    ) -> Result<AssignedBlake2bWord<F>, Error> {
+        let result_value =
+            input_row.full_number.value().map(|input| Blake2bWord(input.0 >> (limbs_to_shift * 8)));
+        let input_limb_indexes: [Option<usize>; 8] =
+            std::array::from_fn(|i| Some(i + limbs_to_shift).filter(|index| *index < 8));
+        self.generate_shift_row(
+            region,
+            offset,
+            input_row,
+            input_limb_indexes,
+            result_value,
+            full_number_u64_column,
+            limbs,
+        )
+    }
+
+    /// Same as [LimbRotation::generate_right_shift_rows_from_input_row], but shifting the limbs to
+    /// the left, with zeros in the least significant limbs.
+    pub(crate) fn generate_left_shift_rows_from_input_row<F: PrimeField>(
+        &self,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+        input_row: AssignedRow<F>,
+        limbs_to_shift: usize,
+        full_number_u64_column: Column<Advice>,
+        limbs: [Column<Advice>; 8],
+    ) -> Result<AssignedBlake2bWord<F>, Error> {
+        let result_value =
+            input_row.full_number.value().map(|input| Blake2bWord(input.0 << (limbs_to_shift * 8)));
+        let input_limb_indexes: [Option<usize>; 8] =
+            std::array::from_fn(|i| i.checked_sub(limbs_to_shift));
+        self.generate_shift_row(
+            region,
+            offset,
+            input_row,
+            input_limb_indexes,
+            result_value,
+            full_number_u64_column,
+            limbs,
+        )
+    }
+
+    /// Puts in a new row the result of a shift, copying in every output limb the input limb at
+    /// the given index, or a zero when there's no such limb.
+    #[allow(clippy::too_many_arguments)]
+    fn generate_shift_row<F: PrimeField>(
+        &self,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+        input_row: AssignedRow<F>,
+        input_limb_indexes: [Option<usize>; 8],
+        result_value: Value<Blake2bWord>,
+        full_number_u64_column: Column<Advice>,
+        limbs: [Column<Advice>; 8],
+    ) -> Result<AssignedBlake2bWord<F>, Error> {
+        let result_cell = AssignedBlake2bWord::assign_advice_word(
+            region,
+            "Full number shift output",
+            full_number_u64_column,
+            *offset,
+            result_value,
+        )?;
+
+        self.q_decompose.enable(region, *offset)?;
+
+        for (out_limb_index, input_limb_index) in input_limb_indexes.into_iter().enumerate() {
+            match input_limb_index {
+                Some(index) => {
+                    AssignedByte::copy_advice_byte(
+                        region,
+                        "Limb shift output",
+                        limbs[out_limb_index],
+                        *offset,
+                        input_row.limbs[index].clone(),
+                    )?;
+                }
+                None => {
+                    region.assign_advice_from_constant(
+                        || "Limb shift zero",
+                        limbs[out_limb_index],
+                        *offset,
+                        F::ZERO,
+                    )?;
+                }
+            }
+        }
+
+        *offset += 1;
+        Ok(result_cell)
+    }
+
     /// Computes the actual value of the rotation of the number
     fn right_rotation_value(
         value: Value<Blake2bWord>,
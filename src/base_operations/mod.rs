@@ -6,11 +6,25 @@ use crate::base_operations::types::row::AssignedRow;
 use crate::base_operations::types::AssignedNative;
 use types::blake2b_word::Blake2bWord;
 
+pub mod addition_mod_32;
 pub mod addition_mod_64;
+pub mod bit_rotation;
+pub mod bitwise;
+pub mod blamka;
+pub mod boolean;
+pub mod equihash_index;
 pub mod negate;
+pub mod nibble;
+pub mod not_equal;
+pub mod pack;
+pub mod reference_index;
+pub mod select;
+pub mod variable_length_padding;
+pub mod word_product;
 pub mod xor;
 
 pub mod generic_limb_rotation;
+pub mod rotate_31;
 pub mod rotate_63;
 
 /// Given a [Blake2bWord], it returns another [Blake2bWord] with the original word rotated to the
@@ -23,6 +37,12 @@ fn rotate_right_field_element(value_to_rotate: Blake2bWord, rotation_degree: usi
     (rotated_value as u64).into()
 }
 
+/// Given a [Blake2bWord] holding a 32-bit number, it returns another [Blake2bWord] with the
+/// original number rotated to the right by 'rotation_degree' bits, as a 32-bit number.
+fn rotate_right_32_bit_word(value_to_rotate: Blake2bWord, rotation_degree: usize) -> Blake2bWord {
+    ((value_to_rotate.0 as u32).rotate_right(rotation_degree as u32) as u64).into()
+}
+
 /// Given an array of [AssignedNative] byte-values, it puts in the circuit a full row with those
 /// bytes in the limbs and the resulting full number in the first column.
 /// WARNING: this method doesn't set any constraints. That's the responsibility of the caller.
@@ -76,7 +96,7 @@ fn compute_full_value_u64_from_bytes<F: PrimeField>(bytes: &[AssignedNative<F>;
 /// Given a cell with a 64-bit value, it creates a new row with the copied full number and the
 /// decomposition in 8-bit limbs.
 /// WARNING: this method doesn't set any constraints. That's the responsibility of the caller.
-fn generate_row_from_cell<F: PrimeField>(
+pub(crate) fn generate_row_from_cell<F: PrimeField>(
     region: &mut Region<'_, F>,
     cell: &AssignedBlake2bWord<F>,
     offset: usize,
@@ -0,0 +1,51 @@
+use super::*;
+use crate::base_operations::types::byte::AssignedByte;
+
+/// This config constrains a byte to be smaller than 16, that is, to have its 4 most significant
+/// bits set to zero. The byte is copied to the first limb of a row and the lookup checks that 16
+/// times the byte is still in the 8-bit range table:
+///
+/// | | byte | ... |
+///
+/// The lookup only bounds the byte when it's known to be an integer in [0, 255], so the copied
+/// cell must already be range checked, which is the case for every [AssignedByte].
+#[derive(Clone, Debug)]
+pub(crate) struct NibbleConfig {
+    q_nibble: Selector,
+}
+
+impl NibbleConfig {
+    /// Creates the lookup of the config
+    /// The lookup is defined as:
+    ///    16 * byte in [0, 255]
+    pub(crate) fn configure<F: PrimeField>(
+        meta: &mut ConstraintSystem<F>,
+        limbs: [Column<Advice>; 8],
+        t_range: TableColumn,
+    ) -> Self {
+        let q_nibble = meta.complex_selector();
+
+        meta.lookup("nibble", |meta| {
+            let q_nibble = meta.query_selector(q_nibble);
+            let byte = meta.query_advice(limbs[0], Rotation::cur());
+            vec![(q_nibble * byte * Expression::Constant(F::from(16)), t_range)]
+        });
+
+        Self { q_nibble }
+    }
+
+    /// This method copies the given byte to a new row of the trace and constrains it to be
+    /// smaller than 16.
+    pub(crate) fn generate_nibble_row_from_byte<F: PrimeField>(
+        &self,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+        byte: &AssignedByte<F>,
+        limbs: [Column<Advice>; 8],
+    ) -> Result<(), Error> {
+        self.q_nibble.enable(region, *offset)?;
+        AssignedByte::copy_advice_byte(region, "nibble", limbs[0], *offset, byte.clone())?;
+        *offset += 1;
+        Ok(())
+    }
+}
@@ -0,0 +1,64 @@
+use midnight_proofs::plonk::Constraints;
+use super::*;
+use crate::base_operations::types::blake2b_word::AssignedBlake2bWord;
+
+/// This config constrains two pairs of words to hold different values, by witnessing the inverse
+/// of the difference of each pair, which only exists when the difference isn't zero. Both pairs
+/// are copied to a single row:
+///
+/// | | lhs_0 | rhs_0 | inverse_0 | lhs_1 | rhs_1 | inverse_1 | | |
+#[derive(Clone, Debug)]
+pub(crate) struct NotEqualConfig {
+    q_not_equal: Selector,
+}
+
+impl NotEqualConfig {
+    /// Creates the gate of the config
+    /// The gate is defined as:
+    ///    0 = (lhs_i - rhs_i) * inverse_i - 1, for i in [0, 1]
+    pub(crate) fn configure<F: PrimeField>(
+        meta: &mut ConstraintSystem<F>,
+        limbs: [Column<Advice>; 8],
+    ) -> Self {
+        let q_not_equal = meta.complex_selector();
+
+        meta.create_gate("not equal", |meta| {
+            let q_not_equal = meta.query_selector(q_not_equal);
+            let constraints = (0..2)
+                .map(|i| {
+                    let lhs = meta.query_advice(limbs[3 * i], Rotation::cur());
+                    let rhs = meta.query_advice(limbs[3 * i + 1], Rotation::cur());
+                    let inverse = meta.query_advice(limbs[3 * i + 2], Rotation::cur());
+                    q_not_equal.clone() * ((lhs - rhs) * inverse - Expression::Constant(F::ONE))
+                })
+                .collect::<Vec<_>>();
+
+            Constraints::without_selector(constraints)
+        });
+
+        Self { q_not_equal }
+    }
+
+    /// This method copies two pairs of words to a new row of the trace and constrains the words of
+    /// each pair to be different.
+    pub(crate) fn generate_not_equal_row_from_cells<F: PrimeField>(
+        &self,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+        pairs: [(&AssignedBlake2bWord<F>, &AssignedBlake2bWord<F>); 2],
+        limbs: [Column<Advice>; 8],
+    ) -> Result<(), Error> {
+        self.q_not_equal.enable(region, *offset)?;
+        for (i, (lhs, rhs)) in pairs.into_iter().enumerate() {
+            lhs.copy_advice_word(region, limbs[3 * i], *offset, "not equal lhs")?;
+            rhs.copy_advice_word(region, limbs[3 * i + 1], *offset, "not equal rhs")?;
+            let inverse = lhs
+                .value()
+                .zip(rhs.value())
+                .map(|(lhs, rhs)| (F::from(lhs.0) - F::from(rhs.0)).invert().unwrap_or(F::ZERO));
+            region.assign_advice(|| "not equal inverse", limbs[3 * i + 2], *offset, || inverse)?;
+        }
+        *offset += 1;
+        Ok(())
+    }
+}
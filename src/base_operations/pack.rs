@@ -0,0 +1,121 @@
+use midnight_proofs::plonk::Constraints;
+use super::*;
+use crate::base_operations::types::blake2b_word::AssignedBlake2bWord;
+
+/// Amount of 64-bit words packed in a row.
+pub(crate) const WORDS_PER_PACK: usize = 4;
+
+/// This config packs up to four 64-bit words into a single field element, in little-endian order,
+/// that is, the element is the sum of the words times increasing powers of 2^64. The words are
+/// copied to the first limbs of a row, and the packed element is placed in the limb after them.
+/// An element holding more significant words can be added to the pack, times 2^256, which
+/// allows to pack wider numbers modulo the field with a row per four words:
+///
+/// | | word_0 | word_1 | word_2 | word_3 | packed | high | | |
+///
+/// The words must already be range checked, and the element only has a single packing when its
+/// words fit below the capacity of the field, which is up to the caller.
+#[derive(Clone, Debug)]
+pub(crate) struct PackConfig {
+    q_pack: Selector,
+}
+
+impl PackConfig {
+    /// Creates the gate of the config
+    /// The gate is defined as:
+    ///    0 = packed - sum_i word_i * 2^(64 * i) - high * 2^256, for i in [0, 3]
+    pub(crate) fn configure<F: PrimeField>(
+        meta: &mut ConstraintSystem<F>,
+        limbs: [Column<Advice>; 8],
+    ) -> Self {
+        let q_pack = meta.complex_selector();
+
+        meta.create_gate("pack", |meta| {
+            let q_pack = meta.query_selector(q_pack);
+            let packed = meta.query_advice(limbs[WORDS_PER_PACK], Rotation::cur());
+            let high = meta.query_advice(limbs[WORDS_PER_PACK + 1], Rotation::cur());
+            let words = (0..WORDS_PER_PACK)
+                .map(|i| meta.query_advice(limbs[i], Rotation::cur()))
+                .zip(word_powers::<F>())
+                .fold(Expression::Constant(F::ZERO), |sum, (word, power)| {
+                    sum + word * Expression::Constant(power)
+                });
+
+            Constraints::without_selector(vec![
+                q_pack * (packed - words - high * Expression::Constant(two_pow_256::<F>())),
+            ])
+        });
+
+        Self { q_pack }
+    }
+
+    /// This method copies up to four words, and optionally the element with the more significant
+    /// words, to a new row of the trace and returns the cell with the element they pack. The
+    /// limbs of the missing words and of a missing high element are constrained to be zero.
+    pub(crate) fn generate_pack_row_from_cells<F: PrimeField>(
+        &self,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+        words: &[AssignedBlake2bWord<F>],
+        high: Option<&AssignedNative<F>>,
+        limbs: [Column<Advice>; 8],
+    ) -> Result<AssignedNative<F>, Error> {
+        assert!(words.len() <= WORDS_PER_PACK, "A row packs up to four words");
+        self.q_pack.enable(region, *offset)?;
+        let mut packed_value = Value::known(F::ZERO);
+        for (i, power) in word_powers::<F>().into_iter().enumerate() {
+            match words.get(i) {
+                Some(word) => {
+                    word.copy_advice_word(region, limbs[i], *offset, "packed word")?;
+                    packed_value = packed_value
+                        .zip(word.value())
+                        .map(|(packed, word)| packed + F::from(word.0) * power);
+                }
+                None => {
+                    region.assign_advice_from_constant(
+                        || "missing packed word",
+                        limbs[i],
+                        *offset,
+                        F::ZERO,
+                    )?;
+                }
+            }
+        }
+        match high {
+            Some(high) => {
+                high.copy_advice(|| "packed high", region, limbs[WORDS_PER_PACK + 1], *offset)?;
+                packed_value = packed_value
+                    .zip(high.value())
+                    .map(|(packed, high)| packed + *high * two_pow_256::<F>());
+            }
+            None => {
+                region.assign_advice_from_constant(
+                    || "missing packed high",
+                    limbs[WORDS_PER_PACK + 1],
+                    *offset,
+                    F::ZERO,
+                )?;
+            }
+        }
+        let packed =
+            region.assign_advice(|| "packed", limbs[WORDS_PER_PACK], *offset, || packed_value)?;
+        *offset += 1;
+        Ok(packed)
+    }
+}
+
+/// The factor of the high element of a pack, 2^256 reduced modulo the field.
+fn two_pow_256<F: PrimeField>() -> F {
+    F::from_u128(1 << 64).pow([4])
+}
+
+/// The powers of 2^64 that multiply the words of a pack.
+fn word_powers<F: PrimeField>() -> [F; WORDS_PER_PACK] {
+    let two_pow_64 = F::from_u128(1 << 64);
+    let mut power = F::ONE;
+    std::array::from_fn(|_| {
+        let current = power;
+        power *= two_pow_64;
+        current
+    })
+}
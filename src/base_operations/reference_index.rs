@@ -0,0 +1,150 @@
+use midnight_proofs::plonk::Constraints;
+use super::*;
+use crate::base_operations::types::bit::AssignedBit;
+use crate::base_operations::types::blake2b_word::{AssignedBlake2bWord, Blake2bWord};
+
+/// This config handles the mapping of the data-dependent addressing of Argon2, which takes the
+/// 32 least significant bits J1 of the first word of the previous block and computes
+///
+///    y = (reference_area_size * (J1 * J1 >> 32)) >> 32
+///
+/// The block that is referenced is the one at 'reference_area_size - 1 - y' positions from the
+/// start of the reference area. Everything happens in four rows of the trace:
+///
+/// | word         | limb_0 | ... | limb_7 |
+/// | square       | limb_0 | ... | limb_7 |
+/// | product      | limb_0 | ... | limb_7 |
+/// | area_size    | bit_0  | ... | bit_7  |
+///
+/// where 'square' is J1 * J1, 'product' is the reference area size times the 32 most significant
+/// bits of 'square', and the bits are the binary decomposition of y, which is the fifth limb of
+/// 'product'. The reference area size is a constant of the circuit and must be smaller than 256,
+/// so the three most significant limbs of 'product' are zero.
+#[derive(Clone, Debug)]
+pub(crate) struct ReferenceIndexConfig {
+    q_reference_index: Selector,
+    q_decompose: Selector,
+    q_range: Selector,
+}
+
+impl ReferenceIndexConfig {
+    /// Creates the gate of the config
+    /// The gate is defined as:
+    ///    0 = square - lo(word) * lo(word)
+    ///    0 = product - area_size * hi(square)
+    ///    0 = limb_i_product, for i in [5, 7]
+    ///    0 = limb_4_product - sum [i=0..7] -> bit_i * (1 << i)
+    ///    0 = bit_i * (1 - bit_i), for i in [0, 7]
+    /// where lo(x) and hi(x) are the numbers formed by the 4 least and most significant limbs of
+    /// x. The first three rows are decomposed and range checked with the q_decompose and q_range
+    /// selectors below.
+    pub(crate) fn configure<F: PrimeField>(
+        meta: &mut ConstraintSystem<F>,
+        full_number_u64: Column<Advice>,
+        limbs: [Column<Advice>; 8],
+        q_decompose: Selector,
+        q_range: Selector,
+    ) -> Self {
+        let q_reference_index = meta.complex_selector();
+
+        meta.create_gate("argon2 reference index", |meta| {
+            let q_reference_index = meta.query_selector(q_reference_index);
+            let mut half_of_row = |rotation: i32, first_limb: usize| {
+                (0..4).fold(Expression::Constant(F::ZERO), |sum, i| {
+                    sum + meta.query_advice(limbs[first_limb + i], Rotation(rotation))
+                        * Expression::Constant(F::from(1 << (8 * i)))
+                })
+            };
+            let low_word = half_of_row(0, 0);
+            let high_square = half_of_row(1, 4);
+            let square = meta.query_advice(full_number_u64, Rotation(1));
+            let product = meta.query_advice(full_number_u64, Rotation(2));
+            let area_size = meta.query_advice(full_number_u64, Rotation(3));
+            let one = Expression::Constant(F::ONE);
+
+            let mut constraints = vec![
+                q_reference_index.clone() * (square - low_word.clone() * low_word),
+                q_reference_index.clone() * (product - area_size * high_square),
+            ];
+            for limb in &limbs[5..] {
+                constraints.push(q_reference_index.clone() * meta.query_advice(*limb, Rotation(2)));
+            }
+            let mut bits_sum = Expression::Constant(F::ZERO);
+            for (i, limb) in limbs.iter().enumerate() {
+                let bit = meta.query_advice(*limb, Rotation(3));
+                constraints
+                    .push(q_reference_index.clone() * bit.clone() * (one.clone() - bit.clone()));
+                bits_sum = bits_sum + bit * Expression::Constant(F::from(1 << i));
+            }
+            let limb_4_product = meta.query_advice(limbs[4], Rotation(2));
+            constraints.push(q_reference_index * (limb_4_product - bits_sum));
+
+            Constraints::without_selector(constraints)
+        });
+
+        Self {
+            q_reference_index,
+            q_decompose,
+            q_range,
+        }
+    }
+
+    /// This method receives the first word of the previous block and the reference area size,
+    /// and returns the bits of y, from the least significant to the most significant one. The
+    /// word is copied to a new row of the trace and the area size is assigned as a constant.
+    pub(crate) fn generate_reference_index_rows_from_cell<F: PrimeField>(
+        &self,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+        first_word: &AssignedBlake2bWord<F>,
+        reference_area_size: u64,
+        full_number_u64: Column<Advice>,
+        limbs: [Column<Advice>; 8],
+    ) -> Result<[AssignedBit<F>; 8], Error> {
+        assert!(reference_area_size < 256, "The reference area must have less than 256 blocks");
+        self.q_reference_index.enable(region, *offset)?;
+
+        self.q_decompose.enable(region, *offset)?;
+        self.q_range.enable(region, *offset)?;
+        generate_row_from_cell(region, first_word, *offset, full_number_u64, limbs)?;
+        *offset += 1;
+
+        let square_value = first_word.value().map(|word| {
+            let low_word = word.0 as u32 as u64;
+            Blake2bWord(low_word * low_word)
+        });
+        let product_value =
+            square_value.map(|square| Blake2bWord(reference_area_size * (square.0 >> 32)));
+        for value in [square_value, product_value] {
+            self.q_decompose.enable(region, *offset)?;
+            self.q_range.enable(region, *offset)?;
+            generate_row_from_word_value(region, value, *offset, full_number_u64, limbs)?;
+            *offset += 1;
+        }
+
+        AssignedBlake2bWord::assign_fixed_word(
+            region,
+            "reference area size",
+            full_number_u64,
+            *offset,
+            Blake2bWord(reference_area_size),
+        )?;
+        let bits = limbs
+            .iter()
+            .enumerate()
+            .map(|(i, limb)| {
+                let bit_value = product_value.map(|product| F::from((product.0 >> (32 + i)) & 1));
+                AssignedBit::assign_advice_bit(
+                    region,
+                    "reference index bit",
+                    *limb,
+                    *offset,
+                    bit_value,
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        *offset += 1;
+
+        Ok(bits.try_into().unwrap())
+    }
+}
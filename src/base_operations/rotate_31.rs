@@ -0,0 +1,82 @@
+use midnight_proofs::plonk::Constraints;
+use super::*;
+use crate::base_operations::types::blake2b_word::AssignedBlake2bWord;
+
+/// This config handles the 31-right-bit rotation of a 32-bit number, which is the same as the
+/// 1-bit rotation to the left. It works like the [Rotate63Config], over 32-bit numbers that are
+/// held in the same rows as the 64-bit ones, with the 4 most significant limbs set to zero.
+///
+/// As in the [Rotate63Config], the gate assumes that the input is already range checked in the
+/// circuit, and it must be placed in the row right before the output. In the context of Blake2s,
+/// the input is always the output of a limb rotation, whose limbs are range checked.
+///
+/// [Rotate63Config]: crate::base_operations::rotate_63::Rotate63Config
+#[derive(Clone, Debug)]
+pub(crate) struct Rotate31Config {
+    pub q_rot31: Selector,
+    q_decompose: Selector,
+    q_range: Selector,
+}
+
+impl Rotate31Config {
+    /// The gate that will be used to rotate a number 31 bits to the right
+    /// The gate is defined as:
+    ///    0 = 2 * input_full_number - output_full_number
+    ///                      * (2 * input_full_number - output_full_number - (1 << 32 - 1))
+    ///    0 = limb_i_output, for i in [4, 7]
+    pub(crate) fn configure<F: PrimeField>(
+        meta: &mut ConstraintSystem<F>,
+        full_number_u64: Column<Advice>,
+        limbs: [Column<Advice>; 8],
+        q_decompose: Selector,
+        q_range: Selector,
+    ) -> Self {
+        let q_rot31 = meta.complex_selector();
+
+        meta.create_gate("rotate right 31", |meta| {
+            let q_rot31 = meta.query_selector(q_rot31);
+            let input_full_number = meta.query_advice(full_number_u64, Rotation(-1));
+            let output_full_number = meta.query_advice(full_number_u64, Rotation(0));
+            let mut constraints = vec![
+                q_rot31.clone()
+                    * (Expression::Constant(F::from(2)) * input_full_number.clone()
+                        - output_full_number.clone())
+                    * (Expression::Constant(F::from(2)) * input_full_number
+                        - output_full_number
+                        - Expression::Constant(F::from((1u64 << 32) - 1))),
+            ];
+            for limb in &limbs[4..] {
+                constraints.push(q_rot31.clone() * meta.query_advice(*limb, Rotation(0)));
+            }
+            Constraints::without_selector(constraints)
+        });
+
+        Self {
+            q_rot31,
+            q_decompose,
+            q_range,
+        }
+    }
+
+    /// This method receives a [AssignedBlake2bWord] holding a 32-bit number, which must be the
+    /// full number of the last row of the trace. The result is placed in the next row, and the
+    /// gate constrains it.
+    pub(crate) fn generate_32_bit_rotation_from_cells<F: PrimeField>(
+        &self,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+        input: &AssignedBlake2bWord<F>,
+        full_number_u64: Column<Advice>,
+        limbs: [Column<Advice>; 8],
+    ) -> Result<AssignedBlake2bWord<F>, Error> {
+        self.q_rot31.enable(region, *offset)?;
+        let result_value = input.value().map(|input| rotate_right_32_bit_word(input, 31));
+
+        self.q_decompose.enable(region, *offset)?;
+        self.q_range.enable(region, *offset)?;
+        let result_row =
+            generate_row_from_word_value(region, result_value, *offset, full_number_u64, limbs)?;
+        *offset += 1;
+        Ok(result_row.full_number)
+    }
+}
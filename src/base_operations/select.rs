@@ -0,0 +1,83 @@
+use midnight_proofs::plonk::Constraints;
+use super::*;
+use crate::base_operations::types::bit::AssignedBit;
+use crate::base_operations::types::blake2b_word::AssignedBlake2bWord;
+
+/// This config handles the selection between two 64-bit numbers depending on a condition bit.
+/// Everything happens in a single row of the trace:
+///
+/// | result | condition | when_false | when_true |
+///
+/// This gate assumes that the condition is already constrained to be a bit and that both
+/// operands are already range checked in the circuit. Under those assumptions the result is
+/// one of the operands, so it doesn't need to be range checked again.
+#[derive(Clone, Debug)]
+pub(crate) struct SelectConfig {
+    q_select: Selector,
+}
+
+impl SelectConfig {
+    /// The gate that will be used to select a number
+    /// The gate is defined as:
+    ///    0 = when_false + condition * (when_true - when_false) - result
+    pub(crate) fn configure<F: PrimeField>(
+        meta: &mut ConstraintSystem<F>,
+        full_number_u64: Column<Advice>,
+        limbs: [Column<Advice>; 8],
+    ) -> Self {
+        let q_select = meta.complex_selector();
+
+        meta.create_gate("select", |meta| {
+            let q_select = meta.query_selector(q_select);
+            let result = meta.query_advice(full_number_u64, Rotation(0));
+            let condition = meta.query_advice(limbs[0], Rotation(0));
+            let when_false = meta.query_advice(limbs[1], Rotation(0));
+            let when_true = meta.query_advice(limbs[2], Rotation(0));
+
+            let constraints = vec![
+                q_select * (when_false.clone() + condition * (when_true - when_false) - result),
+            ];
+            Constraints::without_selector(constraints)
+        });
+
+        Self { q_select }
+    }
+
+    /// This method receives a condition bit and two [AssignedBlake2bWord], copies them to the
+    /// limbs of a new row and places the selected word in the full number column of that row.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn generate_select_row_from_cells<F: PrimeField>(
+        &self,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+        condition: &AssignedBit<F>,
+        when_false: &AssignedBlake2bWord<F>,
+        when_true: &AssignedBlake2bWord<F>,
+        full_number_u64: Column<Advice>,
+        limbs: [Column<Advice>; 8],
+    ) -> Result<AssignedBlake2bWord<F>, Error> {
+        self.q_select.enable(region, *offset)?;
+        condition.copy_advice_bit(region, limbs[0], *offset, "Select condition")?;
+        when_false.copy_advice_word(region, limbs[1], *offset, "Select when false")?;
+        when_true.copy_advice_word(region, limbs[2], *offset, "Select when true")?;
+
+        let result_value = condition.value().zip(when_false.value()).zip(when_true.value()).map(
+            |((condition, when_false), when_true)| {
+                if condition.0 {
+                    when_true
+                } else {
+                    when_false
+                }
+            },
+        );
+        let result = AssignedBlake2bWord::assign_advice_word(
+            region,
+            "Select output",
+            full_number_u64,
+            *offset,
+            result_value,
+        )?;
+        *offset += 1;
+        Ok(result)
+    }
+}
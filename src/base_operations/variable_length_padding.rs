@@ -0,0 +1,190 @@
+use midnight_proofs::plonk::Constraints;
+use super::*;
+use crate::base_operations::types::bit::AssignedBit;
+use crate::base_operations::types::blake2b_word::{AssignedBlake2bWord, Blake2bWord};
+
+/// This config constrains the padding of a byte string whose length is only known at proof
+/// generation time. Every row holding 8 bytes of the string is followed by a row holding a flag
+/// for each of those bytes, which is 1 if the byte is part of the string and 0 if it's padding:
+///
+/// | previous_count | previous_flag_0 | ... | previous_flag_7 |
+/// | full_number    | byte_0          | ... | byte_7          |
+/// | count          | flag_0          | ... | flag_7          |
+///
+/// The gate constrains the flags to be bits in decreasing order (all the ones of the string come
+/// before all the zeros), every byte with a 0 flag to be 0, and the count to be the previous
+/// count plus the amount of flags set in the row. This way, the count of the last flags row is
+/// the length of the string, and the count at the end of each block is the amount of bytes
+/// processed until that block.
+///
+/// The first flags row of a string needs a previous row with count 0 and last flag 1, which is
+/// created by [VariableLengthPaddingConfig::generate_initial_row].
+#[derive(Clone, Debug)]
+pub(crate) struct VariableLengthPaddingConfig {
+    q_padding: Selector,
+    q_last_block: Selector,
+}
+
+impl VariableLengthPaddingConfig {
+    /// Creates the two gates of the config.
+    /// The padding gate is defined as:
+    ///    0 = flag_i * (1 - flag_i)                  for every flag
+    ///    0 = flag_0 * (1 - previous_flag_7)
+    ///    0 = flag_i * (1 - flag_(i-1))              for i in [1, 7]
+    ///    0 = byte_i * (1 - flag_i)                  for every byte
+    ///    0 = count - previous_count - sum(flag_i)
+    ///
+    /// The last block gate computes whether a block is the last one of the string, from the
+    /// flags of the first byte of the block and the first byte of the next block:
+    ///    0 = is_last_block - first_flag + next_first_flag
+    /// Since the flags are in decreasing order, the result is always a bit.
+    pub(crate) fn configure<F: PrimeField>(
+        meta: &mut ConstraintSystem<F>,
+        full_number_u64: Column<Advice>,
+        limbs: [Column<Advice>; 8],
+    ) -> Self {
+        let q_padding = meta.complex_selector();
+        let q_last_block = meta.complex_selector();
+
+        meta.create_gate("variable length padding", |meta| {
+            let q_padding = meta.query_selector(q_padding);
+            let previous_count = meta.query_advice(full_number_u64, Rotation(-2));
+            let previous_flag = meta.query_advice(limbs[7], Rotation(-2));
+            let count = meta.query_advice(full_number_u64, Rotation(0));
+            let bytes: Vec<Expression<F>> =
+                limbs.iter().map(|column| meta.query_advice(*column, Rotation(-1))).collect();
+            let flags: Vec<Expression<F>> =
+                limbs.iter().map(|column| meta.query_advice(*column, Rotation(0))).collect();
+            let one = Expression::Constant(F::ONE);
+
+            let mut constraints = vec![];
+            for flag in flags.iter() {
+                constraints.push(q_padding.clone() * flag.clone() * (one.clone() - flag.clone()));
+            }
+            constraints.push(q_padding.clone() * flags[0].clone() * (one.clone() - previous_flag));
+            for i in 1..8 {
+                constraints.push(
+                    q_padding.clone() * flags[i].clone() * (one.clone() - flags[i - 1].clone()),
+                );
+            }
+            for (byte, flag) in bytes.iter().zip(flags.iter()) {
+                constraints.push(q_padding.clone() * byte.clone() * (one.clone() - flag.clone()));
+            }
+            let flags_sum =
+                flags.iter().fold(Expression::Constant(F::ZERO), |sum, flag| sum + flag.clone());
+            constraints.push(q_padding * (count - previous_count - flags_sum));
+
+            Constraints::without_selector(constraints)
+        });
+
+        meta.create_gate("last block", |meta| {
+            let q_last_block = meta.query_selector(q_last_block);
+            let is_last_block = meta.query_advice(full_number_u64, Rotation(0));
+            let first_flag = meta.query_advice(limbs[0], Rotation(0));
+            let next_first_flag = meta.query_advice(limbs[1], Rotation(0));
+
+            let constraints = vec![q_last_block * (is_last_block - first_flag + next_first_flag)];
+            Constraints::without_selector(constraints)
+        });
+
+        Self {
+            q_padding,
+            q_last_block,
+        }
+    }
+
+    /// This method creates the row that precedes the first flags row of a string. It holds a
+    /// count of 0 and a last flag of 1, both constants.
+    pub(crate) fn generate_initial_row<F: PrimeField>(
+        &self,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+        full_number_u64: Column<Advice>,
+        limbs: [Column<Advice>; 8],
+    ) -> Result<(), Error> {
+        AssignedBlake2bWord::assign_fixed_word(
+            region,
+            "Initial count",
+            full_number_u64,
+            *offset,
+            0u64.into(),
+        )?;
+        AssignedBit::assign_fixed_bit(region, "Initial flag", limbs[7], *offset, true)?;
+        *offset += 1;
+        Ok(())
+    }
+
+    /// This method creates the flags row of the 8 bytes placed in the previous row of the trace,
+    /// so for this method to work the row with the bytes must be the last row of the trace at the
+    /// moment the method is called. The 'first_byte_position' is the position in the string of
+    /// the first of those bytes, and 'length' is the length of the string.
+    /// It returns the count of the row and its flags.
+    pub(crate) fn generate_flags_row<F: PrimeField>(
+        &self,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+        first_byte_position: usize,
+        length: Value<Blake2bWord>,
+        full_number_u64: Column<Advice>,
+        limbs: [Column<Advice>; 8],
+    ) -> Result<(AssignedBlake2bWord<F>, [AssignedBit<F>; 8]), Error> {
+        self.q_padding.enable(region, *offset)?;
+
+        let count_value = length.map(|length| length.0.min(first_byte_position as u64 + 8).into());
+        let count = AssignedBlake2bWord::assign_advice_word(
+            region,
+            "Padding count",
+            full_number_u64,
+            *offset,
+            count_value,
+        )?;
+
+        let flags = (0..8)
+            .map(|i| {
+                let flag_value = length
+                    .map(|length| F::from((((first_byte_position + i) as u64) < length.0) as u64));
+                AssignedBit::assign_advice_bit(
+                    region,
+                    "Padding flag",
+                    limbs[i],
+                    *offset,
+                    flag_value,
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        *offset += 1;
+        Ok((count, flags.try_into().unwrap()))
+    }
+
+    /// This method receives the flag of the first byte of a block and the flag of the first byte
+    /// of the next block, and returns a bit that is 1 if and only if the block is the last one
+    /// holding bytes of the string.
+    pub(crate) fn generate_last_block_row<F: PrimeField>(
+        &self,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+        first_flag: &AssignedBit<F>,
+        next_first_flag: &AssignedBit<F>,
+        full_number_u64: Column<Advice>,
+        limbs: [Column<Advice>; 8],
+    ) -> Result<AssignedBit<F>, Error> {
+        self.q_last_block.enable(region, *offset)?;
+        first_flag.copy_advice_bit(region, limbs[0], *offset, "First flag of the block")?;
+        next_first_flag.copy_advice_bit(region, limbs[1], *offset, "First flag of next block")?;
+
+        let is_last_block_value = first_flag
+            .value()
+            .zip(next_first_flag.value())
+            .map(|(first, next)| F::from(first.0 as u64) - F::from(next.0 as u64));
+        let is_last_block = AssignedBit::assign_advice_bit(
+            region,
+            "Is last block",
+            full_number_u64,
+            *offset,
+            is_last_block_value,
+        )?;
+        *offset += 1;
+        Ok(is_last_block)
+    }
+}
@@ -0,0 +1,108 @@
+use midnight_proofs::plonk::Constraints;
+use super::*;
+
+/// Amount of words of the right-hand side of a product.
+pub(crate) const PRODUCT_RHS_WORDS: usize = 4;
+
+/// This config constrains a column of the schoolbook product of two numbers written in 64-bit
+/// words, which allows to check products and sums of numbers wider than the field. The column k
+/// of 'lhs * rhs + addend = target' is:
+///
+///    sum_j lhs_{k - j} * rhs_j + addend_k + carry_{k - 1} = target_k + 2^64 * carry_k
+///
+/// and it uses two rows, the first one with the carry out of the column, the words of the
+/// left-hand side that are multiplied in it and the up to four words of the right-hand side, and
+/// the second one with the carry into the column, the words of the addend and the target:
+///
+/// | carry_k     | lhs_k    | lhs_k-1  | lhs_k-2 | lhs_k-3 | rhs_0 | rhs_1 | rhs_2 | rhs_3 |
+/// | carry_k-1   | addend_k | target_k |         |         |       |       |       |       |
+///
+/// The gate holds as an equation between integers when every word is range checked and every
+/// carry is smaller than 2^128, which is up to the caller, since both sides of the equation are
+/// then much smaller than the modulus of the field. If every column of a product holds, and the
+/// last carry is zero, then 'lhs * rhs + addend = target' holds as integers.
+#[derive(Clone, Debug)]
+pub(crate) struct WordProductConfig {
+    q_word_product: Selector,
+}
+
+impl WordProductConfig {
+    /// Creates the gate of the config
+    /// The gate is defined as:
+    ///    0 = sum_j lhs_{k - j} * rhs_j + addend_k + carry_{k - 1} - target_k - 2^64 * carry_k
+    pub(crate) fn configure<F: PrimeField>(
+        meta: &mut ConstraintSystem<F>,
+        full_number_u64: Column<Advice>,
+        limbs: [Column<Advice>; 8],
+    ) -> Self {
+        let q_word_product = meta.complex_selector();
+
+        meta.create_gate("word product", |meta| {
+            let q_word_product = meta.query_selector(q_word_product);
+            let carry = meta.query_advice(full_number_u64, Rotation::cur());
+            let previous_carry = meta.query_advice(full_number_u64, Rotation::next());
+            let addend = meta.query_advice(limbs[0], Rotation::next());
+            let target = meta.query_advice(limbs[1], Rotation::next());
+            let products = (0..PRODUCT_RHS_WORDS)
+                .map(|j| {
+                    meta.query_advice(limbs[j], Rotation::cur())
+                        * meta.query_advice(limbs[PRODUCT_RHS_WORDS + j], Rotation::cur())
+                })
+                .fold(Expression::Constant(F::ZERO), |sum, product| sum + product);
+
+            Constraints::without_selector(vec![
+                q_word_product
+                    * (products + addend + previous_carry
+                        - target
+                        - carry * Expression::Constant(F::from_u128(1 << 64))),
+            ])
+        });
+
+        Self { q_word_product }
+    }
+
+    /// This method copies the cells of a column of a product to two new rows of the trace, where
+    /// 'lhs' holds the words lhs_k to lhs_{k - 3} and 'rhs' the words rhs_0 to rhs_3, and assigns
+    /// the carry out of the column with the given value.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn generate_word_product_rows<F: PrimeField>(
+        &self,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+        lhs: [&AssignedNative<F>; PRODUCT_RHS_WORDS],
+        rhs: [&AssignedNative<F>; PRODUCT_RHS_WORDS],
+        addend: &AssignedNative<F>,
+        target: &AssignedNative<F>,
+        previous_carry: &AssignedNative<F>,
+        carry_value: Value<F>,
+        full_number_u64: Column<Advice>,
+        limbs: [Column<Advice>; 8],
+    ) -> Result<AssignedNative<F>, Error> {
+        self.q_word_product.enable(region, *offset)?;
+        let carry = region.assign_advice(
+            || "word product carry",
+            full_number_u64,
+            *offset,
+            || carry_value,
+        )?;
+        for j in 0..PRODUCT_RHS_WORDS {
+            lhs[j].copy_advice(|| "word product lhs", region, limbs[j], *offset)?;
+            rhs[j].copy_advice(
+                || "word product rhs",
+                region,
+                limbs[PRODUCT_RHS_WORDS + j],
+                *offset,
+            )?;
+        }
+        previous_carry.copy_advice(
+            || "word product carry in",
+            region,
+            full_number_u64,
+            *offset + 1,
+        )?;
+        addend.copy_advice(|| "word product addend", region, limbs[0], *offset + 1)?;
+        target.copy_advice(|| "word product target", region, limbs[1], *offset + 1)?;
+        *offset += 2;
+        Ok(carry)
+    }
+}
@@ -3,25 +3,34 @@ use std::marker::PhantomData;
 use crate::base_operations::addition_mod_64::AdditionMod64Config;
 use crate::base_operations::generic_limb_rotation::LimbRotation;
 use crate::base_operations::negate::NegateConfig;
+use crate::base_operations::pack::PackConfig;
 use crate::base_operations::rotate_63::Rotate63Config;
-use crate::types::blake2b_word::AssignedBlake2bWord;
+use crate::base_operations::select::SelectConfig;
+use crate::base_operations::variable_length_padding::VariableLengthPaddingConfig;
+use crate::base_operations::word_product::{WordProductConfig, PRODUCT_RHS_WORDS};
+use crate::types::bit::AssignedBit;
+use crate::types::blake2b_word::{AssignedBlake2bWord, Blake2bWord};
 use crate::types::byte::AssignedByte;
 use crate::types::row::AssignedRow;
-use crate::types::AssignedNative;
+use crate::types::{enforce_little_endian_representation, AssignedNative};
 use crate::base_operations::xor::XorConfig;
 use crate::base_operations::{
     create_limb_decomposition_gate, create_range_check_gate, generate_row_from_assigned_bytes,
-    populate_lookup_table,
+    generate_row_from_cell, generate_row_from_word_value, populate_lookup_table,
+};
+use crate::blake2b::blake2b_instructions::{
+    Blake2bInstructions, ConstantCells, MidstateConstantCells, VariableLengthConstantCells,
 };
-use crate::blake2b::blake2b_instructions::{Blake2bInstructions, ConstantCells};
 use crate::blake2b::utils::{
     compute_processed_bytes_count_value_for_iteration, constrain_padding_cells_to_equal_zero,
-    enforce_input_sizes, full_number_of_each_state_row, get_total_blocks_count,
-    zeros_to_pad_in_current_block, ABCD, BLAKE2B_BLOCK_SIZE, IV_CONSTANTS, SIGMA,
+    enforce_input_sizes, enforce_private_key_size, full_number_of_each_state_row,
+    get_total_blocks_count, get_variable_input_blocks_count, zeros_to_pad_in_current_block, ABCD,
+    BLAKE2B_BLOCK_SIZE, IV_CONSTANTS, SIGMA,
 };
+use crate::blake2b::params::{param_bytes_to_words, Blake2bParams, ParamBytes};
 use crate::blake2b::NB_BLAKE2B_ADVICE_COLS;
 use ff::{Field, PrimeField};
-use midnight_proofs::circuit::{Chip, Layouter, Region};
+use midnight_proofs::circuit::{Chip, Layouter, Region, Value};
 use midnight_proofs::plonk::{Advice, Column, ConstraintSystem, Error, Fixed, Selector, TableColumn};
 
 /// Selectors and columns for the blake2b chip implementation.
@@ -33,14 +42,18 @@ pub struct Blake2bConfig {
     rotate_63_config: Rotate63Config,
     xor_config: XorConfig,
     negate_config: NegateConfig,
+    select_config: SelectConfig,
+    variable_length_padding_config: VariableLengthPaddingConfig,
+    pack_config: Option<PackConfig>,
+    word_product_config: Option<WordProductConfig>,
     /// Advice columns
-    full_number_u64: Column<Advice>,
+    pub(crate) full_number_u64: Column<Advice>,
     /// Columns for the blake2b limbs.
     pub limbs: [Column<Advice>; 8],
     /// Decomposition selectors
-    q_range: Selector,
-    q_decompose: Selector,
-    t_range: TableColumn,
+    pub(crate) q_range: Selector,
+    pub(crate) q_decompose: Selector,
+    pub(crate) t_range: TableColumn,
 }
 
 /// This is the main chip for the Blake2b hash function. It is responsible for the entire hash computation.
@@ -55,6 +68,16 @@ pub struct Blake2bChip<F> {
     _marker: PhantomData<F>,
 }
 
+/// A block of an input whose length is only known at proof generation time. Besides the words
+/// of the block, it holds the amount of input bytes processed until the end of the block and the
+/// padding flag of the first byte of the block.
+#[derive(Debug)]
+struct VariableLengthBlock<F: PrimeField> {
+    words: [AssignedBlake2bWord<F>; 16],
+    processed_bytes_count: AssignedBlake2bWord<F>,
+    first_flag: AssignedBit<F>,
+}
+
 impl<F: Field> Chip<F> for Blake2bChip<F> {
     type Config = Blake2bConfig;
     type Loaded = ();
@@ -81,7 +104,7 @@ impl<F: PrimeField> Blake2bInstructions<F> for Blake2bChip<F> {
     /// cells at the begining of the trace.
     fn assign_constant_advice_cells(
         &self,
-        output_size: usize,
+        params: &Blake2bParams<F>,
         key_size: usize,
         region: &mut Region<'_, F>,
         advice_offset: &mut usize,
@@ -96,12 +119,10 @@ impl<F: PrimeField> Blake2bInstructions<F> for Blake2bChip<F> {
             F::from(0),
         )?;
 
-        let iv_constant_0 = IV_CONSTANTS[0];
-        let out_len = output_size as u64;
-        const INIT_CONST_STATE_0: u64 = 0x01010000u64;
-        let key_size_shifted = (key_size as u64) << 8;
+        // In sequential mode,
         // state[0] = state[0] ^ 0x01010000 ^ (key.len() << 8) as u64 ^ outlen as u64;
-        let initial_state_index_0 = iv_constant_0 ^ INIT_CONST_STATE_0 ^ key_size_shifted ^ out_len;
+        let initial_state_index_0 =
+            IV_CONSTANTS[0] ^ params.constant_parameter_block_words(key_size)[0];
 
         let initial_state_0 = self.assign_limb_constant_u64(
             region,
@@ -135,6 +156,8 @@ impl<F: PrimeField> Blake2bInstructions<F> for Blake2bChip<F> {
         offset: &mut usize,
         input: &[AssignedNative<F>],
         key: &[AssignedNative<F>],
+        key_length: Option<&AssignedNative<F>>,
+        is_last_node: bool,
         iv_constants: &[AssignedBlake2bWord<F>; 8],
         global_state: &mut [AssignedBlake2bWord<F>; 8],
         zero_constant_cell: AssignedNative<F>,
@@ -162,34 +185,51 @@ impl<F: PrimeField> Blake2bInstructions<F> for Blake2bChip<F> {
                     is_key_empty,
                 );
 
-                let amount_of_zeros_to_pad =
-                    zeros_to_pad_in_current_block(key, input_size, is_last_block, is_key_block);
-
-                let current_block_values = Self::build_values_for_current_block(
-                    input,
-                    key,
-                    i,
-                    last_input_block_index,
-                    is_key_empty,
-                    is_last_block,
-                    is_key_block,
-                    zero_constant_cell.clone(),
-                );
-
-                let current_block_rows = self.block_words_from_bytes(
-                    region,
-                    offset,
-                    current_block_values.try_into().unwrap(),
-                )?;
-
-                constrain_padding_cells_to_equal_zero(
-                    region,
-                    amount_of_zeros_to_pad,
-                    &current_block_rows,
-                    &zero_constant_cell,
-                )?;
-
-                let current_block_cells = full_number_of_each_state_row(current_block_rows);
+                let current_block_cells = match key_length {
+                    // The padding of the key block depends on a length known at proof generation
+                    // time, so it's constrained with padding flags.
+                    Some(key_length) if is_key_block => self.key_block_words_with_private_length(
+                        region,
+                        offset,
+                        key,
+                        key_length,
+                        &zero_constant_cell,
+                    )?,
+                    _ => {
+                        let amount_of_zeros_to_pad = zeros_to_pad_in_current_block(
+                            key,
+                            input_size,
+                            is_last_block,
+                            is_key_block,
+                        );
+
+                        let current_block_values = Self::build_values_for_current_block(
+                            input,
+                            key,
+                            i,
+                            last_input_block_index,
+                            is_key_empty,
+                            is_last_block,
+                            is_key_block,
+                            zero_constant_cell.clone(),
+                        );
+
+                        let current_block_rows = self.block_words_from_bytes(
+                            region,
+                            offset,
+                            current_block_values.try_into().unwrap(),
+                        )?;
+
+                        constrain_padding_cells_to_equal_zero(
+                            region,
+                            amount_of_zeros_to_pad,
+                            &current_block_rows,
+                            &zero_constant_cell,
+                        )?;
+
+                        full_number_of_each_state_row(current_block_rows)
+                    }
+                };
 
                 self.compress(
                     region,
@@ -199,6 +239,7 @@ impl<F: PrimeField> Blake2bInstructions<F> for Blake2bChip<F> {
                     current_block_cells,
                     processed_bytes_count,
                     is_last_block,
+                    is_last_node,
                 )
             })
             .last()
@@ -206,6 +247,161 @@ impl<F: PrimeField> Blake2bInstructions<F> for Blake2bChip<F> {
             .expect("unexpected empty sequence of blake2b blocks")
     }
 
+    #[allow(clippy::too_many_arguments)]
+    fn perform_blake2b_iterations_with_variable_input_length(
+        &self,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+        input: &[AssignedNative<F>],
+        input_length: &AssignedNative<F>,
+        key: &[AssignedNative<F>],
+        iv_constants: &[AssignedBlake2bWord<F>; 8],
+        global_state: &mut [AssignedBlake2bWord<F>; 8],
+        zero_constant_cell: AssignedNative<F>,
+    ) -> Result<[AssignedByte<F>; 64], Error> {
+        let is_key_empty = key.is_empty();
+        let input_blocks = get_variable_input_blocks_count(input.len(), is_key_empty);
+
+        let (one_bit, zero_bit, not_iv_constant_6, key_block_size) =
+            self.assign_variable_length_constants(region, offset)?;
+
+        let blocks = self.assign_blocks_with_padding_flags(
+            region,
+            offset,
+            input,
+            input_length,
+            input_blocks,
+            &zero_constant_cell,
+            &zero_bit,
+        )?;
+
+        // A block is the last one if its first byte belongs to the message and the first byte of
+        // the next block doesn't. The first input block is always processed when there's no key,
+        // even if the message is empty, and the key block is the last one if the message is empty.
+        let first_flag_of_block = |index: usize| -> &AssignedBit<F> {
+            blocks.get(index).map(|block| &block.first_flag).unwrap_or(&zero_bit)
+        };
+        let mut block_schedule = Vec::new();
+        if !is_key_empty {
+            let is_last_block =
+                self.config.variable_length_padding_config.generate_last_block_row(
+                    region,
+                    offset,
+                    &one_bit,
+                    first_flag_of_block(0),
+                    self.config.full_number_u64,
+                    self.config.limbs,
+                )?;
+            block_schedule.push((None, is_last_block));
+        }
+        for (index, block) in blocks.iter().enumerate() {
+            let first_flag = if is_key_empty && index == 0 { &one_bit } else { &block.first_flag };
+            let is_last_block =
+                self.config.variable_length_padding_config.generate_last_block_row(
+                    region,
+                    offset,
+                    first_flag,
+                    first_flag_of_block(index + 1),
+                    self.config.full_number_u64,
+                    self.config.limbs,
+                )?;
+            block_schedule.push((Some(block), is_last_block));
+        }
+
+        // Main loop. Every block up to the maximum input length is compressed, and the global
+        // state after the last block of the message is selected as the result.
+        let mut selected_state: Option<[AssignedBlake2bWord<F>; 8]> = None;
+        for (block, is_last_block) in block_schedule {
+            let (current_block_cells, state_12) = match block {
+                None => {
+                    let current_block_values = Self::build_values_for_current_block(
+                        &[],
+                        key,
+                        0,
+                        0,
+                        is_key_empty,
+                        false,
+                        true,
+                        zero_constant_cell.clone(),
+                    );
+                    let current_block_rows = self.block_words_from_bytes(
+                        region,
+                        offset,
+                        current_block_values.try_into().unwrap(),
+                    )?;
+                    constrain_padding_cells_to_equal_zero(
+                        region,
+                        BLAKE2B_BLOCK_SIZE - key.len(),
+                        &current_block_rows,
+                        &zero_constant_cell,
+                    )?;
+                    let new_state_12 = (BLAKE2B_BLOCK_SIZE as u64) ^ IV_CONSTANTS[4];
+                    let state_12 = AssignedBlake2bWord::assign_fixed_word(
+                        region,
+                        "New state[12]",
+                        self.config.full_number_u64,
+                        *offset,
+                        new_state_12.into(),
+                    )?;
+                    *offset += 1;
+                    (full_number_of_each_state_row(current_block_rows), state_12)
+                }
+                Some(block) => {
+                    let processed_bytes_count = if is_key_empty {
+                        block.processed_bytes_count.clone()
+                    } else {
+                        self.add(&block.processed_bytes_count, &key_block_size, region, offset)?
+                            .full_number
+                    };
+                    let state_12 =
+                        self.xor(&iv_constants[4], &processed_bytes_count, region, offset)?;
+                    (block.words.clone(), state_12.full_number)
+                }
+            };
+
+            let state_14 =
+                self.select(&is_last_block, &iv_constants[6], &not_iv_constant_6, region, offset)?;
+
+            self.compress_with_assigned_counter_and_flag(
+                region,
+                offset,
+                iv_constants,
+                global_state,
+                current_block_cells,
+                state_12,
+                state_14,
+                iv_constants[7].clone(),
+            )?;
+
+            selected_state = Some(match selected_state {
+                None => global_state.clone(),
+                Some(previous_state) => {
+                    let mut new_selected_state = Vec::new();
+                    for (previous_word, new_word) in previous_state.iter().zip(global_state.iter())
+                    {
+                        new_selected_state.push(self.select(
+                            &is_last_block,
+                            previous_word,
+                            new_word,
+                            region,
+                            offset,
+                        )?);
+                    }
+                    new_selected_state.try_into().unwrap()
+                }
+            });
+        }
+
+        // Note: there is always at least one block, so the state must have been selected.
+        let selected_state = selected_state.expect("unexpected empty sequence of blake2b blocks");
+        let mut global_state_bytes: Vec<AssignedByte<F>> = Vec::new();
+        for word in selected_state.iter() {
+            let row = self.new_row_from_word(word, region, offset)?;
+            global_state_bytes.extend(row.limbs);
+        }
+        Ok(global_state_bytes.try_into().unwrap())
+    }
+
     fn compress(
         &self,
         region: &mut Region<'_, F>,
@@ -215,19 +411,14 @@ impl<F: PrimeField> Blake2bInstructions<F> for Blake2bChip<F> {
         current_block: [AssignedBlake2bWord<F>; 16],
         processed_bytes_count: u64,
         is_last_block: bool,
+        is_last_node: bool,
     ) -> Result<[AssignedByte<F>; 64], Error> {
-        let mut state_vector: Vec<AssignedBlake2bWord<F>> = Vec::new();
-        state_vector.extend_from_slice(global_state);
-        state_vector.extend_from_slice(iv_constants);
-
-        let mut state: [AssignedBlake2bWord<F>; 16] = state_vector.try_into().unwrap();
-
         // accumulative_state[12] ^= processed_bytes_count
         // Since accumulative_state[12] is allways IV_CONSTANTS[4] at this point in execution
         // and processed_bytes_count is public for both parties, the xor between both values
         // is also a constant.
         let new_state_12 = processed_bytes_count ^ IV_CONSTANTS[4];
-        state[12] = AssignedBlake2bWord::assign_fixed_word(
+        let state_12 = AssignedBlake2bWord::assign_fixed_word(
             region,
             "New state[12]",
             self.config.full_number_u64,
@@ -236,9 +427,49 @@ impl<F: PrimeField> Blake2bInstructions<F> for Blake2bChip<F> {
         )?;
         *row_offset += 1;
 
-        if is_last_block {
-            state[14] = self.not(&state[14], region, row_offset)?;
-        }
+        let state_14 = if is_last_block {
+            self.not(&iv_constants[6], region, row_offset)?
+        } else {
+            iv_constants[6].clone()
+        };
+
+        let state_15 = if is_last_block && is_last_node {
+            self.not(&iv_constants[7], region, row_offset)?
+        } else {
+            iv_constants[7].clone()
+        };
+
+        self.compress_with_assigned_counter_and_flag(
+            region,
+            row_offset,
+            iv_constants,
+            global_state,
+            current_block,
+            state_12,
+            state_14,
+            state_15,
+        )
+    }
+
+    fn compress_with_assigned_counter_and_flag(
+        &self,
+        region: &mut Region<'_, F>,
+        row_offset: &mut usize,
+        iv_constants: &[AssignedBlake2bWord<F>; 8],
+        global_state: &mut [AssignedBlake2bWord<F>; 8],
+        current_block: [AssignedBlake2bWord<F>; 16],
+        state_12: AssignedBlake2bWord<F>,
+        state_14: AssignedBlake2bWord<F>,
+        state_15: AssignedBlake2bWord<F>,
+    ) -> Result<[AssignedByte<F>; 64], Error> {
+        let mut state_vector: Vec<AssignedBlake2bWord<F>> = Vec::new();
+        state_vector.extend_from_slice(global_state);
+        state_vector.extend_from_slice(iv_constants);
+
+        let mut state: [AssignedBlake2bWord<F>; 16] = state_vector.try_into().unwrap();
+        state[12] = state_12;
+        state[14] = state_14;
+        state[15] = state_15;
 
         // Main loop
         for i in 0..12 {
@@ -341,6 +572,8 @@ impl<F: PrimeField> Blake2bChip<F> {
         full_number_u64: Column<Advice>,
         limbs: [Column<Advice>; NB_BLAKE2B_ADVICE_COLS - 1],
     ) -> <Self as Chip<F>>::Config {
+        enforce_little_endian_representation::<F>();
+
         // Enabling column properties.
         meta.enable_constant(constants);
         meta.enable_equality(full_number_u64);
@@ -369,12 +602,21 @@ impl<F: PrimeField> Blake2bChip<F> {
             AdditionMod64Config::configure(meta, full_number_u64, limbs[0], q_decompose, q_range);
         let xor_config = XorConfig::configure(meta, limbs, full_number_u64, limbs, q_decompose);
 
+        // Configs only used when the length of the input is known at proof generation time
+        let select_config = SelectConfig::configure(meta, full_number_u64, limbs);
+        let variable_length_padding_config =
+            VariableLengthPaddingConfig::configure(meta, full_number_u64, limbs);
+
         Blake2bConfig {
             addition_config,
             generic_limb_rotation_config,
             rotate_63_config,
             xor_config,
             negate_config,
+            select_config,
+            variable_length_padding_config,
+            pack_config: None,
+            word_product_config: None,
             full_number_u64,
             limbs,
             q_range,
@@ -383,6 +625,41 @@ impl<F: PrimeField> Blake2bChip<F> {
         }
     }
 
+    /// Configuration of the gate that packs words into field elements, over the configuration of
+    /// a Blake2b chip. It should be called only by circuits that pack digests into field elements
+    /// with [Blake2bChip::pack_digest], so the rest of the circuits don't have the gate.
+    pub fn configure_digest_packing(
+        meta: &mut ConstraintSystem<F>,
+        blake2b_config: &Blake2bConfig,
+    ) -> <Self as Chip<F>>::Config {
+        Blake2bConfig {
+            pack_config: Some(PackConfig::configure(meta, blake2b_config.limbs)),
+            ..blake2b_config.clone()
+        }
+    }
+
+    /// Configuration of the gates used by [Blake2bChip::hash_to_field] and
+    /// [Blake2bChip::reduce_digest], over the configuration of a Blake2b chip. These are the gate
+    /// that packs words into field elements, unless it's already configured, and the gate of the
+    /// columns of a product of words. It should be called only by circuits that reduce digests.
+    pub fn configure_hash_to_field(
+        meta: &mut ConstraintSystem<F>,
+        blake2b_config: &Blake2bConfig,
+    ) -> <Self as Chip<F>>::Config {
+        let blake2b_config = match blake2b_config.pack_config {
+            Some(_) => blake2b_config.clone(),
+            None => Self::configure_digest_packing(meta, blake2b_config),
+        };
+        Blake2bConfig {
+            word_product_config: Some(WordProductConfig::configure(
+                meta,
+                blake2b_config.full_number_u64,
+                blake2b_config.limbs,
+            )),
+            ..blake2b_config
+        }
+    }
+
     /// Loading the tables used in the chip.
     pub fn load(&self, layouter: &mut impl Layouter<F>) -> Result<(), Error> {
         self.populate_lookup_tables(layouter)
@@ -393,7 +670,7 @@ impl<F: PrimeField> Blake2bChip<F> {
     /// Blake2b uses a fixed initialization vector (iv). This method assigns those
     /// fixed values to advice cells. The cells used are the 8 limbs in the very first row of the
     /// trace. This is implementation specific.
-    fn assign_iv_constants_to_fixed_cells(
+    pub(crate) fn assign_iv_constants_to_fixed_cells(
         &self,
         region: &mut Region<'_, F>,
         offset: &mut usize,
@@ -412,10 +689,38 @@ impl<F: PrimeField> Blake2bChip<F> {
         Ok(ret)
     }
 
+    /// Assigns the constants needed to resume a hash from a chaining state known at circuit
+    /// building time: the iv constants, the zero constant and the chaining state, which takes the
+    /// limbs of its own row.
+    pub(crate) fn assign_midstate_constant_cells(
+        &self,
+        chaining_state: &[u64; 8],
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+    ) -> Result<MidstateConstantCells<F>, Error> {
+        let iv_constant_cells = self.assign_iv_constants_to_fixed_cells(region, offset)?;
+        let zero_constant = region.assign_advice_from_constant(
+            || "zero",
+            self.config.limbs[0],
+            *offset,
+            F::from(0),
+        )?;
+        *offset += 1;
+        let chaining_state_cells = chaining_state
+            .iter()
+            .enumerate()
+            .map(|(index, word)| {
+                self.assign_limb_constant_u64(region, offset, "midstate", *word, index)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        *offset += 1;
+        Ok((iv_constant_cells, chaining_state_cells.try_into().unwrap(), zero_constant))
+    }
+
     /// Bitwise negation operation. This is used only once in the circuit, at the beginning of the
     /// last compress iteration. It's implemented through a [NegateConfig] which establishes all the
     /// necessary restrictions.
-    fn not(
+    pub(crate) fn not(
         &self,
         input_cell: &AssignedBlake2bWord<F>,
         region: &mut Region<'_, F>,
@@ -429,10 +734,106 @@ impl<F: PrimeField> Blake2bChip<F> {
         )
     }
 
+    /// Selection between two words. It returns 'when_true' if the condition bit is set and
+    /// 'when_false' otherwise. This is used only when the length of the input is known at proof
+    /// generation time, to choose the last block flag and the resulting state of the hash. It's
+    /// implemented through a [SelectConfig] which establishes all the necessary restrictions.
+    pub(crate) fn select(
+        &self,
+        condition: &AssignedBit<F>,
+        when_false: &AssignedBlake2bWord<F>,
+        when_true: &AssignedBlake2bWord<F>,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+    ) -> Result<AssignedBlake2bWord<F>, Error> {
+        self.config.select_config.generate_select_row_from_cells(
+            region,
+            offset,
+            condition,
+            when_false,
+            when_true,
+            self.config.full_number_u64,
+            self.config.limbs,
+        )
+    }
+
+    /// Packs up to four words into a field element, in little-endian order, plus the 'high'
+    /// element times 2^256 when it's given. It's implemented through a [PackConfig], and it's
+    /// used to pack digests into fewer public inputs and to reduce them into field elements.
+    ///
+    /// The gate must be configured with [Blake2bChip::configure_digest_packing].
+    pub(crate) fn pack(
+        &self,
+        words: &[AssignedBlake2bWord<F>],
+        high: Option<&AssignedNative<F>>,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+    ) -> Result<AssignedNative<F>, Error> {
+        let pack_config =
+            self.config.pack_config.as_ref().expect(
+                "Digest packing must be configured with Blake2bChip::configure_digest_packing",
+            );
+        pack_config.generate_pack_row_from_cells(region, offset, words, high, self.config.limbs)
+    }
+
+    /// Constrains a column of the product of two numbers written in words, see
+    /// [WordProductConfig], and returns the cell of the carry out of the column, which isn't
+    /// range checked.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn word_product_column(
+        &self,
+        lhs: [&AssignedNative<F>; PRODUCT_RHS_WORDS],
+        rhs: [&AssignedNative<F>; PRODUCT_RHS_WORDS],
+        addend: &AssignedNative<F>,
+        target: &AssignedNative<F>,
+        previous_carry: &AssignedNative<F>,
+        carry_value: Value<F>,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+    ) -> Result<AssignedNative<F>, Error> {
+        let word_product_config = self.config.word_product_config.as_ref().expect(
+            "Digest reduction must be configured with Blake2bChip::configure_hash_to_field",
+        );
+        word_product_config.generate_word_product_rows(
+            region,
+            offset,
+            lhs,
+            rhs,
+            addend,
+            target,
+            previous_carry,
+            carry_value,
+            self.config.full_number_u64,
+            self.config.limbs,
+        )
+    }
+
+    /// Given a value holding a 64-bit word, it puts in the circuit a full row with the word in the
+    /// first column and its bytes in the limbs. The resulting values are range-checked by the
+    /// circuit.
+    pub(crate) fn new_row_from_word_value(
+        &self,
+        value: Value<Blake2bWord>,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+    ) -> Result<AssignedRow<F>, Error> {
+        self.config.q_decompose.enable(region, *offset)?;
+        self.config.q_range.enable(region, *offset)?;
+        let row = generate_row_from_word_value(
+            region,
+            value,
+            *offset,
+            self.config.full_number_u64,
+            self.config.limbs,
+        )?;
+        *offset += 1;
+        Ok(row)
+    }
+
     /// Bitwise xor operation. It's performed over two assigned blake2b words. Is one of the most
     /// used operations in the Blake2b function and implemented through a [XorConfig] which
     /// creates all the necessary lookups.
-    fn xor(
+    pub(crate) fn xor(
         &self,
         lhs: &AssignedBlake2bWord<F>,
         rhs: &AssignedBlake2bWord<F>,
@@ -445,7 +846,7 @@ impl<F: PrimeField> Blake2bChip<F> {
     /// Addition operation. It's performed over two assigned blake2b words. Is one of the most
     /// used operations in the Blake2b function and implemented through a [AdditionMod64Config]
     /// which creates all the necessary lookups.
-    fn add(
+    pub(crate) fn add(
         &self,
         lhs: &AssignedBlake2bWord<F>,
         rhs: &AssignedBlake2bWord<F>,
@@ -471,7 +872,7 @@ impl<F: PrimeField> Blake2bChip<F> {
     /// Bitwise rotation mod 64 bits. 63 bits to the right. Internally uses a [Rotate63Config] and
     /// only receives the full number as input because it doesn't need the limbs to establish the
     /// necessary restrictions.
-    fn rotate_right_63(
+    pub(crate) fn rotate_right_63(
         &self,
         input: AssignedBlake2bWord<F>,
         region: &mut Region<'_, F>,
@@ -489,7 +890,7 @@ impl<F: PrimeField> Blake2bChip<F> {
     /// Bitwise rotation mod 64 bits. 16 bits to the right. Internally uses the [LimbRotation] gate
     /// and receives an [AssignedRow] as input because it needs the limbs to establish the
     /// necessary restrictions. It only returns the full number, not the resulting row.
-    fn rotate_right_16(
+    pub(crate) fn rotate_right_16(
         &self,
         input_row: AssignedRow<F>,
         region: &mut Region<'_, F>,
@@ -508,7 +909,7 @@ impl<F: PrimeField> Blake2bChip<F> {
     /// Bitwise rotation mod 64 bits. 24 bits to the right. Internally uses the [LimbRotation] gate
     /// and receives an [AssignedRow] as input because it needs the limbs to establish the
     /// necessary restrictions. It only returns the full number, not the resulting row.
-    fn rotate_right_24(
+    pub(crate) fn rotate_right_24(
         &self,
         input_row: AssignedRow<F>,
         region: &mut Region<'_, F>,
@@ -527,7 +928,7 @@ impl<F: PrimeField> Blake2bChip<F> {
     /// Bitwise rotation mod 64 bits. 32 bits to the right. Internally uses the [LimbRotation] gate
     /// and receives an [AssignedRow] as input because it needs the limbs to establish the
     /// necessary restrictions. It only returns the full number, not the resulting row.
-    fn rotate_right_32(
+    pub(crate) fn rotate_right_32(
         &self,
         input_row: AssignedRow<F>,
         region: &mut Region<'_, F>,
@@ -550,7 +951,7 @@ impl<F: PrimeField> Blake2bChip<F> {
     /// This method reuse the first operand of the operation, so it doesn't need to copy it.
     /// That's why it receives a [AssignedRow] as input, to let us reuse the limbs, which we need
     /// to perform the XOR operation
-    fn xor_copying_one_parameter(
+    pub(crate) fn xor_copying_one_parameter(
         &self,
         previous_operand: &AssignedRow<F>,
         cell_to_copy: &AssignedBlake2bWord<F>,
@@ -568,7 +969,7 @@ impl<F: PrimeField> Blake2bChip<F> {
     /// This method behaves like [add], with the difference that it takes advantage of the fact that
     /// the last row in the circuit is one of the operands of the addition, so it only needs to copy
     /// one parameter because the other is already on the trace.
-    fn add_copying_one_parameter(
+    pub(crate) fn add_copying_one_parameter(
         &self,
         previous_cell: &AssignedBlake2bWord<F>,
         cell_to_copy: &AssignedBlake2bWord<F>,
@@ -603,7 +1004,7 @@ impl<F: PrimeField> Blake2bChip<F> {
     /// Given an array of [AssignedNative] byte-values, it puts in the circuit a full row with those
     /// bytes in the limbs and the resulting full number in the first column. The resulting values
     /// are range-checked by the circuit.
-    fn new_row_from_assigned_bytes(
+    pub(crate) fn new_row_from_assigned_bytes(
         &self,
         bytes: &[AssignedNative<F>; 8],
         region: &mut Region<'_, F>,
@@ -622,10 +1023,55 @@ impl<F: PrimeField> Blake2bChip<F> {
         ret
     }
 
+    /// Given an [AssignedBlake2bWord], it puts in the circuit a full row with a copy of the word
+    /// in the first column and its bytes in the limbs. The resulting values are range-checked by
+    /// the circuit.
+    pub(crate) fn new_row_from_word(
+        &self,
+        word: &AssignedBlake2bWord<F>,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+    ) -> Result<AssignedRow<F>, Error> {
+        self.config.q_decompose.enable(region, *offset)?;
+        self.config.q_range.enable(region, *offset)?;
+        let ret = generate_row_from_cell(
+            region,
+            word,
+            *offset,
+            self.config.full_number_u64,
+            self.config.limbs,
+        );
+        *offset += 1;
+        ret
+    }
+
+    /// Given an [AssignedNative] holding a 64-bit value, it puts in the circuit a full row with a
+    /// copy of the value in the first column and its bytes in the limbs. The resulting values are
+    /// range-checked by the circuit, so the value can be used as an [AssignedBlake2bWord].
+    pub(crate) fn new_row_from_native_word(
+        &self,
+        word: &AssignedNative<F>,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+    ) -> Result<AssignedRow<F>, Error> {
+        self.config.q_decompose.enable(region, *offset)?;
+        self.config.q_range.enable(region, *offset)?;
+        let row = generate_row_from_word_value(
+            region,
+            word.value().map(|v| Blake2bWord::new_from_field(*v)),
+            *offset,
+            self.config.full_number_u64,
+            self.config.limbs,
+        )?;
+        region.constrain_equal(word.cell(), row.full_number.cell())?;
+        *offset += 1;
+        Ok(row)
+    }
+
     /// This method is used when building the block words from the input bytes. It receives a list
     /// of 128 [AssignedNative] bytes that still haven't been range-checked and returns a list of
     /// 16 [AssignedRow] putted in the trace, range-checked and ready for use in the algorithm.
-    fn block_words_from_bytes(
+    pub(crate) fn block_words_from_bytes(
         &self,
         region: &mut Region<'_, F>,
         offset: &mut usize,
@@ -671,6 +1117,244 @@ impl<F: PrimeField> Blake2bChip<F> {
         }
     }
 
+    /// Assigns the constants that are only needed when the length of the input is known at proof
+    /// generation time, all of them in the same row: the bits 1 and 0, the negation of the iv
+    /// constant used for the last block flag, and the size of a block, which is added to the
+    /// processed bytes count when there's a key.
+    fn assign_variable_length_constants(
+        &self,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+    ) -> Result<VariableLengthConstantCells<F>, Error> {
+        let one_bit =
+            AssignedBit::assign_fixed_bit(region, "one", self.config.limbs[0], *offset, true)?;
+        let zero_bit =
+            AssignedBit::assign_fixed_bit(region, "zero", self.config.limbs[1], *offset, false)?;
+        let not_iv_constant_6 = self.assign_limb_constant_u64(
+            region,
+            offset,
+            "not iv constant 6",
+            !IV_CONSTANTS[6],
+            2,
+        )?;
+        let key_block_size = self.assign_limb_constant_u64(
+            region,
+            offset,
+            "key block size",
+            BLAKE2B_BLOCK_SIZE as u64,
+            3,
+        )?;
+        *offset += 1;
+        Ok((one_bit, zero_bit, not_iv_constant_6, key_block_size))
+    }
+
+    /// Puts the given bytes in the circuit, word by word, followed by the padding flags of each
+    /// word, which are constrained by the [VariableLengthPaddingConfig]. The bytes are completed
+    /// with zeros up to 'blocks_count' blocks, and the flags of the positions after the given
+    /// bytes are constrained to be 0, so the length can't exceed the amount of bytes. The count
+    /// of the last flags row is constrained to equal the 'length' cell.
+    #[allow(clippy::too_many_arguments)]
+    fn assign_blocks_with_padding_flags(
+        &self,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+        bytes: &[AssignedNative<F>],
+        length: &AssignedNative<F>,
+        blocks_count: usize,
+        zero_constant_cell: &AssignedNative<F>,
+        zero_bit: &AssignedBit<F>,
+    ) -> Result<Vec<VariableLengthBlock<F>>, Error> {
+        let length_value = length.value().map(|v| Blake2bWord::new_from_field(*v));
+        let mut padded_bytes = bytes.to_vec();
+        padded_bytes.resize(blocks_count * BLAKE2B_BLOCK_SIZE, zero_constant_cell.clone());
+
+        self.config.variable_length_padding_config.generate_initial_row(
+            region,
+            offset,
+            self.config.full_number_u64,
+            self.config.limbs,
+        )?;
+
+        let mut blocks = Vec::with_capacity(blocks_count);
+        for (block_index, block) in padded_bytes.chunks(BLAKE2B_BLOCK_SIZE).enumerate() {
+            let mut words = Vec::with_capacity(16);
+            let mut counts = Vec::with_capacity(16);
+            let mut first_flags = Vec::with_capacity(16);
+            for (word_index, word_bytes) in block.chunks(8).enumerate() {
+                let row = self.new_row_from_assigned_bytes(
+                    word_bytes.try_into().unwrap(),
+                    region,
+                    offset,
+                )?;
+                let first_byte_position = block_index * BLAKE2B_BLOCK_SIZE + word_index * 8;
+                let (count, flags) =
+                    self.config.variable_length_padding_config.generate_flags_row(
+                        region,
+                        offset,
+                        first_byte_position,
+                        length_value,
+                        self.config.full_number_u64,
+                        self.config.limbs,
+                    )?;
+                for (i, flag) in flags.iter().enumerate() {
+                    if first_byte_position + i >= bytes.len() {
+                        region.constrain_equal(flag.cell(), zero_bit.cell())?;
+                    }
+                }
+                words.push(row.full_number);
+                counts.push(count);
+                first_flags.push(flags[0].clone());
+            }
+            blocks.push(VariableLengthBlock {
+                words: words.try_into().unwrap(),
+                processed_bytes_count: counts.pop().unwrap(),
+                first_flag: first_flags.swap_remove(0),
+            });
+        }
+
+        match blocks.last() {
+            Some(block) => {
+                region.constrain_equal(block.processed_bytes_count.cell(), length.cell())?
+            }
+            // Without blocks, the only valid length is 0
+            None => region.constrain_equal(zero_constant_cell.cell(), length.cell())?,
+        }
+        Ok(blocks)
+    }
+
+    /// Builds the words of the key block when the key length is only known at proof generation
+    /// time. The 'key' cells hold the key followed by zeros, and the padding of the block is
+    /// constrained from the 'key_length' cell, which can't be 0.
+    fn key_block_words_with_private_length(
+        &self,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+        key: &[AssignedNative<F>],
+        key_length: &AssignedNative<F>,
+        zero_constant_cell: &AssignedNative<F>,
+    ) -> Result<[AssignedBlake2bWord<F>; 16], Error> {
+        let (one_bit, zero_bit, _, _) = self.assign_variable_length_constants(region, offset)?;
+        let key_block = self
+            .assign_blocks_with_padding_flags(
+                region,
+                offset,
+                key,
+                key_length,
+                1,
+                zero_constant_cell,
+                &zero_bit,
+            )?
+            .pop()
+            .unwrap();
+        // The first byte of the key must be part of it, so the key is not empty
+        region.constrain_equal(key_block.first_flag.cell(), one_bit.cell())?;
+        Ok(key_block.words)
+    }
+
+    /// When the key length is only known at proof generation time, the initial state[0] can't be
+    /// a constant. In that case, the constant is computed as if the key was empty and this method
+    /// xors it with '(key_length << 8)', which is built as a word whose second byte is the length.
+    fn xor_private_key_length_into_initial_state_0(
+        &self,
+        initial_state_0: &AssignedBlake2bWord<F>,
+        key_length: &AssignedNative<F>,
+        zero_constant_cell: &AssignedNative<F>,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+    ) -> Result<AssignedBlake2bWord<F>, Error> {
+        let mut key_length_bytes = vec![zero_constant_cell.clone(); 8];
+        key_length_bytes[1] = key_length.clone();
+        let key_length_row = self.new_row_from_assigned_bytes(
+            &key_length_bytes.try_into().unwrap(),
+            region,
+            offset,
+        )?;
+        Ok(self.xor(initial_state_0, &key_length_row.full_number, region, offset)?.full_number)
+    }
+
+    /// Xors the words 1 to 7 of the parameter block into the initial state, since the word 0 is
+    /// already part of the constants. The words 1 to 3 are constants, and they're only assigned
+    /// when they're not zero, so sequential hashes don't pay for them. The iv constants are still
+    /// used as they are in every compression, so the parameters only change the initial state.
+    pub(crate) fn xor_params_into_initial_state(
+        &self,
+        global_state: &mut [AssignedBlake2bWord<F>; 8],
+        params: &Blake2bParams<F>,
+        key_size: usize,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+    ) -> Result<(), Error> {
+        let parameter_block_words = params.constant_parameter_block_words(key_size);
+        if parameter_block_words[1..].iter().any(|word| *word != 0) {
+            for index in 1..4 {
+                global_state[index] = self.assign_limb_constant_u64(
+                    region,
+                    offset,
+                    "initial state with parameter",
+                    IV_CONSTANTS[index] ^ parameter_block_words[index],
+                    index,
+                )?;
+            }
+            *offset += 1;
+        }
+        if let Some(salt) = &params.salt {
+            self.xor_param_bytes_into_initial_state(global_state, 4, salt, region, offset)?;
+        }
+        if let Some(personalization) = &params.personalization {
+            self.xor_param_bytes_into_initial_state(
+                global_state,
+                6,
+                personalization,
+                region,
+                offset,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Xors the salt or the personalization into the two words of the initial state starting at
+    /// 'first_word_index'. Constant bytes are xored outside the circuit and the resulting words
+    /// are assigned as constants. Assigned bytes are range-checked by placing them in a row and
+    /// then xored with the words of the state in the circuit.
+    fn xor_param_bytes_into_initial_state(
+        &self,
+        global_state: &mut [AssignedBlake2bWord<F>; 8],
+        first_word_index: usize,
+        param_bytes: &ParamBytes<F>,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+    ) -> Result<(), Error> {
+        match param_bytes {
+            ParamBytes::Constant(bytes) => {
+                for (i, word) in param_bytes_to_words(bytes).iter().enumerate() {
+                    let index = first_word_index + i;
+                    global_state[index] = self.assign_limb_constant_u64(
+                        region,
+                        offset,
+                        "initial state with parameter",
+                        IV_CONSTANTS[index] ^ word,
+                        i,
+                    )?;
+                }
+                *offset += 1;
+            }
+            ParamBytes::Assigned(bytes) => {
+                for (i, word_bytes) in bytes.chunks(8).enumerate() {
+                    let index = first_word_index + i;
+                    let row = self.new_row_from_assigned_bytes(
+                        word_bytes.try_into().unwrap(),
+                        region,
+                        offset,
+                    )?;
+                    global_state[index] = self
+                        .xor(&global_state[index], &row.full_number, region, offset)?
+                        .full_number;
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Assigns an u64 constant to trace[row_offset][limbs[limb_index]] cell.
     fn assign_limb_constant_u64(
         &self,
@@ -692,14 +1376,72 @@ impl<F: PrimeField> Blake2bChip<F> {
 
 impl<F: PrimeField> Blake2bChip<F> {
     /// Main method of the chip. The 'input' and 'key' cells should be filled with byte values.
+    /// The optional 'salt' and 'personalization' are the 16-byte fields of the parameter block
+    /// defined in RFC 7693, which are xored into the words 4 to 7 of the initial state. When
+    /// they're missing, the fields are all zeros.
     pub fn hash(
         &self,
         layouter: &mut impl Layouter<F>,
         input: &[AssignedNative<F>],
         key: &[AssignedNative<F>],
         output_size: usize,
+        salt: Option<&ParamBytes<F>>,
+        personalization: Option<&ParamBytes<F>>,
     ) -> Result<[AssignedByte<F>; 64], Error> {
-        enforce_input_sizes(output_size, key.len());
+        let mut params = Blake2bParams::new(output_size);
+        params.salt = salt.cloned();
+        params.personalization = personalization.cloned();
+        self.hash_with_params(layouter, input, key, &params)
+    }
+
+    /// Unkeyed [Blake2bChip::hash] of the input with an output size of 'OUTPUT_SIZE' bytes, which
+    /// returns only the bytes of the digest.
+    pub(crate) fn hash_to_array<const OUTPUT_SIZE: usize>(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        input: &[AssignedNative<F>],
+    ) -> Result<[AssignedByte<F>; OUTPUT_SIZE], Error> {
+        let digest = self.hash(layouter, input, &[], OUTPUT_SIZE, None, None)?;
+        Ok(digest[..OUTPUT_SIZE].to_vec().try_into().unwrap())
+    }
+
+    /// Assigns the given bytes, known at circuit building time, as constants in the limb columns,
+    /// eight bytes per row.
+    pub(crate) fn assign_constant_bytes(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        bytes: &[u8],
+    ) -> Result<Vec<AssignedNative<F>>, Error> {
+        layouter.assign_region(
+            || "constant bytes",
+            |mut region| {
+                bytes
+                    .iter()
+                    .enumerate()
+                    .map(|(index, byte)| {
+                        region.assign_advice_from_constant(
+                            || "constant byte",
+                            self.config.limbs[index % 8],
+                            index / 8,
+                            F::from(*byte as u64),
+                        )
+                    })
+                    .collect()
+            },
+        )
+    }
+
+    /// Variant of [Blake2bChip::hash] that builds the whole parameter block from the given
+    /// [Blake2bParams], which allows to compute the nodes of a tree hash. The key size of the
+    /// parameter block is the amount of 'key' cells.
+    pub fn hash_with_params(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        input: &[AssignedNative<F>],
+        key: &[AssignedNative<F>],
+        params: &Blake2bParams<F>,
+    ) -> Result<[AssignedByte<F>; 64], Error> {
+        enforce_input_sizes(params.output_size, key.len());
         // All the computation is performed inside a single region
         layouter.assign_region(
             || "single region",
@@ -709,12 +1451,172 @@ impl<F: PrimeField> Blake2bChip<F> {
 
                 let (iv_constant_cells, initial_state_0, zero_constant) = self
                     .assign_constant_advice_cells(
-                        output_size,
+                        params,
                         key.len(),
                         &mut region,
                         &mut advice_offset,
                     )?;
 
+                let mut initial_global_state =
+                    self.compute_initial_state(&iv_constant_cells, initial_state_0)?;
+
+                self.xor_params_into_initial_state(
+                    &mut initial_global_state,
+                    params,
+                    key.len(),
+                    &mut region,
+                    &mut advice_offset,
+                )?;
+
+                self.perform_blake2b_iterations(
+                    &mut region,
+                    &mut advice_offset,
+                    input,
+                    key,
+                    None,
+                    params.last_node,
+                    &iv_constant_cells,
+                    &mut initial_global_state,
+                    zero_constant,
+                )
+            },
+        )
+    }
+
+    /// Hashes several independent messages with the same key and output size, returning one
+    /// digest per message, in order. Every message is hashed as in [Blake2bChip::hash], but all
+    /// the compressions are placed back to back in a single region, and the iv constants, the
+    /// zero constant and the initial state are assigned once and shared by every message, which
+    /// saves their rows when hashing many short messages.
+    pub fn hash_many(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        inputs: &[Vec<AssignedNative<F>>],
+        key: &[AssignedNative<F>],
+        output_size: usize,
+    ) -> Result<Vec<[AssignedByte<F>; 64]>, Error> {
+        enforce_input_sizes(output_size, key.len());
+        let params = Blake2bParams::new(output_size);
+        layouter.assign_region(
+            || "single region",
+            |mut region| {
+                let mut advice_offset: usize = 0;
+
+                let (iv_constant_cells, initial_state_0, zero_constant) = self
+                    .assign_constant_advice_cells(
+                        &params,
+                        key.len(),
+                        &mut region,
+                        &mut advice_offset,
+                    )?;
+
+                let initial_global_state =
+                    self.compute_initial_state(&iv_constant_cells, initial_state_0)?;
+
+                inputs
+                    .iter()
+                    .map(|input| {
+                        self.perform_blake2b_iterations(
+                            &mut region,
+                            &mut advice_offset,
+                            input,
+                            key,
+                            None,
+                            false,
+                            &iv_constant_cells,
+                            &mut initial_global_state.clone(),
+                            zero_constant.clone(),
+                        )
+                    })
+                    .collect()
+            },
+        )
+    }
+
+    /// Variant of [Blake2bChip::hash] for inputs whose length is only known at proof generation
+    /// time. The 'input' cells hold the message followed by zeros up to the maximum length of the
+    /// message, which is 'input.len()', and the 'input_length' cell holds the length of the
+    /// message. The padding, the last block flag and the processed bytes count are constrained in
+    /// the circuit, so the circuit only depends on the maximum length and a single verifying key
+    /// works for every message up to that length. Keep in mind that the circuit always has the
+    /// rows of an input of maximum length.
+    pub fn hash_with_variable_input_length(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        input: &[AssignedNative<F>],
+        input_length: &AssignedNative<F>,
+        key: &[AssignedNative<F>],
+        output_size: usize,
+    ) -> Result<[AssignedByte<F>; 64], Error> {
+        enforce_input_sizes(output_size, key.len());
+        layouter.assign_region(
+            || "single region",
+            |mut region| {
+                let mut advice_offset: usize = 0;
+
+                let (iv_constant_cells, initial_state_0, zero_constant) = self
+                    .assign_constant_advice_cells(
+                        &Blake2bParams::new(output_size),
+                        key.len(),
+                        &mut region,
+                        &mut advice_offset,
+                    )?;
+
+                let mut initial_global_state =
+                    self.compute_initial_state(&iv_constant_cells, initial_state_0)?;
+
+                self.perform_blake2b_iterations_with_variable_input_length(
+                    &mut region,
+                    &mut advice_offset,
+                    input,
+                    input_length,
+                    key,
+                    &iv_constant_cells,
+                    &mut initial_global_state,
+                    zero_constant,
+                )
+            },
+        )
+    }
+
+    /// Keyed variant of [Blake2bChip::hash] for keys whose length is only known at proof
+    /// generation time, so the proof doesn't reveal the length of the key. The 'key' cells hold
+    /// the key followed by zeros up to the maximum key length, which is 'key.len()' and can't
+    /// exceed 64, and the 'key_length' cell holds the length of the key, which can't be 0.
+    /// The parameter block and the padding of the key block are constrained in the circuit from
+    /// 'key_length'.
+    pub fn hash_with_private_key_length(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        input: &[AssignedNative<F>],
+        key: &[AssignedNative<F>],
+        key_length: &AssignedNative<F>,
+        output_size: usize,
+    ) -> Result<[AssignedByte<F>; 64], Error> {
+        enforce_input_sizes(output_size, key.len());
+        enforce_private_key_size(key.len());
+        layouter.assign_region(
+            || "single region",
+            |mut region| {
+                let mut advice_offset: usize = 0;
+
+                // The key length is not part of the constants, it's added below
+                let (iv_constant_cells, initial_state_0, zero_constant) = self
+                    .assign_constant_advice_cells(
+                        &Blake2bParams::new(output_size),
+                        0,
+                        &mut region,
+                        &mut advice_offset,
+                    )?;
+
+                let initial_state_0 = self.xor_private_key_length_into_initial_state_0(
+                    &initial_state_0,
+                    key_length,
+                    &zero_constant,
+                    &mut region,
+                    &mut advice_offset,
+                )?;
+
                 let mut initial_global_state =
                     self.compute_initial_state(&iv_constant_cells, initial_state_0)?;
 
@@ -723,6 +1625,8 @@ impl<F: PrimeField> Blake2bChip<F> {
                     &mut advice_offset,
                     input,
                     key,
+                    Some(key_length),
+                    false,
                     &iv_constant_cells,
                     &mut initial_global_state,
                     zero_constant,
@@ -1,3 +1,5 @@
+use crate::blake2b::params::Blake2bParams;
+use crate::types::bit::AssignedBit;
 use crate::types::blake2b_word::AssignedBlake2bWord;
 use crate::types::byte::AssignedByte;
 use crate::types::AssignedNative;
@@ -8,6 +10,12 @@ use midnight_proofs::plonk::Error;
 pub(crate) type ConstantCells<F> =
     ([AssignedBlake2bWord<F>; 8], AssignedBlake2bWord<F>, AssignedNative<F>);
 
+pub(crate) type MidstateConstantCells<F> =
+    ([AssignedBlake2bWord<F>; 8], [AssignedBlake2bWord<F>; 8], AssignedNative<F>);
+
+pub(crate) type VariableLengthConstantCells<F> =
+    (AssignedBit<F>, AssignedBit<F>, AssignedBlake2bWord<F>, AssignedBlake2bWord<F>);
+
 /// This is the trait that groups the Blake2b implementation chips. Every Blake2b chip
 /// should implement this trait.
 pub trait Blake2bInstructions<F: PrimeField>: Clone {
@@ -15,11 +23,11 @@ pub trait Blake2bInstructions<F: PrimeField>: Clone {
     fn populate_lookup_tables(&self, layouter: &mut impl Layouter<F>) -> Result<(), Error>;
 
     /// Assign initializations constants at the beginning. These constants are the initialization
-    /// vector (IV) constants, the zero constant and a constant computed from the key and output
-    /// lengths that is used for the initial state of the rounds.
+    /// vector (IV) constants, the zero constant and a constant computed from the key size and the
+    /// first word of the parameter block that is used for the initial state of the rounds.
     fn assign_constant_advice_cells(
         &self,
-        output_size: usize,
+        params: &Blake2bParams<F>,
         key_size: usize,
         region: &mut Region<'_, F>,
         advice_offset: &mut usize,
@@ -39,6 +47,11 @@ pub trait Blake2bInstructions<F: PrimeField>: Clone {
     /// some of those words change. A change in a state value is represented by changing the cell
     /// that represent that particular word in the state.
     /// The return bytes of this function are the digest of the Blake2b computation.
+    /// If a 'key_length' cell is given, the key length is only known at proof generation time:
+    /// the 'key' cells hold the key followed by zeros and the padding of the key block is
+    /// constrained from that cell.
+    /// If 'is_last_node' is set, the last node flag is set in the last block, as needed for the
+    /// last node of each level of a tree hash.
     #[allow(clippy::too_many_arguments)]
     fn perform_blake2b_iterations(
         &self,
@@ -46,6 +59,8 @@ pub trait Blake2bInstructions<F: PrimeField>: Clone {
         advice_offset: &mut usize,
         input: &[AssignedNative<F>],
         key: &[AssignedNative<F>],
+        key_length: Option<&AssignedNative<F>>,
+        is_last_node: bool,
         iv_constants: &[AssignedBlake2bWord<F>; 8],
         global_state: &mut [AssignedBlake2bWord<F>; 8],
         zero_constant_cell: AssignedNative<F>,
@@ -53,7 +68,8 @@ pub trait Blake2bInstructions<F: PrimeField>: Clone {
 
     /// This method computes a compression round of Blake2b. The global state is update through
     /// consecutive calls of this method. If the algorithm is in its last round, the is_last_block
-    /// parameter should be set to true.
+    /// parameter should be set to true. If the block is also the last one of the last node of a
+    /// level of a tree hash, the is_last_node parameter should be set to true too.
     #[allow(clippy::too_many_arguments)]
     fn compress(
         &self,
@@ -64,6 +80,44 @@ pub trait Blake2bInstructions<F: PrimeField>: Clone {
         current_block: [AssignedBlake2bWord<F>; 16],
         processed_bytes_count: u64,
         is_last_block: bool,
+        is_last_node: bool,
+    ) -> Result<[AssignedByte<F>; 64], Error>;
+
+    /// Same as [Blake2bInstructions::perform_blake2b_iterations], but for an input whose length
+    /// is only known at proof generation time. The 'input' holds the message followed by zeros up
+    /// to the maximum length of the message, and the 'input_length' cell holds its actual length.
+    /// The padding, the processed bytes count and the last block flag of every block are
+    /// constrained in the circuit from 'input_length', and the digest is selected from the global
+    /// state after the last block of the message.
+    #[allow(clippy::too_many_arguments)]
+    fn perform_blake2b_iterations_with_variable_input_length(
+        &self,
+        region: &mut Region<'_, F>,
+        advice_offset: &mut usize,
+        input: &[AssignedNative<F>],
+        input_length: &AssignedNative<F>,
+        key: &[AssignedNative<F>],
+        iv_constants: &[AssignedBlake2bWord<F>; 8],
+        global_state: &mut [AssignedBlake2bWord<F>; 8],
+        zero_constant_cell: AssignedNative<F>,
+    ) -> Result<[AssignedByte<F>; 64], Error>;
+
+    /// This method behaves like [Blake2bInstructions::compress], but the words of the compression
+    /// state that depend on the processed bytes count and the last block flag are received as
+    /// assigned cells. 'state_12' should hold 'IV[4] ^ processed_bytes_count', 'state_14'
+    /// should hold 'IV[6]', or its negation if the block is the last one, and 'state_15' should
+    /// hold 'IV[7]', or its negation if the block is the last one of a last node.
+    #[allow(clippy::too_many_arguments)]
+    fn compress_with_assigned_counter_and_flag(
+        &self,
+        region: &mut Region<'_, F>,
+        row_offset: &mut usize,
+        iv_constants: &[AssignedBlake2bWord<F>; 8],
+        global_state: &mut [AssignedBlake2bWord<F>; 8],
+        current_block: [AssignedBlake2bWord<F>; 16],
+        state_12: AssignedBlake2bWord<F>,
+        state_14: AssignedBlake2bWord<F>,
+        state_15: AssignedBlake2bWord<F>,
     ) -> Result<[AssignedByte<F>; 64], Error>;
 
     /// This method computes a single round of mixing for the Blake2b algorithm.
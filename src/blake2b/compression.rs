@@ -0,0 +1,79 @@
+use crate::blake2b::blake2b_chip::Blake2bChip;
+use crate::blake2b::blake2b_instructions::Blake2bInstructions;
+use crate::types::AssignedNative;
+use ff::PrimeField;
+use midnight_proofs::circuit::Layouter;
+use midnight_proofs::plonk::Error;
+
+impl<F: PrimeField> Blake2bChip<F> {
+    /// Standalone Blake2b compression function F, as defined in RFC 7693. It receives the 8 words
+    /// of the chaining value, the 16 words of the block and the counter of processed bytes, and
+    /// returns the new chaining value. The 'is_last_block' and 'is_last_node' flags are the final
+    /// block flag f0 and the last node flag f1.
+    ///
+    /// Every word is an [AssignedNative] holding a 64-bit value, which is range-checked inside the
+    /// gadget, and the returned words are range-checked too. The counter is the low word of the
+    /// 128-bit counter of the specification, the high word is always 0. This is the building
+    /// block for constructions that drive the compression themselves, such as Argon2, Equihash or
+    /// custom Merkle–Damgård variants. It works in its own region, so calls can be chained.
+    pub fn compress_words(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        chaining_value: &[AssignedNative<F>; 8],
+        block: &[AssignedNative<F>; 16],
+        counter: &AssignedNative<F>,
+        is_last_block: bool,
+        is_last_node: bool,
+    ) -> Result<[AssignedNative<F>; 8], Error> {
+        layouter.assign_region(
+            || "blake2b compression function",
+            |mut region| {
+                let mut offset: usize = 0;
+                let iv_constants =
+                    self.assign_iv_constants_to_fixed_cells(&mut region, &mut offset)?;
+
+                let mut global_state = Vec::with_capacity(8);
+                for word in chaining_value.iter() {
+                    global_state.push(
+                        self.new_row_from_native_word(word, &mut region, &mut offset)?.full_number,
+                    );
+                }
+                let mut global_state = global_state.try_into().unwrap();
+                let mut current_block = Vec::with_capacity(16);
+                for word in block.iter() {
+                    current_block.push(
+                        self.new_row_from_native_word(word, &mut region, &mut offset)?.full_number,
+                    );
+                }
+
+                let counter_row =
+                    self.new_row_from_native_word(counter, &mut region, &mut offset)?;
+                let state_12 = self
+                    .xor(&iv_constants[4], &counter_row.full_number, &mut region, &mut offset)?
+                    .full_number;
+                let state_14 = if is_last_block {
+                    self.not(&iv_constants[6], &mut region, &mut offset)?
+                } else {
+                    iv_constants[6].clone()
+                };
+                let state_15 = if is_last_node {
+                    self.not(&iv_constants[7], &mut region, &mut offset)?
+                } else {
+                    iv_constants[7].clone()
+                };
+
+                self.compress_with_assigned_counter_and_flag(
+                    &mut region,
+                    &mut offset,
+                    &iv_constants,
+                    &mut global_state,
+                    current_block.try_into().unwrap(),
+                    state_12,
+                    state_14,
+                    state_15,
+                )?;
+                Ok(global_state.map(|word| word.to_native()))
+            },
+        )
+    }
+}
@@ -0,0 +1,287 @@
+use crate::base_operations::word_product::PRODUCT_RHS_WORDS;
+use crate::blake2b::blake2b_chip::Blake2bChip;
+use crate::types::blake2b_word::{AssignedBlake2bWord, Blake2bWord};
+use crate::types::byte::AssignedByte;
+use crate::types::AssignedNative;
+use ff::PrimeField;
+use midnight_proofs::circuit::{Chip, Layouter, Region, Value};
+use midnight_proofs::plonk::Error;
+use num_bigint::BigUint;
+
+/// Amount of 64-bit words of a Blake2b digest.
+const DIGEST_WORDS: usize = 8;
+
+/// Smallest bit size of the field for which [Blake2bChip::reduce_digest] is sound. The columns of
+/// the products it checks are below 2^131 and their carries below 2^67, so every term of the
+/// word product gate is below 2^192 and the gate only holds in the field when it holds as an
+/// equation between integers.
+const REDUCTION_MIN_FIELD_BITS: u32 = 194;
+
+impl<F: PrimeField> Blake2bChip<F> {
+    /// Reduces a 64-byte digest, taken as a little-endian integer, modulo the native field. The
+    /// result matches `F::from_uniform_bytes` on the digest for the fields of BLS12-381 and
+    /// BN254, which is how challenges and scalars are usually derived from a digest. The digest
+    /// words are recomposed from their bytes with the decomposition gate, and the integer is
+    /// packed as 'low + high * 2^256' with two pack gates, so the reduction is constrained in
+    /// the circuit without any witness.
+    ///
+    /// The gates of the reduction must be configured with [Blake2bChip::configure_hash_to_field].
+    pub fn hash_to_field(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        digest: &[AssignedByte<F>; 64],
+    ) -> Result<AssignedNative<F>, Error> {
+        layouter.assign_region(
+            || "hash to field",
+            |mut region| {
+                let mut offset: usize = 0;
+                let words = self.digest_words(digest, &mut region, &mut offset)?;
+                let high = self.pack(&words[DIGEST_WORDS / 2..], None, &mut region, &mut offset)?;
+                self.pack(&words[..DIGEST_WORDS / 2], Some(&high), &mut region, &mut offset)
+            },
+        )
+    }
+
+    /// Reduces a 64-byte digest, taken as a little-endian integer, modulo the given modulus,
+    /// which must be greater than 1 and fit below the capacity of the field, so the result is
+    /// returned as a field element. This allows to derive scalars of other curves, such as the
+    /// scalars of Ed25519 inside a BLS12-381 circuit.
+    ///
+    /// The quotient and the remainder of the division are witnessed as range checked words, and
+    /// the circuit checks 'quotient * modulus + remainder = digest' and 'remainder + distance =
+    /// modulus - 1' as equations between integers, column by column of the schoolbook products,
+    /// where 'distance' is another range checked witness, which shows that the remainder is
+    /// smaller than the modulus.
+    ///
+    /// The gates of the reduction must be configured with [Blake2bChip::configure_hash_to_field].
+    pub fn reduce_digest(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        digest: &[AssignedByte<F>; 64],
+        modulus: &BigUint,
+    ) -> Result<AssignedNative<F>, Error> {
+        assert!(
+            F::NUM_BITS >= REDUCTION_MIN_FIELD_BITS,
+            "The field is too small to reduce digests modulo a chosen modulus"
+        );
+        assert!(*modulus > BigUint::from(1u8), "The modulus must be greater than 1");
+        assert!(
+            modulus.bits() <= (F::CAPACITY as u64).min(64 * PRODUCT_RHS_WORDS as u64),
+            "The modulus must fit below the capacity of the field"
+        );
+        let modulus_words = biguint_to_words(modulus, PRODUCT_RHS_WORDS);
+        let bound_words = biguint_to_words(&(modulus - 1u8), PRODUCT_RHS_WORDS);
+
+        layouter.assign_region(
+            || "reduce digest",
+            |mut region| {
+                let mut offset: usize = 0;
+                let small_constants =
+                    self.assign_constant_words(&[0, 1], &mut region, &mut offset)?;
+                let (zero, one) = (&small_constants[0], &small_constants[1]);
+                let modulus_cells =
+                    self.assign_constant_words(&modulus_words, &mut region, &mut offset)?;
+                let bound_cells =
+                    self.assign_constant_words(&bound_words, &mut region, &mut offset)?;
+
+                let words = self.digest_words(digest, &mut region, &mut offset)?;
+                let digest_value: Value<BigUint> = words
+                    .iter()
+                    .map(|word| word.value().map(|word| word.0))
+                    .collect::<Value<Vec<u64>>>()
+                    .map(|words| words_to_biguint(&words));
+                let quotient_value = digest_value.clone().map(|digest| digest / modulus);
+                let remainder_value = digest_value.map(|digest| digest % modulus);
+                let distance_value =
+                    remainder_value.clone().map(|remainder| modulus - 1u8 - remainder);
+
+                let quotient =
+                    self.assign_words(quotient_value, DIGEST_WORDS, &mut region, &mut offset)?;
+                let remainder = self.assign_words(
+                    remainder_value,
+                    PRODUCT_RHS_WORDS,
+                    &mut region,
+                    &mut offset,
+                )?;
+                let distance =
+                    self.assign_words(distance_value, PRODUCT_RHS_WORDS, &mut region, &mut offset)?;
+
+                // quotient * modulus + remainder = digest
+                self.constrain_word_product(
+                    &natives(&quotient),
+                    std::array::from_fn(|j| &modulus_cells[j]),
+                    &natives(&remainder),
+                    &natives(&words),
+                    zero,
+                    &mut region,
+                    &mut offset,
+                )?;
+                // distance * 1 + remainder = modulus - 1
+                self.constrain_word_product(
+                    &natives(&distance),
+                    [one, zero, zero, zero],
+                    &natives(&remainder),
+                    &bound_cells,
+                    zero,
+                    &mut region,
+                    &mut offset,
+                )?;
+
+                self.pack(&remainder, None, &mut region, &mut offset)
+            },
+        )
+    }
+
+    /// Recomposes the words of a digest from its bytes, which also range checks them.
+    fn digest_words(
+        &self,
+        digest: &[AssignedByte<F>; 64],
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+    ) -> Result<Vec<AssignedBlake2bWord<F>>, Error> {
+        digest
+            .chunks(8)
+            .map(|word_bytes| {
+                let bytes = std::array::from_fn(|i| word_bytes[i].to_native());
+                Ok(self.new_row_from_assigned_bytes(&bytes, region, offset)?.full_number)
+            })
+            .collect()
+    }
+
+    /// Assigns the given amount of range checked words holding a witnessed integer, in
+    /// little-endian order.
+    fn assign_words(
+        &self,
+        value: Value<BigUint>,
+        amount: usize,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+    ) -> Result<Vec<AssignedBlake2bWord<F>>, Error> {
+        let words = value.map(|value| biguint_to_words(&value, amount));
+        (0..amount)
+            .map(|i| {
+                let word = words.as_ref().map(|words| Blake2bWord(words[i]));
+                Ok(self.new_row_from_word_value(word, region, offset)?.full_number)
+            })
+            .collect()
+    }
+
+    /// Assigns the given words as constants in the limbs, eight per row.
+    fn assign_constant_words(
+        &self,
+        words: &[u64],
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+    ) -> Result<Vec<AssignedNative<F>>, Error> {
+        let cells = words
+            .iter()
+            .enumerate()
+            .map(|(i, word)| {
+                region.assign_advice_from_constant(
+                    || "constant word",
+                    self.config().limbs[i % 8],
+                    *offset + i / 8,
+                    F::from(*word),
+                )
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        *offset += words.len().div_ceil(8);
+        Ok(cells)
+    }
+
+    /// Constrains 'lhs * rhs + addend = target' as an equation between integers written in
+    /// range checked words, with a word product gate per column of the product. The carries
+    /// between columns are range checked to 128 bits, by packing two words, and the carry out of
+    /// the last column is constrained to be zero. The addend and the target can't have more
+    /// words than the product.
+    #[allow(clippy::too_many_arguments)]
+    fn constrain_word_product(
+        &self,
+        lhs: &[AssignedNative<F>],
+        rhs: [&AssignedNative<F>; PRODUCT_RHS_WORDS],
+        addend: &[AssignedNative<F>],
+        target: &[AssignedNative<F>],
+        zero: &AssignedNative<F>,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+    ) -> Result<(), Error> {
+        let columns = lhs.len() + PRODUCT_RHS_WORDS - 1;
+        assert!(addend.len() <= columns && target.len() <= columns);
+        let word_at = |words: &[AssignedNative<F>], index: usize| -> AssignedNative<F> {
+            words.get(index).unwrap_or(zero).clone()
+        };
+        let integer_value = |cell: &AssignedNative<F>| {
+            cell.value().map(|value| BigUint::from(Blake2bWord::new_from_field(*value).0))
+        };
+
+        let mut carry = zero.clone();
+        let mut carry_value = Value::known(BigUint::from(0u8));
+        for k in 0..columns {
+            let lhs_words: [AssignedNative<F>; PRODUCT_RHS_WORDS] = std::array::from_fn(|j| {
+                k.checked_sub(j).map_or(zero.clone(), |index| word_at(lhs, index))
+            });
+            let addend_word = word_at(addend, k);
+            let target_word = word_at(target, k);
+
+            let column_value = lhs_words
+                .iter()
+                .zip(rhs.iter())
+                .map(|(lhs, rhs)| integer_value(lhs) * integer_value(rhs))
+                .fold(carry_value + integer_value(&addend_word), |sum, product| sum + product);
+            carry_value = column_value
+                .zip(integer_value(&target_word))
+                .map(|(column, target)| (column - target) >> 64);
+
+            carry = self.word_product_column(
+                std::array::from_fn(|j| &lhs_words[j]),
+                rhs,
+                &addend_word,
+                &target_word,
+                &carry,
+                carry_value.as_ref().map(|carry| biguint_to_field(carry)),
+                region,
+                offset,
+            )?;
+            self.range_check_carry(&carry, carry_value.clone(), region, offset)?;
+        }
+        region.constrain_equal(carry.cell(), zero.cell())
+    }
+
+    /// Range checks a carry of a word product to 128 bits, by packing it from two words.
+    fn range_check_carry(
+        &self,
+        carry: &AssignedNative<F>,
+        carry_value: Value<BigUint>,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+    ) -> Result<(), Error> {
+        let words = self.assign_words(carry_value, 2, region, offset)?;
+        let packed = self.pack(&words, None, region, offset)?;
+        region.constrain_equal(packed.cell(), carry.cell())
+    }
+}
+
+fn natives<F: PrimeField>(words: &[AssignedBlake2bWord<F>]) -> Vec<AssignedNative<F>> {
+    words.iter().map(AssignedBlake2bWord::to_native).collect()
+}
+
+/// Little-endian 64-bit words of an integer, which must fit in the given amount of words.
+fn biguint_to_words(value: &BigUint, amount: usize) -> Vec<u64> {
+    let mut words = value.to_u64_digits();
+    assert!(words.len() <= amount, "The integer doesn't fit in {amount} words");
+    words.resize(amount, 0);
+    words
+}
+
+fn words_to_biguint(words: &[u64]) -> BigUint {
+    words.iter().rev().fold(BigUint::from(0u8), |value, word| (value << 64) + word)
+}
+
+/// Field element of an integer smaller than the modulus of the field.
+fn biguint_to_field<F: PrimeField>(value: &BigUint) -> F {
+    value
+        .to_u64_digits()
+        .iter()
+        .rev()
+        .fold(F::ZERO, |element, word| element * F::from_u128(1 << 64) + F::from(*word))
+}
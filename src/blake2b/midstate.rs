@@ -0,0 +1,81 @@
+use crate::blake2b::blake2b_chip::Blake2bChip;
+use crate::blake2b::params::{param_bytes_to_words, Blake2bParams, ParamBytes};
+use crate::blake2b::state::Blake2bState;
+use crate::blake2b::utils::{compress_native, enforce_input_sizes, BLAKE2B_BLOCK_SIZE, IV_CONSTANTS};
+use crate::types::byte::AssignedByte;
+use crate::types::AssignedNative;
+use ff::PrimeField;
+use midnight_proofs::circuit::Layouter;
+use midnight_proofs::plonk::Error;
+
+/// Chaining state of an unkeyed Blake2b hash after a prefix known at circuit building time. It's
+/// computed outside the circuit and loaded as constants, so the blocks of the prefix don't take
+/// any rows of the trace. Since the last block of a message must be compressed with the last block
+/// flag, the prefix must be made of whole blocks and some bytes must follow it.
+#[derive(Clone, Debug)]
+pub struct Blake2bMidstate {
+    pub(crate) chaining_state: [u64; 8],
+    pub(crate) processed_bytes_count: u64,
+    pub(crate) is_last_node: bool,
+}
+
+impl Blake2bMidstate {
+    /// Computes the chaining state after hashing 'prefix' with the given parameters. The length
+    /// of the prefix must be a multiple of 128, and the salt and the personalization of the
+    /// parameters, if any, must be constants.
+    pub fn new<F: PrimeField>(params: &Blake2bParams<F>, prefix: &[u8]) -> Self {
+        enforce_input_sizes(params.output_size, 0);
+        assert!(
+            prefix.len() % BLAKE2B_BLOCK_SIZE == 0,
+            "The prefix of a midstate must be made of whole blocks"
+        );
+
+        let mut chaining_state = IV_CONSTANTS;
+        for (index, word) in params.constant_parameter_block_words(0).iter().enumerate() {
+            chaining_state[index] ^= word;
+        }
+        for (first_word_index, param_bytes) in [(4, &params.salt), (6, &params.personalization)] {
+            match param_bytes {
+                None => {}
+                Some(ParamBytes::Constant(bytes)) => {
+                    for (i, word) in param_bytes_to_words(bytes).iter().enumerate() {
+                        chaining_state[first_word_index + i] ^= word;
+                    }
+                }
+                Some(ParamBytes::Assigned(_)) => {
+                    panic!("The salt and personalization of a midstate must be constants")
+                }
+            }
+        }
+
+        let mut processed_bytes_count = 0;
+        for block in prefix.chunks(BLAKE2B_BLOCK_SIZE) {
+            processed_bytes_count += BLAKE2B_BLOCK_SIZE as u64;
+            compress_native(&mut chaining_state, block, processed_bytes_count);
+        }
+
+        Self {
+            chaining_state,
+            processed_bytes_count,
+            is_last_node: params.last_node,
+        }
+    }
+}
+
+impl<F: PrimeField> Blake2bChip<F> {
+    /// Hashes 'prefix || input', where the prefix is the one of the given [Blake2bMidstate]. The
+    /// hash resumes from the chaining state of the midstate, so only the blocks of 'input' are
+    /// compressed in the circuit, with the processed bytes count following the prefix. The
+    /// 'input' cells should be filled with byte values and can't be empty.
+    pub fn hash_with_midstate(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        midstate: &Blake2bMidstate,
+        input: &[AssignedNative<F>],
+    ) -> Result<[AssignedByte<F>; 64], Error> {
+        assert!(!input.is_empty(), "The input after a midstate can't be empty");
+        let mut state = Blake2bState::from_midstate(self, layouter, midstate)?;
+        state.update(layouter, input)?;
+        state.finalize(layouter)
+    }
+}
@@ -15,8 +15,32 @@ pub(crate) mod blake2b_instructions;
 /// Basic definitions and constants for the blake2b chip.
 pub(crate) mod utils;
 
+/// Optional fields of the blake2b parameter block.
+pub mod params;
+
 /// These are the separated optimizations.
 pub mod blake2b_chip;
 
+/// Standalone compression function of Blake2b.
+pub mod compression;
+
+/// Stateful gadget to hash a message piece by piece.
+pub mod state;
+
+/// Chaining states of public prefixes, computed outside the circuit.
+pub mod midstate;
+
+/// Tree hashing modes built over the chip, including Blake2bp.
+pub mod tree;
+
+/// The Blake2Xb extendable-output function built over the chip.
+pub mod xof;
+
+/// Packing of digests into fewer field elements, for compact public inputs.
+pub mod packing;
+
+/// Reduction of digests into field elements, to derive challenges and scalars.
+pub mod hash_to_field;
+
 /// Number of advice columns required by the chip.
 pub const NB_BLAKE2B_ADVICE_COLS: usize = 9;
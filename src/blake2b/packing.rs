@@ -0,0 +1,107 @@
+use crate::base_operations::pack::WORDS_PER_PACK;
+use crate::blake2b::blake2b_chip::Blake2bChip;
+use crate::types::byte::AssignedByte;
+use crate::types::AssignedNative;
+use ff::PrimeField;
+use midnight_proofs::circuit::{Chip, Layouter};
+use midnight_proofs::plonk::Error;
+
+/// Encoding of a digest as field elements, usually to expose it as public inputs. The bytes of
+/// the digest are always taken in little-endian order, so the first byte of the digest is the
+/// least significant byte of the first element.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DigestPacking {
+    /// An element per byte of the digest.
+    #[default]
+    Bytes,
+    /// An element per 64-bit word, which holds 8 bytes of the digest, or less in the last one.
+    Words,
+    /// Elements holding as many bytes as fit below the capacity of the field, which is 31 bytes
+    /// for the fields of BLS12-381 and BN254. A 32-byte digest fits in two elements and a 64-byte
+    /// digest in three.
+    FieldElements,
+}
+
+impl DigestPacking {
+    /// Amount of bytes of the digest held by every element.
+    pub fn bytes_per_element<F: PrimeField>(&self) -> usize {
+        match self {
+            Self::Bytes => 1,
+            Self::Words => 8,
+            Self::FieldElements => (F::CAPACITY as usize / 8).min(8 * WORDS_PER_PACK),
+        }
+    }
+
+    /// Packs the bytes of a digest computed outside the circuit, which is how the public inputs
+    /// of a packed digest are obtained.
+    pub fn pack<F: PrimeField>(&self, digest: &[u8]) -> Vec<F> {
+        digest
+            .chunks(self.bytes_per_element::<F>())
+            .map(|chunk| {
+                chunk
+                    .iter()
+                    .rev()
+                    .fold(F::ZERO, |element, byte| element * F::from(256) + F::from(*byte as u64))
+            })
+            .collect()
+    }
+}
+
+impl<F: PrimeField> Blake2bChip<F> {
+    /// Packs the bytes of a digest into field elements with the given [DigestPacking], which
+    /// reduces the amount of public inputs needed to expose it. Only the first 'output_size'
+    /// bytes of the result of [Blake2bChip::hash] should be given. Every word is recomposed from
+    /// its bytes with the decomposition gate, and every field element from its words with a pack
+    /// gate, so the packing is constrained in the circuit.
+    ///
+    /// Packing into [DigestPacking::FieldElements] needs the pack gate, which is added to the
+    /// circuit by [Blake2bChip::configure_digest_packing].
+    pub fn pack_digest(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        digest: &[AssignedByte<F>],
+        packing: DigestPacking,
+    ) -> Result<Vec<AssignedNative<F>>, Error> {
+        if packing == DigestPacking::Bytes {
+            return Ok(digest.iter().map(AssignedByte::to_native).collect());
+        }
+        layouter.assign_region(
+            || "pack digest",
+            |mut region| {
+                let mut offset: usize = 0;
+                let zero = region.assign_advice_from_constant(
+                    || "zero",
+                    self.config().limbs[0],
+                    offset,
+                    F::ZERO,
+                )?;
+                offset += 1;
+
+                digest
+                    .chunks(packing.bytes_per_element::<F>())
+                    .map(|element_bytes| {
+                        let words = element_bytes
+                            .chunks(8)
+                            .map(|word_bytes| {
+                                let bytes = std::array::from_fn(|i| match word_bytes.get(i) {
+                                    Some(byte) => byte.to_native(),
+                                    None => zero.clone(),
+                                });
+                                let row = self.new_row_from_assigned_bytes(
+                                    &bytes,
+                                    &mut region,
+                                    &mut offset,
+                                )?;
+                                Ok(row.full_number)
+                            })
+                            .collect::<Result<Vec<_>, Error>>()?;
+                        match packing {
+                            DigestPacking::Words => Ok(words[0].to_native()),
+                            _ => self.pack(&words, None, &mut region, &mut offset),
+                        }
+                    })
+                    .collect()
+            },
+        )
+    }
+}
@@ -0,0 +1,156 @@
+use crate::types::AssignedNative;
+use ff::PrimeField;
+
+/// Length in bytes of the salt and the personalization fields of the parameter block.
+pub const PARAM_BYTES_SIZE: usize = 16;
+
+/// Value of a 16-byte field of the Blake2b parameter block, that is, the salt or the
+/// personalization. The field can be known at circuit building time, in which case it becomes
+/// part of the constants of the circuit, or it can be given as assigned cells, which should be
+/// filled with byte values and are range-checked inside the chip.
+#[derive(Clone, Debug)]
+pub enum ParamBytes<F: PrimeField> {
+    /// Bytes known at circuit building time.
+    Constant([u8; PARAM_BYTES_SIZE]),
+    /// Bytes known at proof generation time.
+    Assigned([AssignedNative<F>; PARAM_BYTES_SIZE]),
+}
+
+/// Returns the two little endian words in which the given bytes are placed in the parameter
+/// block.
+pub(crate) fn param_bytes_to_words(bytes: &[u8; PARAM_BYTES_SIZE]) -> [u64; 2] {
+    [
+        u64::from_le_bytes(bytes[..8].try_into().unwrap()),
+        u64::from_le_bytes(bytes[8..].try_into().unwrap()),
+    ]
+}
+
+/// Builder of the Blake2b parameter block, as defined in the BLAKE2 specification. Besides the
+/// output size, the key size, the salt and the personalization, the parameter block holds the
+/// fields used in tree hashing mode: fanout, depth, leaf length, node offset, node depth and inner
+/// length. The builder also holds the last node flag, which isn't part of the parameter block but
+/// is needed to compute tree hashes. Every field except the key size is set through this builder,
+/// and the key size is taken from the amount of key cells given to the chip. The defaults are the
+/// ones of sequential mode, so `Blake2bParams::new(output_size)` yields the parameter block of a
+/// regular hash.
+///
+/// The tree hashing fields are always known at circuit building time, while the salt and the
+/// personalization can be either constants or assigned bytes, see [ParamBytes].
+#[derive(Clone, Debug)]
+pub struct Blake2bParams<F: PrimeField> {
+    pub(crate) output_size: usize,
+    pub(crate) fanout: u8,
+    depth: u8,
+    pub(crate) leaf_length: u32,
+    node_offset: u64,
+    xof_length: u32,
+    node_depth: u8,
+    pub(crate) inner_length: u8,
+    pub(crate) last_node: bool,
+    pub(crate) salt: Option<ParamBytes<F>>,
+    pub(crate) personalization: Option<ParamBytes<F>>,
+}
+
+impl<F: PrimeField> Blake2bParams<F> {
+    /// Creates the parameters of a sequential hash with the given output size.
+    pub fn new(output_size: usize) -> Self {
+        Self {
+            output_size,
+            fanout: 1,
+            depth: 1,
+            leaf_length: 0,
+            node_offset: 0,
+            xof_length: 0,
+            node_depth: 0,
+            inner_length: 0,
+            last_node: false,
+            salt: None,
+            personalization: None,
+        }
+    }
+
+    /// Sets the fanout, which is 0 for unlimited fanout and 1 for sequential mode.
+    pub fn fanout(mut self, fanout: u8) -> Self {
+        self.fanout = fanout;
+        self
+    }
+
+    /// Sets the maximal depth of the tree, which is 255 for unlimited depth, 1 for sequential
+    /// mode and 0 for the output nodes of Blake2Xb.
+    pub fn depth(mut self, depth: u8) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    /// Sets the maximal byte length of the leaves, which is 0 for unlimited length.
+    pub fn leaf_length(mut self, leaf_length: u32) -> Self {
+        self.leaf_length = leaf_length;
+        self
+    }
+
+    /// Sets the offset of the node in its level of the tree.
+    pub fn node_offset(mut self, node_offset: u64) -> Self {
+        self.node_offset = node_offset;
+        self
+    }
+
+    /// Sets the length of the output of Blake2Xb. In Blake2Xb, the node offset field of the
+    /// parameter block is split in two: the node offset takes the 4 least significant bytes and
+    /// the XOF length takes the 4 most significant ones, so the node offset must fit in 32 bits
+    /// when the XOF length is set.
+    pub fn xof_length(mut self, xof_length: u32) -> Self {
+        self.xof_length = xof_length;
+        self
+    }
+
+    /// Sets the depth of the node in the tree, which is 0 for the leaves.
+    pub fn node_depth(mut self, node_depth: u8) -> Self {
+        self.node_depth = node_depth;
+        self
+    }
+
+    /// Sets the byte length of the digests of the inner nodes of the tree.
+    pub fn inner_length(mut self, inner_length: usize) -> Self {
+        assert!(inner_length <= 64, "Inner length must be between 0 and 64 bytes");
+        self.inner_length = inner_length as u8;
+        self
+    }
+
+    /// Sets whether the node is the last one of its level of the tree. This flag is not part of
+    /// the parameter block, it's set in the compression of the last block of the node.
+    pub fn last_node(mut self, last_node: bool) -> Self {
+        self.last_node = last_node;
+        self
+    }
+
+    /// Sets the salt, which is xored into the words 4 and 5 of the initial state.
+    pub fn salt(mut self, salt: ParamBytes<F>) -> Self {
+        self.salt = Some(salt);
+        self
+    }
+
+    /// Sets the personalization, which is xored into the words 6 and 7 of the initial state.
+    pub fn personalization(mut self, personalization: ParamBytes<F>) -> Self {
+        self.personalization = Some(personalization);
+        self
+    }
+
+    /// Returns the first 4 words of the parameter block, which are always known at circuit
+    /// building time. The rest of the block are the salt and the personalization.
+    pub(crate) fn constant_parameter_block_words(&self, key_size: usize) -> [u64; 4] {
+        assert!(
+            self.xof_length == 0 || self.node_offset <= u32::MAX as u64,
+            "Node offset must fit in 32 bits when the XOF length is set"
+        );
+        [
+            self.output_size as u64
+                ^ ((key_size as u64) << 8)
+                ^ ((self.fanout as u64) << 16)
+                ^ ((self.depth as u64) << 24)
+                ^ ((self.leaf_length as u64) << 32),
+            self.node_offset ^ ((self.xof_length as u64) << 32),
+            self.node_depth as u64 ^ ((self.inner_length as u64) << 8),
+            0,
+        ]
+    }
+}
@@ -0,0 +1,194 @@
+use crate::blake2b::blake2b_chip::Blake2bChip;
+use crate::blake2b::blake2b_instructions::Blake2bInstructions;
+use crate::blake2b::midstate::Blake2bMidstate;
+use crate::blake2b::params::Blake2bParams;
+use crate::blake2b::utils::{
+    constrain_padding_cells_to_equal_zero, enforce_input_sizes, full_number_of_each_state_row,
+    BLAKE2B_BLOCK_SIZE,
+};
+use crate::types::blake2b_word::AssignedBlake2bWord;
+use crate::types::byte::AssignedByte;
+use crate::types::AssignedNative;
+use ff::PrimeField;
+use midnight_proofs::circuit::Layouter;
+use midnight_proofs::plonk::Error;
+
+/// Stateful Blake2b gadget, for messages that are built piece by piece. The state is created with
+/// [Blake2bState::init], the message is given through any amount of calls to
+/// [Blake2bState::update], and the digest is computed by [Blake2bState::finalize].
+///
+/// Between calls, the gadget carries the assigned global state, the bytes of the current block
+/// and the amount of bytes processed so far. Every call works in its own regions, so the calls
+/// can be interleaved with other gadgets. As in the reference implementation, a block is only
+/// compressed once there are more bytes after it, since the last block must be compressed with
+/// the last block flag.
+///
+/// The amount of bytes given to every call must be known at circuit building time, and the digest
+/// is the same as the one of [Blake2bChip::hash_with_params] over the whole message.
+#[derive(Clone, Debug)]
+pub struct Blake2bState<F: PrimeField> {
+    chip: Blake2bChip<F>,
+    iv_constants: [AssignedBlake2bWord<F>; 8],
+    global_state: [AssignedBlake2bWord<F>; 8],
+    zero_constant: AssignedNative<F>,
+    pending_bytes: Vec<AssignedNative<F>>,
+    processed_bytes_count: u64,
+    is_last_node: bool,
+}
+
+impl<F: PrimeField> Blake2bState<F> {
+    /// Creates the state of a hash with the given parameters and key. The 'key' cells should be
+    /// filled with byte values, and when there's a key, its block is the first one of the message.
+    pub fn init(
+        chip: &Blake2bChip<F>,
+        layouter: &mut impl Layouter<F>,
+        params: &Blake2bParams<F>,
+        key: &[AssignedNative<F>],
+    ) -> Result<Self, Error> {
+        enforce_input_sizes(params.output_size, key.len());
+        let (iv_constants, global_state, zero_constant) = layouter.assign_region(
+            || "blake2b init",
+            |mut region| {
+                let mut advice_offset: usize = 0;
+
+                let (iv_constant_cells, initial_state_0, zero_constant) = chip
+                    .assign_constant_advice_cells(
+                        params,
+                        key.len(),
+                        &mut region,
+                        &mut advice_offset,
+                    )?;
+
+                let mut initial_global_state =
+                    chip.compute_initial_state(&iv_constant_cells, initial_state_0)?;
+
+                chip.xor_params_into_initial_state(
+                    &mut initial_global_state,
+                    params,
+                    key.len(),
+                    &mut region,
+                    &mut advice_offset,
+                )?;
+
+                Ok((iv_constant_cells, initial_global_state, zero_constant))
+            },
+        )?;
+
+        let mut pending_bytes = key.to_vec();
+        if !key.is_empty() {
+            pending_bytes.resize(BLAKE2B_BLOCK_SIZE, zero_constant.clone());
+        }
+        Ok(Self {
+            chip: chip.clone(),
+            iv_constants,
+            global_state,
+            zero_constant,
+            pending_bytes,
+            processed_bytes_count: 0,
+            is_last_node: params.last_node,
+        })
+    }
+
+    /// Creates the state of a hash that resumes from the given [Blake2bMidstate], whose chaining
+    /// state and processed bytes count are loaded as constants. At least one byte must be given to
+    /// [Blake2bState::update] before finalizing, since the prefix of the midstate can't hold the
+    /// last block of the message.
+    pub fn from_midstate(
+        chip: &Blake2bChip<F>,
+        layouter: &mut impl Layouter<F>,
+        midstate: &Blake2bMidstate,
+    ) -> Result<Self, Error> {
+        let (iv_constants, global_state, zero_constant) = layouter.assign_region(
+            || "blake2b midstate",
+            |mut region| {
+                let mut advice_offset: usize = 0;
+                chip.assign_midstate_constant_cells(
+                    &midstate.chaining_state,
+                    &mut region,
+                    &mut advice_offset,
+                )
+            },
+        )?;
+        Ok(Self {
+            chip: chip.clone(),
+            iv_constants,
+            global_state,
+            zero_constant,
+            pending_bytes: vec![],
+            processed_bytes_count: midstate.processed_bytes_count,
+            is_last_node: midstate.is_last_node,
+        })
+    }
+
+    /// Adds the given bytes to the message. Every block that is followed by more bytes is
+    /// compressed, and the rest of the bytes wait for the next call. The 'bytes' cells should be
+    /// filled with byte values.
+    pub fn update(
+        &mut self,
+        layouter: &mut impl Layouter<F>,
+        bytes: &[AssignedNative<F>],
+    ) -> Result<(), Error> {
+        self.pending_bytes.extend_from_slice(bytes);
+        while self.pending_bytes.len() > BLAKE2B_BLOCK_SIZE {
+            let block: Vec<AssignedNative<F>> =
+                self.pending_bytes.drain(..BLAKE2B_BLOCK_SIZE).collect();
+            self.processed_bytes_count += BLAKE2B_BLOCK_SIZE as u64;
+            self.compress_block(layouter, block, false)?;
+        }
+        Ok(())
+    }
+
+    /// Compresses the last block of the message and returns the digest. As in [Blake2bChip::hash],
+    /// only the first 'output_size' bytes of the result are part of the digest.
+    pub fn finalize(
+        mut self,
+        layouter: &mut impl Layouter<F>,
+    ) -> Result<[AssignedByte<F>; 64], Error> {
+        let block = std::mem::take(&mut self.pending_bytes);
+        self.processed_bytes_count += block.len() as u64;
+        self.compress_block(layouter, block, true)
+    }
+
+    /// Compresses a block of at most 128 bytes in a new region, padding it with zeros, and
+    /// updates the global state.
+    fn compress_block(
+        &mut self,
+        layouter: &mut impl Layouter<F>,
+        mut block: Vec<AssignedNative<F>>,
+        is_last_block: bool,
+    ) -> Result<[AssignedByte<F>; 64], Error> {
+        let amount_of_zeros_to_pad = BLAKE2B_BLOCK_SIZE - block.len();
+        block.resize(BLAKE2B_BLOCK_SIZE, self.zero_constant.clone());
+        let (global_state, digest) = layouter.assign_region(
+            || "blake2b compression",
+            |mut region| {
+                let mut advice_offset: usize = 0;
+                let mut global_state = self.global_state.clone();
+                let current_block_rows = self.chip.block_words_from_bytes(
+                    &mut region,
+                    &mut advice_offset,
+                    block.clone().try_into().unwrap(),
+                )?;
+                constrain_padding_cells_to_equal_zero(
+                    &mut region,
+                    amount_of_zeros_to_pad,
+                    &current_block_rows,
+                    &self.zero_constant,
+                )?;
+                let digest = self.chip.compress(
+                    &mut region,
+                    &mut advice_offset,
+                    &self.iv_constants,
+                    &mut global_state,
+                    full_number_of_each_state_row(current_block_rows),
+                    self.processed_bytes_count,
+                    is_last_block,
+                    self.is_last_node,
+                )?;
+                Ok((global_state, digest))
+            },
+        )?;
+        self.global_state = global_state;
+        Ok(digest)
+    }
+}
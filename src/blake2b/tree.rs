@@ -0,0 +1,104 @@
+use crate::blake2b::blake2b_chip::Blake2bChip;
+use crate::blake2b::params::Blake2bParams;
+use crate::blake2b::utils::BLAKE2B_BLOCK_SIZE;
+use crate::types::byte::AssignedByte;
+use crate::types::AssignedNative;
+use ff::PrimeField;
+use midnight_proofs::circuit::Layouter;
+use midnight_proofs::plonk::Error;
+
+/// Amount of leaves of Blake2bp.
+pub const BLAKE2BP_DEGREE: usize = 4;
+
+/// Size of the digests of the leaves of Blake2bp, which is also its inner length.
+const BLAKE2BP_INNER_LENGTH: usize = 64;
+
+impl<F: PrimeField> Blake2bChip<F> {
+    /// Computes the Blake2bp digest of the input, as defined in the BLAKE2 specification and
+    /// computed by `b2sum -a blake2bp`, without a key. The blocks of the input are distributed
+    /// among 4 leaves in round robin, and the root hashes the concatenation of the 64-byte digests
+    /// of the leaves.
+    /// Every node is a regular hash of the chip, with the tree fields of its parameter block and
+    /// the last node flag set in the last leaf and in the root.
+    pub fn hash_blake2bp(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        input: &[AssignedNative<F>],
+        output_size: usize,
+    ) -> Result<[AssignedByte<F>; 64], Error> {
+        // As in the reference implementation, the output size of the parameter block of every
+        // node is the requested one, but the leaves always hand their whole state to the root
+        let base_params = Blake2bParams::new(output_size)
+            .fanout(BLAKE2BP_DEGREE as u8)
+            .depth(2)
+            .inner_length(BLAKE2BP_INNER_LENGTH);
+
+        let mut leaf_digests = Vec::with_capacity(BLAKE2BP_DEGREE * BLAKE2BP_INNER_LENGTH);
+        for leaf_index in 0..BLAKE2BP_DEGREE {
+            let leaf_input: Vec<AssignedNative<F>> = input
+                .chunks(BLAKE2B_BLOCK_SIZE)
+                .skip(leaf_index)
+                .step_by(BLAKE2BP_DEGREE)
+                .flatten()
+                .cloned()
+                .collect();
+            let leaf_params = base_params
+                .clone()
+                .node_offset(leaf_index as u64)
+                .last_node(leaf_index == BLAKE2BP_DEGREE - 1);
+            let leaf_digest = self.hash_with_params(layouter, &leaf_input, &[], &leaf_params)?;
+            leaf_digests.extend(leaf_digest.iter().map(AssignedByte::to_native));
+        }
+
+        let root_params = base_params.node_depth(1).last_node(true);
+        self.hash_with_params(layouter, &leaf_digests, &[], &root_params)
+    }
+
+    /// Computes an unkeyed Blake2b tree hash of the input with unlimited depth. The input is split
+    /// in leaves of 'leaf_length' bytes, or in a single empty leaf if the input is empty, and the
+    /// digests of each level are grouped by 'fanout' to build the nodes of the next level, until
+    /// a single node remains, which is the root. The node offset, the node depth and the last node
+    /// flag of every node are set by this method, and the rest of the parameter block is taken
+    /// from 'params'. The inner nodes have a digest of 'inner_length' bytes and the root has a
+    /// digest of 'output_size' bytes, both taken from 'params'.
+    pub fn hash_tree(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        input: &[AssignedNative<F>],
+        params: &Blake2bParams<F>,
+    ) -> Result<[AssignedByte<F>; 64], Error> {
+        assert!(params.fanout >= 2, "Fanout must be at least 2 in a tree hash");
+        assert!(params.leaf_length > 0, "Leaf length can't be unlimited in a tree hash");
+        assert!(params.inner_length > 0, "Inner length can't be 0 in a tree hash");
+        let inner_length = params.inner_length as usize;
+
+        let mut level: Vec<Vec<AssignedNative<F>>> = if input.is_empty() {
+            vec![vec![]]
+        } else {
+            input.chunks(params.leaf_length as usize).map(|leaf| leaf.to_vec()).collect()
+        };
+        let mut node_depth = 0u8;
+        loop {
+            let is_root_level = level.len() == 1;
+            let mut digests = Vec::with_capacity(level.len());
+            for (node_offset, node_input) in level.iter().enumerate() {
+                let mut node_params = params
+                    .clone()
+                    .node_offset(node_offset as u64)
+                    .node_depth(node_depth)
+                    .last_node(node_offset == level.len() - 1);
+                if is_root_level {
+                    return self.hash_with_params(layouter, node_input, &[], &node_params);
+                }
+                node_params.output_size = inner_length;
+                let digest = self.hash_with_params(layouter, node_input, &[], &node_params)?;
+                digests.extend(digest[..inner_length].iter().map(AssignedByte::to_native));
+            }
+            level = digests
+                .chunks(params.fanout as usize * inner_length)
+                .map(|children| children.to_vec())
+                .collect();
+            node_depth += 1;
+        }
+    }
+}
@@ -14,6 +14,21 @@ pub(crate) fn enforce_input_sizes(output_size: usize, key_size: usize) {
     assert!(key_size <= 64, "Key size must be between 1 and 64 bytes");
 }
 
+/// Enforces the output size of Blake2Xb.
+/// Output size must be between 1 and 2^32 - 2 bytes, since 2^32 - 1 stands for an unknown length.
+pub(crate) fn enforce_xof_output_size(output_size: usize) {
+    assert!(
+        output_size > 0 && output_size < u32::MAX as usize,
+        "XOF output size must be between 1 and 2^32 - 2 bytes"
+    );
+}
+
+/// Enforces the amount of key cells when the key length is only known at proof generation time.
+/// The key block is always processed in that case, so there must be at least one key cell.
+pub(crate) fn enforce_private_key_size(max_key_size: usize) {
+    assert!(max_key_size > 0, "Key cells can't be empty when the key length is private");
+}
+
 /// Extracts the full number cell of each of the state rows
 pub(crate) fn full_number_of_each_state_row<F: PrimeField>(
     current_block_rows: [AssignedRow<F>; 16],
@@ -92,6 +107,18 @@ pub(crate) fn get_total_blocks_count(
     }
 }
 
+/// Computes the amount of input blocks to process when the input length is only known at proof
+/// generation time. It's the amount of blocks of an input of maximum size, except when there's no
+/// key, where at least one block is always processed.
+pub(crate) fn get_variable_input_blocks_count(max_input_size: usize, is_key_empty: bool) -> usize {
+    let input_blocks = max_input_size.div_ceil(BLAKE2B_BLOCK_SIZE);
+    if is_key_empty {
+        input_blocks.max(1)
+    } else {
+        input_blocks
+    }
+}
+
 /// This method constrains the padding cells to equal zero. The amount of constraints
 /// depends on the input size and the key size, which makes sense since those values are known
 /// at circuit building time.
@@ -119,6 +146,35 @@ pub(crate) fn constrain_padding_cells_to_equal_zero<F: PrimeField>(
     Ok(())
 }
 
+/// Computes the Blake2b compression of a block that is not the last one outside the circuit. It's
+/// used to compute the chaining state after a prefix known at circuit building time.
+pub(crate) fn compress_native(state: &mut [u64; 8], block: &[u8], processed_bytes_count: u64) {
+    let message: Vec<u64> =
+        block.chunks(8).map(|word| u64::from_le_bytes(word.try_into().unwrap())).collect();
+    let mut v = [0u64; 16];
+    v[..8].copy_from_slice(state);
+    v[8..].copy_from_slice(&IV_CONSTANTS);
+    v[12] ^= processed_bytes_count;
+
+    for sigma in SIGMA.iter() {
+        for (j, [a, b, c, d]) in ABCD.iter().enumerate() {
+            let (x, y) = (message[sigma[2 * j]], message[sigma[2 * j + 1]]);
+            v[*a] = v[*a].wrapping_add(v[*b]).wrapping_add(x);
+            v[*d] = (v[*d] ^ v[*a]).rotate_right(32);
+            v[*c] = v[*c].wrapping_add(v[*d]);
+            v[*b] = (v[*b] ^ v[*c]).rotate_right(24);
+            v[*a] = v[*a].wrapping_add(v[*b]).wrapping_add(y);
+            v[*d] = (v[*d] ^ v[*a]).rotate_right(16);
+            v[*c] = v[*c].wrapping_add(v[*d]);
+            v[*b] = (v[*b] ^ v[*c]).rotate_right(63);
+        }
+    }
+
+    for i in 0..8 {
+        state[i] ^= v[i] ^ v[i + 8];
+    }
+}
+
 // ----- Blake2b constants -----
 
 pub const BLAKE2B_BLOCK_SIZE: usize = 128;
@@ -0,0 +1,60 @@
+use crate::blake2b::blake2b_chip::Blake2bChip;
+use crate::blake2b::params::{Blake2bParams, ParamBytes};
+use crate::blake2b::utils::enforce_xof_output_size;
+use crate::types::byte::AssignedByte;
+use crate::types::AssignedNative;
+use ff::PrimeField;
+use midnight_proofs::circuit::Layouter;
+use midnight_proofs::plonk::Error;
+
+/// Size of the root digest of Blake2Xb, and of the digest of every output node except the last.
+const BLAKE2XB_NODE_OUTPUT_SIZE: usize = 64;
+
+impl<F: PrimeField> Blake2bChip<F> {
+    /// Computes 'output_size' bytes of the Blake2Xb extendable-output function of the input, as
+    /// defined in the BLAKE2X specification. The input and the key are hashed into a 64-byte root
+    /// digest with the XOF length set in the parameter block, and then every 64 bytes of the
+    /// output are the digest of an output node, which hashes the root digest with the offset of
+    /// the node. The optional 'salt' and 'personalization' are used in every node, like in
+    /// [Blake2bChip::hash].
+    /// The output size must be known at circuit building time, and every output node costs a
+    /// compression.
+    pub fn hash_blake2xb(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        input: &[AssignedNative<F>],
+        key: &[AssignedNative<F>],
+        output_size: usize,
+        salt: Option<&ParamBytes<F>>,
+        personalization: Option<&ParamBytes<F>>,
+    ) -> Result<Vec<AssignedByte<F>>, Error> {
+        enforce_xof_output_size(output_size);
+        let mut root_params =
+            Blake2bParams::new(BLAKE2XB_NODE_OUTPUT_SIZE).xof_length(output_size as u32);
+        root_params.salt = salt.cloned();
+        root_params.personalization = personalization.cloned();
+        let root_digest: Vec<AssignedNative<F>> = self
+            .hash_with_params(layouter, input, key, &root_params)?
+            .iter()
+            .map(AssignedByte::to_native)
+            .collect();
+
+        let mut output = Vec::with_capacity(output_size);
+        for node_offset in 0..output_size.div_ceil(BLAKE2XB_NODE_OUTPUT_SIZE) {
+            let node_output_size = BLAKE2XB_NODE_OUTPUT_SIZE
+                .min(output_size - node_offset * BLAKE2XB_NODE_OUTPUT_SIZE);
+            let mut node_params = Blake2bParams::new(node_output_size)
+                .fanout(0)
+                .depth(0)
+                .leaf_length(BLAKE2XB_NODE_OUTPUT_SIZE as u32)
+                .node_offset(node_offset as u64)
+                .xof_length(output_size as u32)
+                .inner_length(BLAKE2XB_NODE_OUTPUT_SIZE);
+            node_params.salt = salt.cloned();
+            node_params.personalization = personalization.cloned();
+            let node_digest = self.hash_with_params(layouter, &root_digest, &[], &node_params)?;
+            output.extend_from_slice(&node_digest[..node_output_size]);
+        }
+        Ok(output)
+    }
+}
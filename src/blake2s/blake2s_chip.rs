@@ -0,0 +1,610 @@
+use std::marker::PhantomData;
+
+use crate::base_operations::addition_mod_32::AdditionMod32Config;
+use crate::base_operations::generic_limb_rotation::LimbRotation;
+use crate::base_operations::rotate_31::Rotate31Config;
+use crate::base_operations::xor::XorConfig;
+use crate::base_operations::{
+    create_limb_decomposition_gate, create_range_check_gate, generate_row_from_assigned_bytes,
+    populate_lookup_table,
+};
+use crate::blake2b::blake2b_instructions::ConstantCells;
+use crate::blake2b::utils::{get_total_blocks_count, ABCD, SIGMA};
+use crate::blake2s::blake2s_instructions::Blake2sInstructions;
+use crate::blake2s::utils::{
+    compute_processed_bytes_count_value_for_iteration, constrain_padding_cells_to_equal_zero,
+    enforce_input_sizes, zeros_to_pad_in_current_block, BLAKE2S_BLOCK_SIZE, BLAKE2S_ROUNDS,
+    BLAKE2S_WORD_SIZE, IV_CONSTANTS,
+};
+use crate::blake2s::NB_BLAKE2S_ADVICE_COLS;
+use crate::types::blake2b_word::AssignedBlake2bWord;
+use crate::types::byte::AssignedByte;
+use crate::types::row::AssignedRow;
+use crate::types::{enforce_little_endian_representation, AssignedNative};
+use ff::{Field, PrimeField};
+use midnight_proofs::circuit::{Chip, Layouter, Region};
+use midnight_proofs::plonk::{Advice, Column, ConstraintSystem, Error, Fixed, Selector, TableColumn};
+
+/// Selectors and columns for the blake2s chip implementation.
+#[derive(Clone, Debug)]
+pub struct Blake2sConfig {
+    /// Base oprerations configs
+    addition_config: AdditionMod32Config,
+    generic_limb_rotation_config: LimbRotation,
+    rotate_31_config: Rotate31Config,
+    xor_config: XorConfig,
+    /// Advice columns
+    full_number_u64: Column<Advice>,
+    /// Columns for the blake2s limbs.
+    pub limbs: [Column<Advice>; 8],
+    /// Decomposition selectors
+    q_range: Selector,
+    q_decompose: Selector,
+    t_range: TableColumn,
+}
+
+/// This is the main chip for the Blake2s hash function. It is responsible for the entire hash
+/// computation.
+///
+/// It works over the same trace as the [Blake2bChip]: a column for the full number and 8 columns
+/// for its 8-bit limbs. The 32-bit words of Blake2s take the full number column and the 4 least
+/// significant limbs, and the 4 most significant limbs are constrained to be zero. The xor is
+/// computed with the same table of 8-bit operands, the additions are mod 2^32, and the rotations
+/// by 16 and 8 bits are limb rotations. The rotations by 12 and 7 bits are limb rotations by 16
+/// and 8 bits followed by 4 and 1 rotations by 1 bit to the left, respectively.
+///
+/// [Blake2bChip]: crate::blake2b::blake2b_chip::Blake2bChip
+#[derive(Clone, Debug)]
+pub struct Blake2sChip<F> {
+    config: Blake2sConfig,
+    _marker: PhantomData<F>,
+}
+
+impl<F: Field> Chip<F> for Blake2sChip<F> {
+    type Config = Blake2sConfig;
+    type Loaded = ();
+
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+impl<F: PrimeField> Blake2sInstructions<F> for Blake2sChip<F> {
+    /// This chip uses the same 2 tables as the Blake2b chip:
+    /// * A lookup table for range-checks of 8 bits: [0, 255]
+    /// * A lookup table consisting of 3 columns that pre-computes the xor operation of 16 bits.
+    fn populate_lookup_tables(&self, layouter: &mut impl Layouter<F>) -> Result<(), Error> {
+        populate_lookup_table(layouter, self.config.t_range)?;
+        self.config.xor_config.populate_xor_lookup_table(layouter)
+    }
+
+    /// Here the constants that will be used throughout the algorithm are assigned in some storage
+    /// cells at the begining of the trace.
+    fn assign_constant_advice_cells(
+        &self,
+        output_size: usize,
+        key_size: usize,
+        region: &mut Region<'_, F>,
+        advice_offset: &mut usize,
+    ) -> Result<ConstantCells<F>, Error> {
+        let iv_constant_cells: [AssignedBlake2bWord<F>; 8] = IV_CONSTANTS
+            .iter()
+            .enumerate()
+            .map(|(index, constant)| {
+                self.assign_limb_constant_u32(
+                    region,
+                    advice_offset,
+                    "iv constants",
+                    *constant,
+                    index,
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?
+            .try_into()
+            .unwrap();
+        *advice_offset += 1;
+
+        let zero_constant = region.assign_advice_from_constant(
+            || "zero",
+            self.config.limbs[0],
+            *advice_offset,
+            F::from(0),
+        )?;
+
+        // state[0] = state[0] ^ 0x01010000 ^ (key.len() << 8) as u32 ^ outlen as u32;
+        let initial_state_index_0 =
+            IV_CONSTANTS[0] ^ 0x01010000 ^ ((key_size as u32) << 8) ^ (output_size as u32);
+
+        let initial_state_0 = self.assign_limb_constant_u32(
+            region,
+            advice_offset,
+            "initial state index 0",
+            initial_state_index_0,
+            1,
+        )?;
+
+        *advice_offset += 1;
+
+        Ok((iv_constant_cells, initial_state_0, zero_constant))
+    }
+
+    fn perform_blake2s_iterations(
+        &self,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+        input: &[AssignedNative<F>],
+        key: &[AssignedNative<F>],
+        iv_constants: &[AssignedBlake2bWord<F>; 8],
+        global_state: &mut [AssignedBlake2bWord<F>; 8],
+        zero_constant_cell: AssignedNative<F>,
+    ) -> Result<[AssignedByte<F>; 32], Error> {
+        let input_size = input.len();
+        let is_key_empty = key.is_empty();
+        let is_input_empty = input_size == 0;
+
+        let input_blocks = input_size.div_ceil(BLAKE2S_BLOCK_SIZE);
+        let total_blocks = get_total_blocks_count(input_blocks, is_input_empty, is_key_empty);
+
+        // The key block goes first, followed by the blocks of the input
+        let mut message = key.to_vec();
+        if !is_key_empty {
+            message.resize(BLAKE2S_BLOCK_SIZE, zero_constant_cell.clone());
+        }
+        message.extend_from_slice(input);
+        message.resize(total_blocks * BLAKE2S_BLOCK_SIZE, zero_constant_cell.clone());
+
+        // Main loop
+        message
+            .chunks(BLAKE2S_BLOCK_SIZE)
+            .enumerate()
+            .map(|(i, block)| {
+                let is_last_block = i == total_blocks - 1;
+                let is_key_block = !is_key_empty && i == 0;
+
+                let processed_bytes_count = compute_processed_bytes_count_value_for_iteration(
+                    i,
+                    is_last_block,
+                    input_size,
+                    is_key_empty,
+                );
+
+                let amount_of_zeros_to_pad =
+                    zeros_to_pad_in_current_block(key, input_size, is_last_block, is_key_block);
+
+                let current_block_rows =
+                    self.block_words_from_bytes(region, offset, block, &zero_constant_cell)?;
+
+                constrain_padding_cells_to_equal_zero(
+                    region,
+                    amount_of_zeros_to_pad,
+                    &current_block_rows,
+                    &zero_constant_cell,
+                )?;
+
+                let current_block_cells = current_block_rows
+                    .iter()
+                    .map(|row| row.full_number.clone())
+                    .collect::<Vec<_>>()
+                    .try_into()
+                    .unwrap();
+
+                self.compress(
+                    region,
+                    offset,
+                    iv_constants,
+                    global_state,
+                    current_block_cells,
+                    processed_bytes_count,
+                    is_last_block,
+                )
+            })
+            .last()
+            // Note: `total_blocks` is greater or equal than 1. Therefore, this `unwrap` must
+            // succeeds.
+            .expect("unexpected empty sequence of blake2s blocks")
+    }
+
+    fn compress(
+        &self,
+        region: &mut Region<'_, F>,
+        row_offset: &mut usize,
+        iv_constants: &[AssignedBlake2bWord<F>; 8],
+        global_state: &mut [AssignedBlake2bWord<F>; 8],
+        current_block: [AssignedBlake2bWord<F>; 16],
+        processed_bytes_count: u64,
+        is_last_block: bool,
+    ) -> Result<[AssignedByte<F>; 32], Error> {
+        let mut state_vector: Vec<AssignedBlake2bWord<F>> = Vec::new();
+        state_vector.extend_from_slice(global_state);
+        state_vector.extend_from_slice(iv_constants);
+        let mut state: [AssignedBlake2bWord<F>; 16] = state_vector.try_into().unwrap();
+
+        // accumulative_state[12] ^= processed_bytes_count as u32
+        // accumulative_state[13] ^= (processed_bytes_count >> 32) as u32
+        // Since the processed_bytes_count and the last block flag are public for both parties,
+        // the words 12 to 14 of the state are constants, which are placed in a single row.
+        state[12] = self.assign_limb_constant_u32(
+            region,
+            row_offset,
+            "New state[12]",
+            IV_CONSTANTS[4] ^ processed_bytes_count as u32,
+            0,
+        )?;
+        let processed_bytes_count_high = (processed_bytes_count >> 32) as u32;
+        if processed_bytes_count_high != 0 {
+            state[13] = self.assign_limb_constant_u32(
+                region,
+                row_offset,
+                "New state[13]",
+                IV_CONSTANTS[5] ^ processed_bytes_count_high,
+                1,
+            )?;
+        }
+        if is_last_block {
+            state[14] = self.assign_limb_constant_u32(
+                region,
+                row_offset,
+                "New state[14]",
+                !IV_CONSTANTS[6],
+                2,
+            )?;
+        }
+        *row_offset += 1;
+
+        // Main loop
+        for sigma in SIGMA.iter().take(BLAKE2S_ROUNDS) {
+            for (j, state_indexes) in ABCD.iter().enumerate() {
+                self.mix(
+                    *state_indexes,
+                    current_block[sigma[2 * j]].clone(),
+                    current_block[sigma[2 * j + 1]].clone(),
+                    &mut state,
+                    region,
+                    row_offset,
+                )?;
+            }
+        }
+
+        let mut global_state_bytes: Vec<AssignedByte<F>> = Vec::new();
+        for i in 0..8 {
+            global_state[i] =
+                self.xor(&global_state[i], &state[i], region, row_offset)?.full_number;
+            let row = self.xor(&global_state[i], &state[i + 8], region, row_offset)?;
+            global_state_bytes.extend_from_slice(&row.limbs[..BLAKE2S_WORD_SIZE]);
+            global_state[i] = row.full_number;
+        }
+        Ok(global_state_bytes.try_into().unwrap())
+    }
+
+    fn mix(
+        &self,
+        state_indexes: [usize; 4],
+        x: AssignedBlake2bWord<F>,
+        y: AssignedBlake2bWord<F>,
+        state: &mut [AssignedBlake2bWord<F>; 16],
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+    ) -> Result<(), Error> {
+        let v_a = &state[state_indexes[0]];
+        let v_b = &state[state_indexes[1]];
+        let v_c = &state[state_indexes[2]];
+        let v_d = &state[state_indexes[3]];
+
+        // v[a] = ((v[a] as u64 + v[b] as u64 + x as u64) % (1 << 32)) as u32;
+        let a_plus_b = self.add(v_a, v_b, region, offset)?;
+        let a = self.add_copying_one_parameter(&a_plus_b.full_number, &x, region, offset)?;
+
+        // v[d] = rotr_32(v[d] ^ v[a], 16);
+        let d_xor_a = self.xor_copying_one_parameter(&a, v_d, region, offset)?;
+        let d = self.rotate_right_limbs(d_xor_a, 2, region, offset)?;
+
+        // v[c] = ((v[c] as u64 + v[d] as u64) % (1 << 32)) as u32;
+        let c = self.add_copying_one_parameter(&d, v_c, region, offset)?;
+
+        // v[b] = rotr_32(v[b] ^ v[c], 12);
+        let b_xor_c = self.xor_copying_one_parameter(&c, v_b, region, offset)?;
+        let b = self.rotate_right_limbs(b_xor_c, 2, region, offset)?;
+        let b = self.rotate_left_bits(b, 4, region, offset)?;
+
+        // v[a] = ((v[a] as u64 + v[b] as u64 + y as u64) % (1 << 32)) as u32;
+        let a_plus_b = self.add_copying_one_parameter(&b, &a.full_number, region, offset)?;
+        let a = self.add_copying_one_parameter(&a_plus_b.full_number, &y, region, offset)?;
+
+        // v[d] = rotr_32(v[d] ^ v[a], 8);
+        let d_xor_a = self.xor_copying_one_parameter(&a, &d, region, offset)?;
+        let d = self.rotate_right_limbs(d_xor_a, 1, region, offset)?;
+
+        // v[c] = ((v[c] as u64 + v[d] as u64) % (1 << 32)) as u32;
+        let c = self.add_copying_one_parameter(&d, &c.full_number, region, offset)?;
+
+        // v[b] = rotr_32(v[b] ^ v[c], 7);
+        let b_xor_c = self.xor_copying_one_parameter(&c, &b, region, offset)?;
+        let b = self.rotate_right_limbs(b_xor_c, 1, region, offset)?;
+        let b = self.rotate_left_bits(b, 1, region, offset)?;
+
+        state[state_indexes[0]] = a.full_number;
+        state[state_indexes[1]] = b;
+        state[state_indexes[2]] = c.full_number;
+        state[state_indexes[3]] = d;
+
+        Ok(())
+    }
+}
+
+impl<F: PrimeField> Blake2sChip<F> {
+    /// Generation of a fresh circuit from a configuration.
+    pub fn new(config: &Blake2sConfig) -> Self {
+        Self {
+            config: config.clone(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Configuration of the circuit, this includes initialization of all the necessary configs.
+    /// It should be called in the configuration of the user circuit before instantiating the
+    /// Blake2s gadget.
+    ///
+    /// Note: following the convention in midnight-circuits, this function enables equality on all
+    /// necessary columns, i.e., it should not be done manually before calling this function.
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        constants: Column<Fixed>,
+        full_number_u64: Column<Advice>,
+        limbs: [Column<Advice>; NB_BLAKE2S_ADVICE_COLS - 1],
+    ) -> <Self as Chip<F>>::Config {
+        enforce_little_endian_representation::<F>();
+
+        // Enabling column properties.
+        meta.enable_constant(constants);
+        meta.enable_equality(full_number_u64);
+        for limb in limbs {
+            meta.enable_equality(limb);
+        }
+
+        // Gate that checks if the 8-bit limb decomposition is correct
+        let q_decompose = meta.complex_selector();
+        create_limb_decomposition_gate(meta, q_decompose, full_number_u64, limbs);
+
+        // Range-check lookups
+        let q_range = meta.complex_selector();
+        let t_range = meta.lookup_table_column();
+        create_range_check_gate(meta, t_range, q_range, limbs);
+
+        let rotate_31_config =
+            Rotate31Config::configure(meta, full_number_u64, limbs, q_decompose, q_range);
+        let generic_limb_rotation_config = LimbRotation::configure(q_decompose);
+        let addition_config =
+            AdditionMod32Config::configure(meta, full_number_u64, limbs, q_decompose, q_range);
+        let xor_config = XorConfig::configure(meta, limbs, full_number_u64, limbs, q_decompose);
+
+        Blake2sConfig {
+            addition_config,
+            generic_limb_rotation_config,
+            rotate_31_config,
+            xor_config,
+            full_number_u64,
+            limbs,
+            q_range,
+            q_decompose,
+            t_range,
+        }
+    }
+
+    /// Loading the tables used in the chip.
+    pub fn load(&self, layouter: &mut impl Layouter<F>) -> Result<(), Error> {
+        self.populate_lookup_tables(layouter)
+    }
+
+    /// Main method of the chip. The 'input' and 'key' cells should be filled with byte values.
+    /// The output size must be between 1 and 32 bytes and the key can have up to 32 bytes. Only
+    /// the first 'output_size' bytes of the result are part of the digest.
+    pub fn hash(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        input: &[AssignedNative<F>],
+        key: &[AssignedNative<F>],
+        output_size: usize,
+    ) -> Result<[AssignedByte<F>; 32], Error> {
+        enforce_input_sizes(output_size, key.len());
+        // All the computation is performed inside a single region
+        layouter.assign_region(
+            || "single region",
+            |mut region| {
+                let mut advice_offset: usize = 0;
+
+                let (iv_constant_cells, initial_state_0, zero_constant) = self
+                    .assign_constant_advice_cells(
+                        output_size,
+                        key.len(),
+                        &mut region,
+                        &mut advice_offset,
+                    )?;
+
+                let mut global_state = iv_constant_cells.clone();
+                global_state[0] = initial_state_0;
+
+                self.perform_blake2s_iterations(
+                    &mut region,
+                    &mut advice_offset,
+                    input,
+                    key,
+                    &iv_constant_cells,
+                    &mut global_state,
+                    zero_constant,
+                )
+            },
+        )
+    }
+}
+
+impl<F: PrimeField> Blake2sChip<F> {
+    /// Bitwise xor operation. It's performed over two assigned words and implemented through a
+    /// [XorConfig] which creates all the necessary lookups. The most significant limbs of both
+    /// operands are zero, so the ones of the result are zero too.
+    pub(crate) fn xor(
+        &self,
+        lhs: &AssignedBlake2bWord<F>,
+        rhs: &AssignedBlake2bWord<F>,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+    ) -> Result<AssignedRow<F>, Error> {
+        self.config.xor_config.generate_xor_rows_from_cells(region, offset, lhs, rhs)
+    }
+
+    /// Same as [Blake2sChip::xor], but reusing the first operand, which must be the last row of
+    /// the trace. It returns the whole row, so the next rotation can read the limbs directly.
+    fn xor_copying_one_parameter(
+        &self,
+        previous_operand: &AssignedRow<F>,
+        cell_to_copy: &AssignedBlake2bWord<F>,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+    ) -> Result<AssignedRow<F>, Error> {
+        self.config.xor_config.generate_xor_rows_reusing_first_operand(
+            region,
+            offset,
+            previous_operand,
+            cell_to_copy,
+        )
+    }
+
+    /// Addition mod 2^32 of two assigned words. It's implemented through a [AdditionMod32Config].
+    fn add(
+        &self,
+        lhs: &AssignedBlake2bWord<F>,
+        rhs: &AssignedBlake2bWord<F>,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+    ) -> Result<AssignedRow<F>, Error> {
+        Ok(self
+            .config
+            .addition_config
+            .generate_addition_rows_from_cells(
+                region,
+                offset,
+                lhs,
+                rhs,
+                false,
+                self.config.full_number_u64,
+                self.config.limbs,
+            )?
+            .0)
+    }
+
+    /// Same as [Blake2sChip::add], but the first operand must be the last cell of the trace, so
+    /// it doesn't need to be copied.
+    fn add_copying_one_parameter(
+        &self,
+        previous_cell: &AssignedBlake2bWord<F>,
+        cell_to_copy: &AssignedBlake2bWord<F>,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+    ) -> Result<AssignedRow<F>, Error> {
+        Ok(self
+            .config
+            .addition_config
+            .generate_addition_rows_from_cells(
+                region,
+                offset,
+                previous_cell,
+                cell_to_copy,
+                true, // Uses the optimization
+                self.config.full_number_u64,
+                self.config.limbs,
+            )?
+            .0)
+    }
+
+    /// Bitwise rotation mod 32 bits by a whole amount of limbs to the right. Internally uses the
+    /// [LimbRotation] gate and receives an [AssignedRow] as input because it needs the limbs to
+    /// establish the necessary restrictions. It only returns the full number.
+    fn rotate_right_limbs(
+        &self,
+        input_row: AssignedRow<F>,
+        limbs_to_rotate: usize,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+    ) -> Result<AssignedBlake2bWord<F>, Error> {
+        self.config.generic_limb_rotation_config.generate_32_bit_rotation_rows_from_input_row(
+            region,
+            offset,
+            input_row,
+            limbs_to_rotate,
+            self.config.full_number_u64,
+            self.config.limbs,
+        )
+    }
+
+    /// Bitwise rotation mod 32 bits by a few bits to the left, one row per bit. Internally uses a
+    /// [Rotate31Config], so the input must be the last cell of the trace.
+    fn rotate_left_bits(
+        &self,
+        input: AssignedBlake2bWord<F>,
+        bits_to_rotate: usize,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+    ) -> Result<AssignedBlake2bWord<F>, Error> {
+        (0..bits_to_rotate).try_fold(input, |word, _| {
+            self.config.rotate_31_config.generate_32_bit_rotation_from_cells(
+                region,
+                offset,
+                &word,
+                self.config.full_number_u64,
+                self.config.limbs,
+            )
+        })
+    }
+
+    /// This method is used when building the block words from the input bytes. It receives a list
+    /// of 64 [AssignedNative] bytes that still haven't been range-checked and returns a list of
+    /// 16 [AssignedRow] putted in the trace, range-checked and ready for use in the algorithm.
+    /// Each row holds 4 bytes of the block, and its most significant limbs are copies of the zero
+    /// constant.
+    pub(crate) fn block_words_from_bytes(
+        &self,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+        block: &[AssignedNative<F>],
+        zero_constant_cell: &AssignedNative<F>,
+    ) -> Result<[AssignedRow<F>; 16], Error> {
+        let mut current_block_rows_vector: Vec<AssignedRow<F>> = Vec::new();
+        for word_bytes in block.chunks(BLAKE2S_WORD_SIZE) {
+            let mut bytes = word_bytes.to_vec();
+            bytes.resize(8, zero_constant_cell.clone());
+            self.config.q_decompose.enable(region, *offset)?;
+            self.config.q_range.enable(region, *offset)?;
+            let row = generate_row_from_assigned_bytes(
+                region,
+                &bytes.try_into().unwrap(),
+                *offset,
+                self.config.full_number_u64,
+                self.config.limbs,
+            )?;
+            *offset += 1;
+            current_block_rows_vector.push(row);
+        }
+        Ok(current_block_rows_vector.try_into().unwrap())
+    }
+
+    /// Assigns an u32 constant to trace[row_offset][limbs[limb_index]] cell.
+    pub(crate) fn assign_limb_constant_u32(
+        &self,
+        region: &mut Region<'_, F>,
+        row_offset: &usize,
+        description: &str,
+        constant: u32,
+        limb_index: usize,
+    ) -> Result<AssignedBlake2bWord<F>, Error> {
+        AssignedBlake2bWord::assign_fixed_word(
+            region,
+            description,
+            self.config.limbs[limb_index],
+            *row_offset,
+            (constant as u64).into(),
+        )
+    }
+}
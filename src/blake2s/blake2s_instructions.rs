@@ -0,0 +1,72 @@
+use crate::blake2b::blake2b_instructions::ConstantCells;
+use crate::types::blake2b_word::AssignedBlake2bWord;
+use crate::types::byte::AssignedByte;
+use crate::types::AssignedNative;
+use ff::PrimeField;
+use midnight_proofs::circuit::{Layouter, Region};
+use midnight_proofs::plonk::Error;
+
+/// This is the trait that groups the Blake2s implementation chips. Every Blake2s chip
+/// should implement this trait. The 32-bit words of Blake2s are held in [AssignedBlake2bWord]
+/// cells whose 4 most significant limbs are zero.
+pub trait Blake2sInstructions<F: PrimeField>: Clone {
+    /// Populate all lookup tables needed for the chip
+    fn populate_lookup_tables(&self, layouter: &mut impl Layouter<F>) -> Result<(), Error>;
+
+    /// Assign initializations constants at the beginning. These constants are the initialization
+    /// vector (IV) constants, the zero constant and a constant computed from the key size and the
+    /// output size that is used for the initial state of the rounds.
+    fn assign_constant_advice_cells(
+        &self,
+        output_size: usize,
+        key_size: usize,
+        region: &mut Region<'_, F>,
+        advice_offset: &mut usize,
+    ) -> Result<ConstantCells<F>, Error>;
+
+    /// Here occurs the top loop of the hash function. It iterates for each block of the input and
+    /// key, compressing the block and updating the global state.
+    /// The return bytes of this function are the digest of the Blake2s computation.
+    #[allow(clippy::too_many_arguments)]
+    fn perform_blake2s_iterations(
+        &self,
+        region: &mut Region<'_, F>,
+        advice_offset: &mut usize,
+        input: &[AssignedNative<F>],
+        key: &[AssignedNative<F>],
+        iv_constants: &[AssignedBlake2bWord<F>; 8],
+        global_state: &mut [AssignedBlake2bWord<F>; 8],
+        zero_constant_cell: AssignedNative<F>,
+    ) -> Result<[AssignedByte<F>; 32], Error>;
+
+    /// This method computes a compression round of Blake2s. The global state is update through
+    /// consecutive calls of this method. If the algorithm is in its last round, the is_last_block
+    /// parameter should be set to true.
+    #[allow(clippy::too_many_arguments)]
+    fn compress(
+        &self,
+        region: &mut Region<'_, F>,
+        row_offset: &mut usize,
+        iv_constants: &[AssignedBlake2bWord<F>; 8],
+        global_state: &mut [AssignedBlake2bWord<F>; 8],
+        current_block: [AssignedBlake2bWord<F>; 16],
+        processed_bytes_count: u64,
+        is_last_block: bool,
+    ) -> Result<[AssignedByte<F>; 32], Error>;
+
+    /// This method computes a single round of mixing for the Blake2s algorithm.
+    /// One round of compress has 80 mixing rounds.
+    /// 'x' and 'y' are the variables that hold the AssignedCell with the input values that will
+    /// be processed in this mixing round.
+    /// The 'state_indexes' are the indexes of the compress state that will take part on this
+    /// mixing round. These are also needed to update the state at the end of the mixing.
+    fn mix(
+        &self,
+        state_indexes: [usize; 4],
+        x: AssignedBlake2bWord<F>,
+        y: AssignedBlake2bWord<F>,
+        state: &mut [AssignedBlake2bWord<F>; 16],
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+    ) -> Result<(), Error>;
+}
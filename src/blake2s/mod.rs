@@ -0,0 +1,20 @@
+//! A chip defining a Blake2s hash invocation, as defined in RFC 7693. This interface works with
+//! in/out consisting of AssignedNative. The algorithm expects its values to be in the range of a
+//! Byte, and will fail if they're not.
+//!
+//! The chip relies on a set of basic instructions, implemented as a trait called
+//! [Blake2sInstructions], over the same base operations as the Blake2b chip:
+//! * [Blake2sChip] This chip uses the lookup table of size `2**16` of the Blake2b chip, so all
+//!   circuits instantiating this chip will be at least `2**17` rows.
+
+/// This is the trait that contains most of the behaviour of the blake2s chips.
+pub(crate) mod blake2s_instructions;
+
+/// Basic definitions and constants for the blake2s chip.
+pub(crate) mod utils;
+
+/// The Blake2s chip.
+pub mod blake2s_chip;
+
+/// Number of advice columns required by the chip.
+pub const NB_BLAKE2S_ADVICE_COLS: usize = 9;
@@ -0,0 +1,103 @@
+use crate::types::AssignedNative;
+use ff::PrimeField;
+use midnight_proofs::circuit::Region;
+use midnight_proofs::plonk::Error;
+use crate::types::row::AssignedRow;
+
+/// Enforces the output and key sizes.
+/// Output size must be between 1 and 32 bytes.
+/// Key size must be between 0 and 32 bytes.
+pub(crate) fn enforce_input_sizes(output_size: usize, key_size: usize) {
+    assert!(output_size <= 32, "Output size must be between 1 and 32 bytes");
+    assert!(output_size > 0, "Output size must be between 1 and 32 bytes");
+    assert!(key_size <= 32, "Key size must be between 0 and 32 bytes");
+}
+
+/// The 'processed_bytes_count' is a variable in the algorithm that changes with every iteration,
+/// in each iteration we compute the new value for it.
+pub(crate) fn compute_processed_bytes_count_value_for_iteration(
+    iteration: usize,
+    is_last_block: bool,
+    input_size: usize,
+    empty_key: bool,
+) -> u64 {
+    let processed_bytes_count = if is_last_block {
+        input_size + if empty_key { 0 } else { BLAKE2S_BLOCK_SIZE }
+    } else {
+        BLAKE2S_BLOCK_SIZE * (iteration + 1)
+    };
+
+    processed_bytes_count as u64
+}
+
+/// This function will return 0 in most cases, except in 3 opportunities:
+/// 1 - In the "key block": the first block (if there's a key)
+/// 2 - In the last block, if the input length in bytes isn't a multiple of 64
+/// 3 - If the input and key are empty, the algorithm will compute a single block of 64 zeros
+pub(crate) fn zeros_to_pad_in_current_block<F: PrimeField>(
+    key: &[AssignedNative<F>],
+    input_size: usize,
+    is_last_block: bool,
+    is_key_block: bool,
+) -> usize {
+    if is_last_block && !is_key_block {
+        if input_size == 0 {
+            // Border case, the input and the key are empty
+            BLAKE2S_BLOCK_SIZE
+        } else {
+            // Last block, need to complete the block with zeroes
+            (BLAKE2S_BLOCK_SIZE - input_size % BLAKE2S_BLOCK_SIZE) % BLAKE2S_BLOCK_SIZE
+        }
+    } else if is_key_block {
+        // First block when there's a key, need to complete the block with zeroes
+        BLAKE2S_BLOCK_SIZE - key.len()
+    } else {
+        // Middle block, no need to pad anything
+        0
+    }
+}
+
+/// This method constrains the padding cells to equal zero. The words of Blake2s only take the 4
+/// least significant limbs of each row, so only those limbs hold bytes of the block.
+pub(crate) fn constrain_padding_cells_to_equal_zero<F: PrimeField>(
+    region: &mut Region<'_, F>,
+    zeros_amount: usize,
+    current_block_rows: &[AssignedRow<F>; 16],
+    zero_constant_cell: &AssignedNative<F>,
+) -> Result<(), Error> {
+    let mut constrained_padding_cells = 0;
+    for row in (0..16).rev() {
+        for limb in (0..BLAKE2S_WORD_SIZE).rev() {
+            if constrained_padding_cells < zeros_amount {
+                region.constrain_equal(
+                    current_block_rows[row].limbs[limb].cell(),
+                    zero_constant_cell.cell(),
+                )?;
+                constrained_padding_cells += 1;
+            }
+        }
+    }
+    Ok(())
+}
+
+// ----- Blake2s constants -----
+
+pub const BLAKE2S_BLOCK_SIZE: usize = 64;
+
+/// Amount of bytes of a Blake2s word.
+pub const BLAKE2S_WORD_SIZE: usize = 4;
+
+/// Amount of rounds of the compression function. The permutations of the message words are the
+/// first 10 of the ones of Blake2b.
+pub const BLAKE2S_ROUNDS: usize = 10;
+
+pub const IV_CONSTANTS: [u32; 8] = [
+    0x6A09E667u32,
+    0xBB67AE85u32,
+    0x3C6EF372u32,
+    0xA54FF53Au32,
+    0x510E527Fu32,
+    0x9B05688Cu32,
+    0x1F83D9ABu32,
+    0x5BE0CD19u32,
+];
@@ -0,0 +1,337 @@
+use crate::blake2b::utils::ABCD;
+use crate::blake2s::blake2s_chip::{Blake2sChip, Blake2sConfig};
+use crate::blake2s::blake2s_instructions::Blake2sInstructions;
+use crate::blake2s::utils::{BLAKE2S_WORD_SIZE, IV_CONSTANTS};
+use crate::blake3::utils::{
+    left_subtree_chunks, BLAKE3_BLOCK_SIZE, BLAKE3_CHUNK_SIZE, BLAKE3_ROUNDS, CHUNK_END,
+    CHUNK_START, MSG_PERMUTATION, PARENT, ROOT,
+};
+use crate::blake3::NB_BLAKE3_ADVICE_COLS;
+use crate::types::blake2b_word::AssignedBlake2bWord;
+use crate::types::byte::AssignedByte;
+use crate::types::row::AssignedRow;
+use crate::types::AssignedNative;
+use ff::PrimeField;
+use midnight_proofs::circuit::{Chip, Layouter, Region};
+use midnight_proofs::plonk::{Advice, Column, ConstraintSystem, Error, Fixed};
+
+/// Selectors and columns for the BLAKE3 chip implementation.
+#[derive(Clone, Debug)]
+pub struct Blake3Config {
+    /// Config of the Blake2s chip, which holds the base operations over 32-bit words.
+    blake2s_config: Blake2sConfig,
+    /// Columns for the limbs, shared with the Blake2s chip.
+    pub limbs: [Column<Advice>; 8],
+}
+
+/// This is the main chip for the BLAKE3 hash function. It is responsible for the entire hash
+/// computation.
+///
+/// The compression function of BLAKE3 is the one of Blake2s with 7 rounds, a fixed permutation of
+/// the message words between rounds and a state initialized with the chaining value, the IV, the
+/// counter, the length of the block and the flags. The mixing function is the one of the
+/// [Blake2sChip], over the same trace. The length of the input is known when the circuit is
+/// built, so the shape of the tree, the counters, the lengths and the flags are constants.
+///
+/// [Blake2sChip]: crate::blake2s::blake2s_chip::Blake2sChip
+#[derive(Clone, Debug)]
+pub struct Blake3Chip<F: PrimeField> {
+    config: Blake3Config,
+    blake2s_chip: Blake2sChip<F>,
+}
+
+impl<F: PrimeField> Chip<F> for Blake3Chip<F> {
+    type Config = Blake3Config;
+    type Loaded = ();
+
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+impl<F: PrimeField> Blake3Chip<F> {
+    /// Generation of a fresh circuit from a configuration.
+    pub fn new(config: &Blake3Config) -> Self {
+        Self {
+            config: config.clone(),
+            blake2s_chip: Blake2sChip::new(&config.blake2s_config),
+        }
+    }
+
+    /// Configuration of the circuit, which is the configuration of the Blake2s chip. It should be
+    /// called in the configuration of the user circuit before instantiating the chip.
+    ///
+    /// Note: as in the Blake2s chip, this function enables equality on all necessary columns,
+    /// i.e., it should not be done manually before calling this function.
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        constants: Column<Fixed>,
+        full_number_u64: Column<Advice>,
+        limbs: [Column<Advice>; NB_BLAKE3_ADVICE_COLS - 1],
+    ) -> <Self as Chip<F>>::Config {
+        let blake2s_config = Blake2sChip::configure(meta, constants, full_number_u64, limbs);
+        Blake3Config {
+            blake2s_config,
+            limbs,
+        }
+    }
+
+    /// Loading the tables used in the chip, which are the ones of the Blake2s chip.
+    pub fn load(&self, layouter: &mut impl Layouter<F>) -> Result<(), Error> {
+        self.blake2s_chip.load(layouter)
+    }
+
+    /// Main method of the chip. The 'input' cells should be filled with byte values. The input is
+    /// split in chunks of 1024 bytes, and the chaining values of the chunks are merged in parent
+    /// nodes up to the root, whose chaining value is the 32-byte digest.
+    pub fn hash(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        input: &[AssignedNative<F>],
+    ) -> Result<[AssignedByte<F>; 32], Error> {
+        // All the computation is performed inside a single region
+        layouter.assign_region(
+            || "single region",
+            |mut region| {
+                let mut offset: usize = 0;
+                let (iv_constants, zero_constant) =
+                    self.assign_constant_advice_cells(&mut region, &mut offset)?;
+
+                let chunks: Vec<&[AssignedNative<F>]> = if input.is_empty() {
+                    vec![input]
+                } else {
+                    input.chunks(BLAKE3_CHUNK_SIZE).collect()
+                };
+                let is_root = chunks.len() == 1;
+                let chunk_outputs = chunks
+                    .iter()
+                    .enumerate()
+                    .map(|(counter, chunk)| {
+                        self.compress_chunk(
+                            &mut region,
+                            &mut offset,
+                            &iv_constants,
+                            chunk,
+                            counter as u64,
+                            is_root,
+                            &zero_constant,
+                        )
+                    })
+                    .collect::<Result<Vec<_>, Error>>()?;
+
+                let root_output = if is_root {
+                    chunk_outputs.into_iter().next().unwrap()
+                } else {
+                    let chaining_values =
+                        chunk_outputs.iter().map(chaining_value_of).collect::<Vec<_>>();
+                    self.merge_subtree(
+                        &mut region,
+                        &mut offset,
+                        &iv_constants,
+                        &chaining_values,
+                        true,
+                    )?
+                };
+
+                let digest: Vec<AssignedByte<F>> = root_output
+                    .iter()
+                    .flat_map(|row| row.limbs[..BLAKE2S_WORD_SIZE].to_vec())
+                    .collect();
+                Ok(digest.try_into().unwrap())
+            },
+        )
+    }
+}
+
+impl<F: PrimeField> Blake3Chip<F> {
+    /// Here the constants that will be used throughout the algorithm are assigned in some storage
+    /// cells at the begining of the trace. These are the IV, which is also the key of the default
+    /// hash mode, and the zero constant used to pad the blocks.
+    fn assign_constant_advice_cells(
+        &self,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+    ) -> Result<([AssignedBlake2bWord<F>; 8], AssignedNative<F>), Error> {
+        let iv_constants = IV_CONSTANTS
+            .iter()
+            .enumerate()
+            .map(|(index, constant)| {
+                self.blake2s_chip.assign_limb_constant_u32(
+                    region,
+                    offset,
+                    "iv constants",
+                    *constant,
+                    index,
+                )
+            })
+            .collect::<Result<Vec<_>, Error>>()?
+            .try_into()
+            .unwrap();
+        *offset += 1;
+
+        let zero_constant = region.assign_advice_from_constant(
+            || "zero",
+            self.config.limbs[0],
+            *offset,
+            F::from(0),
+        )?;
+        *offset += 1;
+
+        Ok((iv_constants, zero_constant))
+    }
+
+    /// Compresses the blocks of a chunk, chaining them from the IV, and returns the output of the
+    /// compression of its last block. The empty input is a single chunk of an empty block.
+    #[allow(clippy::too_many_arguments)]
+    fn compress_chunk(
+        &self,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+        iv_constants: &[AssignedBlake2bWord<F>; 8],
+        chunk: &[AssignedNative<F>],
+        counter: u64,
+        is_root: bool,
+        zero_constant: &AssignedNative<F>,
+    ) -> Result<[AssignedRow<F>; 8], Error> {
+        let blocks: Vec<&[AssignedNative<F>]> =
+            if chunk.is_empty() { vec![chunk] } else { chunk.chunks(BLAKE3_BLOCK_SIZE).collect() };
+        let total_blocks = blocks.len();
+
+        let mut chaining_value = iv_constants.clone();
+        let mut output = None;
+        for (i, block) in blocks.into_iter().enumerate() {
+            let is_last_block = i == total_blocks - 1;
+            let mut flags = if i == 0 { CHUNK_START } else { 0 };
+            if is_last_block {
+                flags |= CHUNK_END;
+                if is_root {
+                    flags |= ROOT;
+                }
+            }
+
+            let mut block_bytes = block.to_vec();
+            block_bytes.resize(BLAKE3_BLOCK_SIZE, zero_constant.clone());
+            let block_words = self
+                .blake2s_chip
+                .block_words_from_bytes(region, offset, &block_bytes, zero_constant)?
+                .map(|row| row.full_number);
+
+            let block_output = self.compress(
+                region,
+                offset,
+                iv_constants,
+                &chaining_value,
+                block_words,
+                counter,
+                block.len() as u32,
+                flags,
+            )?;
+            chaining_value = chaining_value_of(&block_output);
+            output = Some(block_output);
+        }
+        // Note: a chunk has at least one block. Therefore, this `unwrap` must succeed.
+        Ok(output.unwrap())
+    }
+
+    /// Merges the chaining values of a subtree of chunks into the output of its parent node. The
+    /// left subtree takes the largest power of 2 of chunks smaller than the amount of chunks.
+    fn merge_subtree(
+        &self,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+        iv_constants: &[AssignedBlake2bWord<F>; 8],
+        chaining_values: &[[AssignedBlake2bWord<F>; 8]],
+        is_root: bool,
+    ) -> Result<[AssignedRow<F>; 8], Error> {
+        let (left, right) = chaining_values.split_at(left_subtree_chunks(chaining_values.len()));
+        let mut block_words = Vec::with_capacity(16);
+        for subtree in [left, right] {
+            let subtree_chaining_value = if subtree.len() == 1 {
+                subtree[0].clone()
+            } else {
+                let subtree_output =
+                    self.merge_subtree(region, offset, iv_constants, subtree, false)?;
+                chaining_value_of(&subtree_output)
+            };
+            block_words.extend(subtree_chaining_value);
+        }
+
+        let flags = if is_root { PARENT | ROOT } else { PARENT };
+        self.compress(
+            region,
+            offset,
+            iv_constants,
+            iv_constants,
+            block_words.try_into().unwrap(),
+            0,
+            BLAKE3_BLOCK_SIZE as u32,
+            flags,
+        )
+    }
+
+    /// This method computes the compression function of BLAKE3 over a block. It returns the rows
+    /// of the 8 words of the output, the xor of the two halves of the final state, whose limbs
+    /// hold the bytes of the digest when the node is the root.
+    #[allow(clippy::too_many_arguments)]
+    fn compress(
+        &self,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+        iv_constants: &[AssignedBlake2bWord<F>; 8],
+        chaining_value: &[AssignedBlake2bWord<F>; 8],
+        block: [AssignedBlake2bWord<F>; 16],
+        counter: u64,
+        block_length: u32,
+        flags: u32,
+    ) -> Result<[AssignedRow<F>; 8], Error> {
+        let mut state_vector: Vec<AssignedBlake2bWord<F>> = Vec::with_capacity(16);
+        state_vector.extend_from_slice(chaining_value);
+        state_vector.extend_from_slice(&iv_constants[..4]);
+
+        // The counter, the length of the block and the flags are constants, which are placed in
+        // a single row.
+        let state_constants = [counter as u32, (counter >> 32) as u32, block_length, flags];
+        for (index, constant) in state_constants.iter().enumerate() {
+            state_vector.push(self.blake2s_chip.assign_limb_constant_u32(
+                region,
+                offset,
+                "state constant",
+                *constant,
+                index,
+            )?);
+        }
+        *offset += 1;
+        let mut state: [AssignedBlake2bWord<F>; 16] = state_vector.try_into().unwrap();
+
+        // Main loop, with the message words permuted after every round
+        let mut schedule: [usize; 16] = std::array::from_fn(|i| i);
+        for _ in 0..BLAKE3_ROUNDS {
+            for (j, state_indexes) in ABCD.iter().enumerate() {
+                self.blake2s_chip.mix(
+                    *state_indexes,
+                    block[schedule[2 * j]].clone(),
+                    block[schedule[2 * j + 1]].clone(),
+                    &mut state,
+                    region,
+                    offset,
+                )?;
+            }
+            schedule = MSG_PERMUTATION.map(|index| schedule[index]);
+        }
+
+        let mut output = Vec::with_capacity(8);
+        for i in 0..8 {
+            output.push(self.blake2s_chip.xor(&state[i], &state[i + 8], region, offset)?);
+        }
+        Ok(output.try_into().unwrap())
+    }
+}
+
+/// The chaining value of a compression output is its 8 words.
+fn chaining_value_of<F: PrimeField>(output: &[AssignedRow<F>; 8]) -> [AssignedBlake2bWord<F>; 8] {
+    std::array::from_fn(|i| output[i].full_number.clone())
+}
@@ -0,0 +1,21 @@
+//! A chip defining a BLAKE3 hash invocation, in its default hash mode with a 32-byte output. This
+//! interface works with inputs consisting of AssignedNative. The algorithm expects its values to
+//! be in the range of a Byte, and will fail if they're not.
+//!
+//! BLAKE3 compresses 64-byte blocks of 32-bit words with the mixing function of Blake2s, so the
+//! chip is built over the base operations of the [Blake2sChip]:
+//! * [Blake3Chip] This chip uses the lookup table of size `2**16` of the Blake2s chip, so all
+//!   circuits instantiating this chip will be at least `2**17` rows. The input is split in chunks
+//!   of 1024 bytes, whose chaining values are merged in a binary tree.
+//!
+//! [Blake2sChip]: crate::blake2s::blake2s_chip::Blake2sChip
+//! [Blake3Chip]: crate::blake3::blake3_chip::Blake3Chip
+
+/// Basic definitions and constants for the blake3 chip.
+pub(crate) mod utils;
+
+/// The BLAKE3 chip.
+pub mod blake3_chip;
+
+/// Number of advice columns required by the chip.
+pub const NB_BLAKE3_ADVICE_COLS: usize = 9;
@@ -0,0 +1,27 @@
+// ----- BLAKE3 constants -----
+
+pub const BLAKE3_BLOCK_SIZE: usize = 64;
+
+/// Amount of bytes of a chunk, the leaves of the tree of BLAKE3. Every chunk is compressed block
+/// by block, like a whole Blake2s input.
+pub const BLAKE3_CHUNK_SIZE: usize = 1024;
+
+/// Amount of rounds of the compression function.
+pub const BLAKE3_ROUNDS: usize = 7;
+
+/// Permutation of the message words applied after every round. Round 'r' uses the words of the
+/// block permuted 'r' times.
+pub const MSG_PERMUTATION: [usize; 16] = [2, 6, 3, 10, 7, 0, 4, 13, 1, 11, 12, 5, 9, 14, 15, 8];
+
+/// Domain separation flags of the last word of the compression state.
+pub const CHUNK_START: u32 = 1 << 0;
+pub const CHUNK_END: u32 = 1 << 1;
+pub const PARENT: u32 = 1 << 2;
+pub const ROOT: u32 = 1 << 3;
+
+/// Amount of chunks of the left subtree of a node with the given amount of chunks, which is the
+/// largest power of 2 smaller than it. The left subtree is always complete.
+pub(crate) fn left_subtree_chunks(chunks: usize) -> usize {
+    assert!(chunks > 1, "A parent node has at least 2 chunks");
+    1 << (usize::BITS - 1 - (chunks - 1).leading_zeros())
+}
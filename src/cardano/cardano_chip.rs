@@ -0,0 +1,371 @@
+use crate::blake2b::blake2b_chip::{Blake2bChip, Blake2bConfig};
+use crate::blake2b::NB_BLAKE2B_ADVICE_COLS;
+use crate::cardano::cbor::{CborField, CborHeader, CBOR_BYTE_STRING, CBOR_UNSIGNED_INTEGER};
+use crate::cardano::credential::{
+    Credential, ScriptLanguage, CREDENTIAL_HASH_SIZE, VERIFICATION_KEY_SIZE,
+};
+use crate::types::blake2b_word::{AssignedBlake2bWord, Blake2bWord};
+use crate::types::byte::AssignedByte;
+use crate::types::AssignedNative;
+use ff::PrimeField;
+use midnight_proofs::circuit::{Chip, Layouter, Region};
+use midnight_proofs::plonk::{Advice, Column, ConstraintSystem, Error, Fixed};
+use std::{array, iter};
+
+/// Size in bytes of a transaction id, the Blake2b-256 digest of the transaction body.
+pub const TRANSACTION_ID_SIZE: usize = 32;
+
+/// Type of the base addresses, whose bits 0 and 1 are set when the payment and the stake
+/// credentials are scripts.
+const BASE_ADDRESS_TYPE: u8 = 0b0000;
+
+/// Type of the enterprise addresses, whose bit 0 is set when the payment credential is a script.
+const ENTERPRISE_ADDRESS_TYPE: u8 = 0b0110;
+
+/// Type of the reward addresses, whose bit 0 is set when the stake credential is a script.
+const REWARD_ADDRESS_TYPE: u8 = 0b1110;
+
+/// Selectors and columns for the Cardano chip implementation.
+#[derive(Clone, Debug)]
+pub struct CardanoConfig {
+    /// Config of the Blake2b chip, which computes all the hashes.
+    blake2b_config: Blake2bConfig,
+    /// Columns for the limbs, shared with the Blake2b chip.
+    pub limbs: [Column<Advice>; 8],
+}
+
+/// This is the chip for the hashes that identify Cardano credentials, which are Blake2b-224
+/// digests of Ed25519 verification keys or of scripts, and for the Shelley addresses built from
+/// them. An address is a header byte, with the type of the address in its 4 most significant
+/// bits and the network id in the 4 least significant ones, followed by the hashes of its
+/// credentials, as defined in CIP-19. These are the bytes encoded in bech32 by wallets.
+///
+/// Payment and stake key hashes are computed in the same way, so a credential is used as either
+/// one depending on its position in the address.
+///
+/// The chip also computes transaction ids, which are the Blake2b-256 digests of the CBOR-encoded
+/// transaction bodies, and reads fields of the hashed body, such as the address or the amount of
+/// an output, at the positions given by the locators of the [cbor] module.
+///
+/// [cbor]: crate::cardano::cbor
+#[derive(Clone, Debug)]
+pub struct CardanoChip<F: PrimeField> {
+    config: CardanoConfig,
+    blake2b_chip: Blake2bChip<F>,
+}
+
+impl<F: PrimeField> Chip<F> for CardanoChip<F> {
+    type Config = CardanoConfig;
+    type Loaded = ();
+
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+impl<F: PrimeField> CardanoChip<F> {
+    /// Generation of a fresh circuit from a configuration.
+    pub fn new(config: &CardanoConfig) -> Self {
+        Self {
+            config: config.clone(),
+            blake2b_chip: Blake2bChip::new(&config.blake2b_config),
+        }
+    }
+
+    /// Configuration of the circuit, which is the configuration of the Blake2b chip. It should be
+    /// called in the configuration of the user circuit before instantiating the Cardano gadgets.
+    ///
+    /// Note: as in the Blake2b chip, this function enables equality on all necessary columns,
+    /// i.e., it should not be done manually before calling this function.
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        constants: Column<Fixed>,
+        full_number_u64: Column<Advice>,
+        limbs: [Column<Advice>; NB_BLAKE2B_ADVICE_COLS - 1],
+    ) -> <Self as Chip<F>>::Config {
+        let blake2b_config = Blake2bChip::configure(meta, constants, full_number_u64, limbs);
+        CardanoConfig {
+            blake2b_config,
+            limbs,
+        }
+    }
+
+    /// Loading the tables used in the chip, which are the ones of the Blake2b chip.
+    pub fn load(&self, layouter: &mut impl Layouter<F>) -> Result<(), Error> {
+        self.blake2b_chip.load(layouter)
+    }
+
+    /// Computes the hash of an Ed25519 verification key, which is the payment or stake key hash
+    /// of the key. The 32 'verification_key' cells should be filled with byte values.
+    pub fn key_hash(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        verification_key: &[AssignedNative<F>],
+    ) -> Result<[AssignedByte<F>; CREDENTIAL_HASH_SIZE], Error> {
+        assert_eq!(
+            verification_key.len(),
+            VERIFICATION_KEY_SIZE,
+            "A verification key must have 32 bytes"
+        );
+        self.blake2b_chip.hash_to_array::<CREDENTIAL_HASH_SIZE>(layouter, verification_key)
+    }
+
+    /// Computes the hash of a script of the given language, which is the Blake2b-224 digest of
+    /// the tag of the language followed by the serialization of the script. The 'script' cells
+    /// should be filled with byte values, and their amount must be known at circuit building
+    /// time.
+    pub fn script_hash(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        language: ScriptLanguage,
+        script: &[AssignedNative<F>],
+    ) -> Result<[AssignedByte<F>; CREDENTIAL_HASH_SIZE], Error> {
+        let mut input = self.blake2b_chip.assign_constant_bytes(layouter, &[language.tag()])?;
+        input.extend_from_slice(script);
+        self.blake2b_chip.hash_to_array::<CREDENTIAL_HASH_SIZE>(layouter, &input)
+    }
+
+    /// Returns the 57 bytes of the base address with the given payment and stake credentials.
+    pub fn base_address(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        network_id: u8,
+        payment: &Credential<F>,
+        stake: &Credential<F>,
+    ) -> Result<Vec<AssignedNative<F>>, Error> {
+        let address_type =
+            BASE_ADDRESS_TYPE | payment.is_script() as u8 | ((stake.is_script() as u8) << 1);
+        self.address(layouter, address_type, network_id, &[payment, stake])
+    }
+
+    /// Returns the 29 bytes of the enterprise address with the given payment credential, which
+    /// carries no stake rights.
+    pub fn enterprise_address(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        network_id: u8,
+        payment: &Credential<F>,
+    ) -> Result<Vec<AssignedNative<F>>, Error> {
+        let address_type = ENTERPRISE_ADDRESS_TYPE | payment.is_script() as u8;
+        self.address(layouter, address_type, network_id, &[payment])
+    }
+
+    /// Returns the 29 bytes of the reward address, also known as stake address, with the given
+    /// stake credential.
+    pub fn reward_address(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        network_id: u8,
+        stake: &Credential<F>,
+    ) -> Result<Vec<AssignedNative<F>>, Error> {
+        let address_type = REWARD_ADDRESS_TYPE | stake.is_script() as u8;
+        self.address(layouter, address_type, network_id, &[stake])
+    }
+
+    /// Computes the id of a transaction, which is the Blake2b-256 digest of its CBOR-encoded body.
+    /// The 'body' cells hold the body followed by zeros up to its maximum length, which is
+    /// 'body.len()', and the 'body_length' cell holds the length of the body, as in
+    /// [Blake2bChip::hash_with_variable_input_length].
+    pub fn transaction_id(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        body: &[AssignedNative<F>],
+        body_length: &AssignedNative<F>,
+    ) -> Result<[AssignedByte<F>; TRANSACTION_ID_SIZE], Error> {
+        let digest = self.blake2b_chip.hash_with_variable_input_length(
+            layouter,
+            body,
+            body_length,
+            &[],
+            TRANSACTION_ID_SIZE,
+        )?;
+        Ok(digest[..TRANSACTION_ID_SIZE].to_vec().try_into().unwrap())
+    }
+
+    /// Reads the byte string at the given field of a transaction body, and returns the cells of
+    /// its content, such as the bytes of an output address. The header of the byte string is
+    /// constrained to the one of 'field', so the content has the length of the field, the headers
+    /// traversed to reach it are constrained to the ones of 'field', and the field is constrained
+    /// to end before 'body_length'. The 'body' and 'body_length' cells are
+    /// the ones given to [CardanoChip::transaction_id].
+    pub fn cbor_bytes(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        body: &[AssignedNative<F>],
+        body_length: &AssignedNative<F>,
+        field: &CborField,
+    ) -> Result<Vec<AssignedNative<F>>, Error> {
+        assert_eq!(field.major_type(), CBOR_BYTE_STRING, "The field must be a byte string");
+        let content_offset = field.content_offset();
+        let content_length = field.offset + field.length - content_offset;
+        let mut header = vec![field.initial_byte];
+        header.extend_from_slice(
+            &(content_length as u64).to_be_bytes()[8 - field.argument_length()..],
+        );
+        self.constrain_field(layouter, body, body_length, field, &header)?;
+        Ok(body[content_offset..field.offset + field.length].to_vec())
+    }
+
+    /// Reads the unsigned integer at the given field of a transaction body, such as the amount of
+    /// an output or the fee, and returns a cell with its value. The initial byte of the integer is
+    /// constrained to the one of 'field', so the integer is encoded with the same amount of bytes,
+    /// the headers traversed to reach it are constrained to the ones of 'field', and the field is
+    /// constrained to end before 'body_length'. Integers smaller than 24, which
+    /// are held in the initial byte, are not supported.
+    pub fn cbor_uint(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        body: &[AssignedNative<F>],
+        body_length: &AssignedNative<F>,
+        field: &CborField,
+    ) -> Result<AssignedNative<F>, Error> {
+        assert_eq!(
+            field.major_type(),
+            CBOR_UNSIGNED_INTEGER,
+            "The field must be an unsigned integer"
+        );
+        let argument_length = field.argument_length();
+        assert!(argument_length > 0, "Integers held in the initial byte are not supported");
+        self.constrain_field(layouter, body, body_length, field, &[field.initial_byte])?;
+
+        // The argument is big-endian, and the limbs of a row are little-endian
+        let argument = &body[field.offset + 1..field.content_offset()];
+        layouter.assign_region(
+            || "cbor unsigned integer",
+            |mut region| {
+                let mut offset = 0;
+                let zero = region.assign_advice_from_constant(
+                    || "zero",
+                    self.config.limbs[0],
+                    offset,
+                    F::ZERO,
+                )?;
+                offset += 1;
+                let bytes = array::from_fn(|i| {
+                    if i < argument_length {
+                        argument[argument_length - 1 - i].clone()
+                    } else {
+                        zero.clone()
+                    }
+                });
+                let row = self.blake2b_chip.new_row_from_assigned_bytes(
+                    &bytes,
+                    &mut region,
+                    &mut offset,
+                )?;
+                Ok(row.full_number.to_native())
+            },
+        )
+    }
+}
+
+impl<F: PrimeField> CardanoChip<F> {
+    /// Returns the header byte of an address, which is a constant of the circuit, followed by the
+    /// hashes of its credentials.
+    fn address(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        address_type: u8,
+        network_id: u8,
+        credentials: &[&Credential<F>],
+    ) -> Result<Vec<AssignedNative<F>>, Error> {
+        assert!(network_id < 16, "The network id must fit in 4 bits");
+        let mut address = self
+            .blake2b_chip
+            .assign_constant_bytes(layouter, &[(address_type << 4) | network_id])?;
+        for credential in credentials {
+            address.extend_from_slice(credential.hash());
+        }
+        Ok(address)
+    }
+
+    /// Constrains the first bytes of the field to the given header, the bytes of the headers
+    /// traversed to reach the field to the ones recorded in it, and the field to end before the
+    /// length of the body, which holds the sum of the end of the field and a witnessed distance
+    /// of 32 bits. Otherwise, a field could be read from the zero padding of the body.
+    fn constrain_field(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        body: &[AssignedNative<F>],
+        body_length: &AssignedNative<F>,
+        field: &CborField,
+        header: &[u8],
+    ) -> Result<(), Error> {
+        let end = field.offset + field.length;
+        assert!(end <= body.len(), "The field must be inside the body");
+        let field_header = CborHeader {
+            offset: field.offset,
+            bytes: header.to_vec(),
+        };
+        let pinned_bytes: Vec<(usize, u8)> = field
+            .headers
+            .iter()
+            .chain(iter::once(&field_header))
+            .flat_map(|header| {
+                header.bytes.iter().enumerate().map(|(index, byte)| (header.offset + index, *byte))
+            })
+            .collect();
+        layouter.assign_region(
+            || "cbor field",
+            |mut region| {
+                let mut offset = 0;
+                let zero = region.assign_advice_from_constant(
+                    || "zero",
+                    self.config.limbs[0],
+                    offset,
+                    F::ZERO,
+                )?;
+                for (index, (position, byte)) in pinned_bytes.iter().enumerate() {
+                    let header_byte = region.assign_advice_from_constant(
+                        || "cbor header byte",
+                        self.config.limbs[(index + 1) % 8],
+                        offset + (index + 1) / 8,
+                        F::from(*byte as u64),
+                    )?;
+                    region.constrain_equal(header_byte.cell(), body[*position].cell())?;
+                }
+                offset += (pinned_bytes.len() + 1).div_ceil(8);
+                self.constrain_end_of_field(&mut region, &mut offset, body_length, end, &zero)
+            },
+        )
+    }
+
+    fn constrain_end_of_field(
+        &self,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+        body_length: &AssignedNative<F>,
+        end: usize,
+        zero: &AssignedNative<F>,
+    ) -> Result<(), Error> {
+        let distance_value = body_length.value().map(|length| {
+            Blake2bWord(Blake2bWord::new_from_field(*length).0.wrapping_sub(end as u64))
+        });
+        let distance = AssignedBlake2bWord::assign_advice_word(
+            region,
+            "distance to the end of the body",
+            self.config.limbs[0],
+            *offset,
+            distance_value,
+        )?;
+        *offset += 1;
+        let distance_row = self.blake2b_chip.new_row_from_word(&distance, region, offset)?;
+        for limb in &distance_row.limbs[4..] {
+            region.constrain_equal(limb.cell(), zero.cell())?;
+        }
+        let end = AssignedBlake2bWord::assign_fixed_word(
+            region,
+            "end of the field",
+            self.config.limbs[0],
+            *offset,
+            Blake2bWord(end as u64),
+        )?;
+        *offset += 1;
+        let sum = self.blake2b_chip.add(&end, &distance_row.full_number, region, offset)?;
+        region.constrain_equal(sum.full_number.cell(), body_length.cell())
+    }
+}
@@ -0,0 +1,248 @@
+/// Major type of the CBOR unsigned integers.
+pub(crate) const CBOR_UNSIGNED_INTEGER: u8 = 0;
+
+/// Major type of the CBOR byte strings.
+pub(crate) const CBOR_BYTE_STRING: u8 = 2;
+
+const CBOR_ARRAY: u8 = 4;
+const CBOR_MAP: u8 = 5;
+const CBOR_TAG: u8 = 6;
+
+/// Keys of the map of a transaction body.
+const OUTPUTS_KEY: u64 = 1;
+const FEE_KEY: u64 = 2;
+
+/// Keys of the map of a post-Alonzo transaction output.
+const OUTPUT_ADDRESS_KEY: u64 = 0;
+const OUTPUT_VALUE_KEY: u64 = 1;
+
+/// Position of an encoded CBOR item inside a byte string, such as a transaction body. The
+/// gadgets of the [CardanoChip] that read a field are built for its position, for its initial
+/// byte, which holds the major type of the item and the size of its argument, and for the headers
+/// of the items traversed to reach it, so a circuit works for every transaction that has the
+/// field in the same place of the same structure and with the same size.
+///
+/// [CardanoChip]: crate::cardano::cardano_chip::CardanoChip
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CborField {
+    /// Position of the initial byte of the item.
+    pub offset: usize,
+    /// Initial byte of the item.
+    pub initial_byte: u8,
+    /// Byte length of the whole item, including its header.
+    pub length: usize,
+    /// Headers of the items before the field that were read to locate it, such as the header of
+    /// the map of the body, its keys, and the headers of the items that were skipped. They fix
+    /// the structure around the field, otherwise the same bytes could be read from a byte string
+    /// that merely contains them, like a datum or the metadata.
+    pub headers: Vec<CborHeader>,
+}
+
+/// Bytes of the header of a CBOR item that determine the structure of the encoding: the initial
+/// byte, and the argument of the strings, arrays, maps and tags, which is their length, their
+/// amount of elements or their tag. The arguments of the other items are their values, which are
+/// left out, except for the keys of maps.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CborHeader {
+    /// Position of the initial byte of the item.
+    pub offset: usize,
+    /// Bytes of the header from its initial byte.
+    pub bytes: Vec<u8>,
+}
+
+impl CborField {
+    /// Major type of the item, the 3 most significant bits of its initial byte.
+    pub fn major_type(&self) -> u8 {
+        self.initial_byte >> 5
+    }
+
+    /// Byte length of the argument that follows the initial byte. Arguments smaller than 24 are
+    /// held in the initial byte itself.
+    pub fn argument_length(&self) -> usize {
+        argument_length(self.initial_byte)
+    }
+
+    /// Position of the first byte after the header of the item.
+    pub fn content_offset(&self) -> usize {
+        self.offset + 1 + self.argument_length()
+    }
+}
+
+/// Locates the address of the output at the given index of a transaction body. The address is a
+/// byte string, with the bytes of a Shelley address as its content.
+pub fn locate_output_address(body: &[u8], output_index: usize) -> CborField {
+    let mut headers = vec![];
+    let (output, is_map) = locate_output(body, output_index, &mut headers);
+    let address_offset = if is_map {
+        locate_map_value(body, output, OUTPUT_ADDRESS_KEY, &mut headers)
+    } else {
+        locate_array_element(body, output, 0, &mut headers)
+    };
+    field_at(body, address_offset, headers)
+}
+
+/// Locates the amount of lovelace of the output at the given index of a transaction body. The
+/// amount is an unsigned integer, which is the whole value of the output, or the first element
+/// of the value when the output holds other assets.
+pub fn locate_output_coin(body: &[u8], output_index: usize) -> CborField {
+    let mut headers = vec![];
+    let (output, is_map) = locate_output(body, output_index, &mut headers);
+    let value_offset = if is_map {
+        locate_map_value(body, output, OUTPUT_VALUE_KEY, &mut headers)
+    } else {
+        locate_array_element(body, output, 1, &mut headers)
+    };
+    let (major_type, _, _) = read_header(body, value_offset);
+    if major_type == CBOR_ARRAY {
+        let coin_offset = locate_array_element(body, value_offset, 0, &mut headers);
+        field_at(body, coin_offset, headers)
+    } else {
+        field_at(body, value_offset, headers)
+    }
+}
+
+/// Locates the fee of a transaction body, an unsigned integer with the amount of lovelace.
+pub fn locate_fee(body: &[u8]) -> CborField {
+    let mut headers = vec![];
+    let fee_offset = locate_map_value(body, 0, FEE_KEY, &mut headers);
+    field_at(body, fee_offset, headers)
+}
+
+/// Returns the position of the output at the given index and whether it's a post-Alonzo output,
+/// encoded as a map, or a legacy one, encoded as an array.
+fn locate_output(body: &[u8], output_index: usize, headers: &mut Vec<CborHeader>) -> (usize, bool) {
+    let outputs = locate_map_value(body, 0, OUTPUTS_KEY, headers);
+    let output = locate_array_element(body, outputs, output_index, headers);
+    let (major_type, _, _) = read_header(body, output);
+    assert!(
+        major_type == CBOR_MAP || major_type == CBOR_ARRAY,
+        "A transaction output must be a map or an array"
+    );
+    (output, major_type == CBOR_MAP)
+}
+
+/// Returns the position of the value of the given unsigned integer key of the map at 'offset'.
+/// The headers of the map, of its keys and of the entries before the key are added to 'headers'.
+fn locate_map_value(bytes: &[u8], offset: usize, key: u64, headers: &mut Vec<CborHeader>) -> usize {
+    let (major_type, entries, header_length) = traverse_header(bytes, offset, false, headers);
+    assert_eq!(major_type, CBOR_MAP, "Expected a CBOR map");
+    let mut position = offset + header_length;
+    for _ in 0..entries {
+        let (key_type, key_argument, key_header_length) =
+            traverse_header(bytes, position, true, headers);
+        let value =
+            skip_content(bytes, key_type, key_argument, position + key_header_length, headers);
+        if key_type == CBOR_UNSIGNED_INTEGER && key_argument == key {
+            return value;
+        }
+        position = skip_item(bytes, value, headers);
+    }
+    panic!("The key {key} is not in the map");
+}
+
+/// Returns the position of the element at the given index of the array at 'offset', which can be
+/// tagged, like the sets of the Conway era. The headers of the tags, of the array and of the
+/// elements before the index are added to 'headers'.
+fn locate_array_element(
+    bytes: &[u8],
+    offset: usize,
+    index: usize,
+    headers: &mut Vec<CborHeader>,
+) -> usize {
+    let (mut major_type, mut elements, mut header_length) =
+        traverse_header(bytes, offset, false, headers);
+    let mut offset = offset;
+    while major_type == CBOR_TAG {
+        offset += header_length;
+        (major_type, elements, header_length) = traverse_header(bytes, offset, false, headers);
+    }
+    assert_eq!(major_type, CBOR_ARRAY, "Expected a CBOR array");
+    assert!((index as u64) < elements, "The array has {elements} elements");
+    (0..index).fold(offset + header_length, |position, _| skip_item(bytes, position, headers))
+}
+
+/// Returns the field of the item at 'offset', reached through the given headers.
+fn field_at(bytes: &[u8], offset: usize, headers: Vec<CborHeader>) -> CborField {
+    CborField {
+        offset,
+        initial_byte: bytes[offset],
+        length: skip_item(bytes, offset, &mut vec![]) - offset,
+        headers,
+    }
+}
+
+/// Returns the position of the first byte after the item at 'offset', and adds the headers of
+/// the item and of the items it contains to 'headers'.
+fn skip_item(bytes: &[u8], offset: usize, headers: &mut Vec<CborHeader>) -> usize {
+    let (major_type, argument, header_length) = traverse_header(bytes, offset, false, headers);
+    skip_content(bytes, major_type, argument, offset + header_length, headers)
+}
+
+/// Returns the position of the first byte after the content, at 'content', of an item with the
+/// given major type and argument, and adds the headers of the items it contains to 'headers'.
+fn skip_content(
+    bytes: &[u8],
+    major_type: u8,
+    argument: u64,
+    content: usize,
+    headers: &mut Vec<CborHeader>,
+) -> usize {
+    match major_type {
+        0 | 1 | 7 => content,
+        2 | 3 => content + argument as usize,
+        4 => (0..argument).fold(content, |position, _| skip_item(bytes, position, headers)),
+        5 => (0..2 * argument).fold(content, |position, _| skip_item(bytes, position, headers)),
+        _ => skip_item(bytes, content, headers),
+    }
+}
+
+/// Reads the header of the item at 'offset' as [read_header] does, and adds the bytes of the
+/// header that determine the structure of the encoding to 'headers', which is the whole header
+/// if 'whole_header' is set.
+fn traverse_header(
+    bytes: &[u8],
+    offset: usize,
+    whole_header: bool,
+    headers: &mut Vec<CborHeader>,
+) -> (u8, u64, usize) {
+    let (major_type, argument, header_length) = read_header(bytes, offset);
+    let structural_length = if whole_header || (CBOR_BYTE_STRING..=CBOR_TAG).contains(&major_type) {
+        header_length
+    } else {
+        1
+    };
+    headers.push(CborHeader {
+        offset,
+        bytes: bytes[offset..offset + structural_length].to_vec(),
+    });
+    (major_type, argument, header_length)
+}
+
+/// Reads the header of the item at 'offset', and returns its major type, its argument and the
+/// byte length of the header. Items of indefinite length are not supported, since they're not
+/// used in transaction bodies.
+fn read_header(bytes: &[u8], offset: usize) -> (u8, u64, usize) {
+    let initial_byte = bytes[offset];
+    let additional_information = initial_byte & 0x1f;
+    let argument_length = argument_length(initial_byte);
+    let argument = if argument_length == 0 {
+        additional_information as u64
+    } else {
+        bytes[offset + 1..offset + 1 + argument_length]
+            .iter()
+            .fold(0, |argument, byte| (argument << 8) | *byte as u64)
+    };
+    (initial_byte >> 5, argument, 1 + argument_length)
+}
+
+/// Byte length of the argument that follows the given initial byte.
+fn argument_length(initial_byte: u8) -> usize {
+    match initial_byte & 0x1f {
+        0..=23 => 0,
+        24 => 1,
+        25 => 2,
+        26 => 4,
+        27 => 8,
+        _ => panic!("Items of indefinite length are not supported"),
+    }
+}
@@ -0,0 +1,69 @@
+use crate::types::AssignedNative;
+use ff::PrimeField;
+
+/// Byte length of the hashes of verification keys and scripts.
+pub const CREDENTIAL_HASH_SIZE: usize = 28;
+
+/// Byte length of an Ed25519 verification key.
+pub const VERIFICATION_KEY_SIZE: usize = 32;
+
+/// Network id of the addresses of the Cardano mainnet.
+pub const MAINNET_NETWORK_ID: u8 = 1;
+
+/// Network id of the addresses of the Cardano testnets.
+pub const TESTNET_NETWORK_ID: u8 = 0;
+
+/// Language of a script. The hash of a script is the Blake2b-224 digest of its serialization
+/// prefixed with the tag of its language.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ScriptLanguage {
+    /// Native scripts, also known as multisig or timelock scripts.
+    Native,
+    /// Plutus V1 scripts.
+    PlutusV1,
+    /// Plutus V2 scripts.
+    PlutusV2,
+    /// Plutus V3 scripts.
+    PlutusV3,
+}
+
+impl ScriptLanguage {
+    /// Returns the byte that prefixes the serialization of a script of this language when it's
+    /// hashed.
+    pub fn tag(&self) -> u8 {
+        match self {
+            ScriptLanguage::Native => 0,
+            ScriptLanguage::PlutusV1 => 1,
+            ScriptLanguage::PlutusV2 => 2,
+            ScriptLanguage::PlutusV3 => 3,
+        }
+    }
+}
+
+/// Payment or stake credential of a Shelley address, which is the 28-byte hash of either a
+/// verification key or a script. The cells should be filled with byte values, like the digests
+/// of the [CardanoChip].
+///
+/// [CardanoChip]: crate::cardano::cardano_chip::CardanoChip
+#[derive(Clone, Debug)]
+pub enum Credential<F: PrimeField> {
+    /// Blake2b-224 hash of an Ed25519 verification key.
+    Key([AssignedNative<F>; CREDENTIAL_HASH_SIZE]),
+    /// Hash of a script, see [ScriptLanguage].
+    Script([AssignedNative<F>; CREDENTIAL_HASH_SIZE]),
+}
+
+impl<F: PrimeField> Credential<F> {
+    /// Returns whether the credential is a script hash, which sets a bit of the type of the
+    /// address.
+    pub(crate) fn is_script(&self) -> bool {
+        matches!(self, Credential::Script(_))
+    }
+
+    /// Returns the cells of the hash of the credential.
+    pub(crate) fn hash(&self) -> &[AssignedNative<F>; CREDENTIAL_HASH_SIZE] {
+        match self {
+            Credential::Key(hash) | Credential::Script(hash) => hash,
+        }
+    }
+}
@@ -0,0 +1,22 @@
+//! Gadgets for the hashes of Cardano, built over the [Blake2bChip]. This interface works with
+//! in/out consisting of AssignedNative. The algorithm expects its values to be in the range of a
+//! Byte, and will fail if they're not.
+//!
+//! * [CardanoChip] This chip computes the Blake2b-224 hashes of verification keys and scripts that
+//!   make up the credentials of Shelley addresses, and the raw bytes of those addresses, as defined
+//!   in CIP-19, and the Blake2b-256 ids of transactions, whose CBOR-encoded bodies can be read
+//!   at the fields located in the [cbor] module. It uses the lookup table of size `2**16` of the
+//!   Blake2b chip.
+//!
+//! [cbor]: crate::cardano::cbor
+//! [Blake2bChip]: crate::blake2b::blake2b_chip::Blake2bChip
+//! [CardanoChip]: crate::cardano::cardano_chip::CardanoChip
+
+/// Credentials, script languages and network ids of Shelley addresses.
+pub mod credential;
+
+/// Locators of the fields of CBOR-encoded transaction bodies.
+pub mod cbor;
+
+/// The Cardano chip.
+pub mod cardano_chip;
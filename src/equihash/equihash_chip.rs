@@ -0,0 +1,365 @@
+use crate::base_operations::equihash_index::EquihashIndexConfig;
+use crate::base_operations::nibble::NibbleConfig;
+use crate::base_operations::not_equal::NotEqualConfig;
+use crate::blake2b::blake2b_chip::{Blake2bChip, Blake2bConfig};
+use crate::blake2b::params::{Blake2bParams, ParamBytes};
+use crate::blake2b::state::Blake2bState;
+use crate::blake2b::NB_BLAKE2B_ADVICE_COLS;
+use crate::equihash::utils::{
+    equihash_personalization, COLLISION_BIT_LENGTH, EQUIHASH_K, HASH_OUTPUT_LENGTH, SOLUTION_SIZE,
+    STRING_LENGTH, STRING_WORDS,
+};
+use crate::types::bit::AssignedBit;
+use crate::types::blake2b_word::{AssignedBlake2bWord, Blake2bWord};
+use crate::types::byte::AssignedByte;
+use crate::types::AssignedNative;
+use ff::PrimeField;
+use midnight_proofs::circuit::{Chip, Layouter, Region, Value};
+use midnight_proofs::plonk::{Advice, Column, ConstraintSystem, Error, Fixed};
+
+/// A node of the tree of a solution: the xor of the strings of its indices, placed in 64-bit
+/// words, and its indices in the order of the solution.
+#[derive(Clone, Debug)]
+struct SolutionNode<F: PrimeField> {
+    string: [AssignedBlake2bWord<F>; STRING_WORDS],
+    indices: Vec<AssignedBlake2bWord<F>>,
+}
+
+/// Selectors and columns for the Equihash chip implementation.
+#[derive(Clone, Debug)]
+pub struct EquihashConfig {
+    /// Config of the Blake2b chip, which is used for the digests of the puzzle and provides the
+    /// decomposition, xor, addition and selection base operations.
+    blake2b_config: Blake2bConfig,
+    /// Base operations configs that are specific to Equihash
+    equihash_index_config: EquihashIndexConfig,
+    nibble_config: NibbleConfig,
+    not_equal_config: NotEqualConfig,
+    /// Columns for the limbs, shared with the Blake2b chip.
+    pub limbs: [Column<Advice>; 8],
+}
+
+/// This is the chip for the verification of Equihash (200, 9) solutions, as done by Zcash. It
+/// proves that a solution, given as its 512 indices, is valid for a header, which is the Blake2b
+/// input of the puzzle, that is, the header of the block without the solution and with the nonce.
+///
+/// The string of an index i is the half i % 2 of the 50-byte Blake2b digest of the header and
+/// i / 2, personalized with the parameters of the puzzle. The indices are the leaves of a binary
+/// tree, and the chip checks, for every node of the level r of the tree:
+/// * that the xor of the strings of its indices starts with 20 * r zero bits, or that it's zero
+///   in the last level, with the xor lookup table of the Blake2b chip.
+/// * that the first index of its left child is smaller than the first index of its right child.
+/// * that the indices of its children are distinct, with a row for every two pairs of indices.
+///
+/// The first block of the digests, which only depends on the header, is compressed once and shared
+/// by all the digests.
+#[derive(Clone, Debug)]
+pub struct EquihashChip<F: PrimeField> {
+    config: EquihashConfig,
+    blake2b_chip: Blake2bChip<F>,
+}
+
+impl<F: PrimeField> Chip<F> for EquihashChip<F> {
+    type Config = EquihashConfig;
+    type Loaded = ();
+
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+impl<F: PrimeField> EquihashChip<F> {
+    /// Generation of a fresh circuit from a configuration.
+    pub fn new(config: &EquihashConfig) -> Self {
+        Self {
+            config: config.clone(),
+            blake2b_chip: Blake2bChip::new(&config.blake2b_config),
+        }
+    }
+
+    /// Configuration of the circuit, this includes the configuration of the Blake2b chip and the
+    /// gates of the checks of the indices. It should be called in the configuration of the user
+    /// circuit before instantiating the Equihash gadget.
+    ///
+    /// Note: as in the Blake2b chip, this function enables equality on all necessary columns,
+    /// i.e., it should not be done manually before calling this function.
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        constants: Column<Fixed>,
+        full_number_u64: Column<Advice>,
+        limbs: [Column<Advice>; NB_BLAKE2B_ADVICE_COLS - 1],
+    ) -> <Self as Chip<F>>::Config {
+        let blake2b_config = Blake2bChip::configure(meta, constants, full_number_u64, limbs);
+        let nibble_config = NibbleConfig::configure(meta, limbs, blake2b_config.t_range);
+        let equihash_index_config = EquihashIndexConfig::configure(
+            meta,
+            full_number_u64,
+            limbs,
+            blake2b_config.q_range,
+            nibble_config.clone(),
+        );
+        let not_equal_config = NotEqualConfig::configure(meta, limbs);
+
+        EquihashConfig {
+            blake2b_config,
+            equihash_index_config,
+            nibble_config,
+            not_equal_config,
+            limbs,
+        }
+    }
+
+    /// Loading the tables used in the chip, which are the ones of the Blake2b chip.
+    pub fn load(&self, layouter: &mut impl Layouter<F>) -> Result<(), Error> {
+        self.blake2b_chip.load(layouter)
+    }
+
+    /// Main method of the chip. It constrains the given indices to be a valid solution of the
+    /// puzzle of the given header. The 'header' cells should be filled with byte values, and its
+    /// length must be known at circuit building time. There must be 512 'solution' cells, with
+    /// the indices in the order of the solution, see [indices_from_minimal].
+    ///
+    /// [indices_from_minimal]: crate::equihash::solution::indices_from_minimal
+    pub fn verify_solution(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        header: &[AssignedNative<F>],
+        solution: &[AssignedNative<F>],
+    ) -> Result<(), Error> {
+        assert_eq!(solution.len(), SOLUTION_SIZE, "A solution must have 512 indices");
+        let params = Blake2bParams::new(HASH_OUTPUT_LENGTH)
+            .personalization(ParamBytes::Constant(equihash_personalization()));
+        let mut header_state = Blake2bState::init(&self.blake2b_chip, layouter, &params, &[])?;
+        header_state.update(layouter, header)?;
+
+        let mut nodes = Vec::with_capacity(SOLUTION_SIZE);
+        for index in solution {
+            let (index, hash_index_bytes, half) = self.decompose_index(layouter, index)?;
+            let mut state = header_state.clone();
+            let hash_index: Vec<AssignedNative<F>> =
+                hash_index_bytes.iter().map(AssignedByte::to_native).collect();
+            state.update(layouter, &hash_index)?;
+            let digest = state.finalize(layouter)?;
+            nodes.push(SolutionNode {
+                string: self.string_from_digest(layouter, &digest, &half)?,
+                indices: vec![index],
+            });
+        }
+
+        for level in 1..=EQUIHASH_K {
+            nodes = nodes
+                .chunks(2)
+                .map(|pair| self.merge(layouter, &pair[0], &pair[1], level))
+                .collect::<Result<_, Error>>()?;
+        }
+        Ok(())
+    }
+}
+
+impl<F: PrimeField> EquihashChip<F> {
+    /// Copies an index of the solution to the trace and splits it in the bytes of its hash index
+    /// and the half of the digest that holds its string, with the [EquihashIndexConfig].
+    #[allow(clippy::type_complexity)]
+    fn decompose_index(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        index: &AssignedNative<F>,
+    ) -> Result<(AssignedBlake2bWord<F>, [AssignedByte<F>; 4], AssignedBit<F>), Error> {
+        layouter.assign_region(
+            || "equihash index",
+            |mut region| {
+                let mut offset: usize = 0;
+                let (assigned_index, hash_index_bytes, half) =
+                    self.config.equihash_index_config.generate_index_rows(
+                        &mut region,
+                        &mut offset,
+                        index.value().map(|v| Blake2bWord::new_from_field(*v)),
+                        self.config.blake2b_config.full_number_u64,
+                        self.config.limbs,
+                    )?;
+                region.constrain_equal(index.cell(), assigned_index.cell())?;
+                Ok((assigned_index, hash_index_bytes, half))
+            },
+        )
+    }
+
+    /// Returns the string of an index, which is the half of the digest given by the 'half' bit,
+    /// placed in words with zeros at the end. Every word is selected between the words of both
+    /// halves.
+    fn string_from_digest(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        digest: &[AssignedByte<F>; 64],
+        half: &AssignedBit<F>,
+    ) -> Result<[AssignedBlake2bWord<F>; STRING_WORDS], Error> {
+        layouter.assign_region(
+            || "equihash string",
+            |mut region| {
+                let zero = region.assign_advice_from_constant(
+                    || "zero",
+                    self.config.limbs[0],
+                    0,
+                    F::ZERO,
+                )?;
+                let mut offset: usize = 1;
+                let mut halves = Vec::with_capacity(2);
+                for half_bytes in digest[..HASH_OUTPUT_LENGTH].chunks(STRING_LENGTH) {
+                    let mut bytes: Vec<AssignedNative<F>> =
+                        half_bytes.iter().map(AssignedByte::to_native).collect();
+                    bytes.resize(8 * STRING_WORDS, zero.clone());
+                    let words = bytes
+                        .chunks(8)
+                        .map(|word_bytes| {
+                            let row = self.blake2b_chip.new_row_from_assigned_bytes(
+                                word_bytes.try_into().unwrap(),
+                                &mut region,
+                                &mut offset,
+                            )?;
+                            Ok(row.full_number)
+                        })
+                        .collect::<Result<Vec<_>, Error>>()?;
+                    halves.push(words);
+                }
+
+                let string = halves[0]
+                    .iter()
+                    .zip(halves[1].iter())
+                    .map(|(when_false, when_true)| {
+                        self.blake2b_chip.select(
+                            half,
+                            when_false,
+                            when_true,
+                            &mut region,
+                            &mut offset,
+                        )
+                    })
+                    .collect::<Result<Vec<_>, Error>>()?;
+                Ok(string.try_into().unwrap())
+            },
+        )
+    }
+
+    /// Merges two sibling nodes of the given level of the tree. The xor of their strings must
+    /// start with 20 * level zero bits, or be zero in the last level, the first index of the left
+    /// node must be smaller than the one of the right node, and their indices must be distinct.
+    fn merge(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        left: &SolutionNode<F>,
+        right: &SolutionNode<F>,
+        level: usize,
+    ) -> Result<SolutionNode<F>, Error> {
+        layouter.assign_region(
+            || "equihash merge",
+            |mut region| {
+                let zero = region.assign_advice_from_constant(
+                    || "zero",
+                    self.config.limbs[0],
+                    0,
+                    F::ZERO,
+                )?;
+                let one = AssignedBlake2bWord::assign_fixed_word(
+                    &mut region,
+                    "one",
+                    self.config.blake2b_config.full_number_u64,
+                    0,
+                    Blake2bWord(1),
+                )?;
+                let mut offset: usize = 1;
+
+                let rows = left
+                    .string
+                    .iter()
+                    .zip(right.string.iter())
+                    .map(|(lhs, rhs)| self.blake2b_chip.xor(lhs, rhs, &mut region, &mut offset))
+                    .collect::<Result<Vec<_>, Error>>()?;
+
+                // The bits of the strings are taken in big endian order from their bytes, so
+                // when the collision ends in the middle of a byte, the byte must be a nibble
+                let zero_bits = if level == EQUIHASH_K {
+                    8 * STRING_LENGTH
+                } else {
+                    COLLISION_BIT_LENGTH * level
+                };
+                let byte = |position: usize| &rows[position / 8].limbs[position % 8];
+                for position in 0..zero_bits / 8 {
+                    region.constrain_equal(byte(position).cell(), zero.cell())?;
+                }
+                if zero_bits % 8 != 0 {
+                    self.config.nibble_config.generate_nibble_row_from_byte(
+                        &mut region,
+                        &mut offset,
+                        byte(zero_bits / 8),
+                        self.config.limbs,
+                    )?;
+                }
+
+                self.constrain_less_than(
+                    &left.indices[0],
+                    &right.indices[0],
+                    &one,
+                    &mut region,
+                    &mut offset,
+                )?;
+
+                let pairs: Vec<_> = left
+                    .indices
+                    .iter()
+                    .flat_map(|lhs| right.indices.iter().map(move |rhs| (lhs, rhs)))
+                    .collect();
+                for pairs_of_row in pairs.chunks(2) {
+                    // A single pair is checked twice in its row
+                    let pairs_of_row = [pairs_of_row[0], pairs_of_row[pairs_of_row.len() - 1]];
+                    self.config.not_equal_config.generate_not_equal_row_from_cells(
+                        &mut region,
+                        &mut offset,
+                        pairs_of_row,
+                        self.config.limbs,
+                    )?;
+                }
+
+                Ok(SolutionNode {
+                    string: rows
+                        .into_iter()
+                        .map(|row| row.full_number)
+                        .collect::<Vec<_>>()
+                        .try_into()
+                        .unwrap(),
+                    indices: [left.indices.clone(), right.indices.clone()].concat(),
+                })
+            },
+        )
+    }
+
+    /// Constrains an index to be smaller than another one, by witnessing their difference minus
+    /// one as a 21-bit number d, with the [EquihashIndexConfig], and checking that lhs + d + 1 is
+    /// rhs. The sums can't overflow, since all the operands are small.
+    fn constrain_less_than(
+        &self,
+        lhs: &AssignedBlake2bWord<F>,
+        rhs: &AssignedBlake2bWord<F>,
+        one: &AssignedBlake2bWord<F>,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+    ) -> Result<(), Error> {
+        let difference_value: Value<Blake2bWord> = lhs
+            .value()
+            .zip(rhs.value())
+            .map(|(lhs, rhs)| Blake2bWord(rhs.0.wrapping_sub(lhs.0).wrapping_sub(1)));
+        let (difference, _, _) = self.config.equihash_index_config.generate_index_rows(
+            region,
+            offset,
+            difference_value,
+            self.config.blake2b_config.full_number_u64,
+            self.config.limbs,
+        )?;
+        let sum = self.blake2b_chip.add(lhs, &difference, region, offset)?;
+        let sum =
+            self.blake2b_chip.add_copying_one_parameter(&sum.full_number, one, region, offset)?;
+        region.constrain_equal(sum.full_number.cell(), rhs.cell())
+    }
+}
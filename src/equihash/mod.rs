@@ -0,0 +1,21 @@
+//! A chip verifying solutions of the Equihash proof of work of Zcash, with parameters n = 200 and
+//! k = 9. This interface works with in/out consisting of AssignedNative. The algorithm expects its
+//! values to be in the range of a Byte, and will fail if they're not.
+//!
+//! The chip is built over the [Blake2bChip], which computes the personalized digests of the
+//! puzzle, and uses its xor lookup table for the collision checks:
+//! * [EquihashChip] This chip uses the lookup table of size `2**16` of the Blake2b chip, and a
+//!   solution costs 512 Blake2b compressions plus a row for every two pairs of indices, so the
+//!   circuits instantiating this chip are usually of at least `2**21` rows.
+//!
+//! [Blake2bChip]: crate::blake2b::blake2b_chip::Blake2bChip
+//! [EquihashChip]: crate::equihash::equihash_chip::EquihashChip
+
+/// Basic definitions and constants of Equihash.
+pub(crate) mod utils;
+
+/// Decoding of the encodings of a solution that are used outside the circuit.
+pub mod solution;
+
+/// The Equihash chip.
+pub mod equihash_chip;
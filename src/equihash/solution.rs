@@ -0,0 +1,23 @@
+use crate::equihash::utils::{INDEX_BIT_LENGTH, SOLUTION_SIZE};
+
+/// Byte length of the minimal encoding of a solution, the one that is included in the headers of
+/// Zcash blocks.
+pub const MINIMAL_SOLUTION_LENGTH: usize = SOLUTION_SIZE * INDEX_BIT_LENGTH / 8;
+
+/// Decodes the indices of a solution from its minimal encoding, where every index takes 21 bits
+/// in big endian order. The chip receives the indices of a solution, so this is the way to obtain
+/// them from a block header.
+pub fn indices_from_minimal(minimal: &[u8]) -> Vec<u32> {
+    assert_eq!(
+        minimal.len(),
+        MINIMAL_SOLUTION_LENGTH,
+        "The minimal encoding of a solution has {MINIMAL_SOLUTION_LENGTH} bytes"
+    );
+    let bit = |position: usize| (minimal[position / 8] >> (7 - position % 8)) & 1;
+    (0..SOLUTION_SIZE)
+        .map(|i| {
+            (0..INDEX_BIT_LENGTH)
+                .fold(0, |index, j| (index << 1) | bit(i * INDEX_BIT_LENGTH + j) as u32)
+        })
+        .collect()
+}
@@ -0,0 +1,36 @@
+/// Returns the personalization of the Blake2b digests of the puzzle, which is "ZcashPoW"
+/// followed by the parameters n and k as 4 little endian bytes each.
+pub(crate) fn equihash_personalization() -> [u8; 16] {
+    let mut personalization = [0u8; 16];
+    personalization[..8].copy_from_slice(b"ZcashPoW");
+    personalization[8..12].copy_from_slice(&(EQUIHASH_N as u32).to_le_bytes());
+    personalization[12..].copy_from_slice(&(EQUIHASH_K as u32).to_le_bytes());
+    personalization
+}
+
+// ----- Equihash constants -----
+
+pub(crate) const EQUIHASH_N: usize = 200;
+
+pub(crate) const EQUIHASH_K: usize = 9;
+
+/// Amount of bits on which the strings collide in every level of the tree but the last one.
+pub(crate) const COLLISION_BIT_LENGTH: usize = EQUIHASH_N / (EQUIHASH_K + 1);
+
+/// Amount of indices of a solution.
+pub(crate) const SOLUTION_SIZE: usize = 1 << EQUIHASH_K;
+
+/// Amount of bits of every index of a solution.
+pub(crate) const INDEX_BIT_LENGTH: usize = COLLISION_BIT_LENGTH + 1;
+
+/// Byte length of the string of an index.
+pub(crate) const STRING_LENGTH: usize = EQUIHASH_N / 8;
+
+/// Amount of strings in every Blake2b digest of the puzzle.
+pub(crate) const INDICES_PER_HASH_OUTPUT: usize = 512 / EQUIHASH_N;
+
+/// Byte length of the Blake2b digests of the puzzle.
+pub(crate) const HASH_OUTPUT_LENGTH: usize = INDICES_PER_HASH_OUTPUT * STRING_LENGTH;
+
+/// Amount of 64-bit words in which the string of an index is placed, with zeros at the end.
+pub(crate) const STRING_WORDS: usize = STRING_LENGTH.div_ceil(8);
@@ -14,6 +14,15 @@ pub(crate) mod base_operations;
 
 #[cfg(test)]
 mod tests;
+pub mod argon2;
 pub mod blake2b;
+pub mod blake2s;
+pub mod blake3;
+pub mod cardano;
+pub mod equihash;
+pub mod merkle;
+pub mod sha512;
+pub mod substrate;
 pub mod types;
+pub mod uint64;
 pub mod usage_utils;
@@ -0,0 +1,214 @@
+use crate::base_operations::boolean::BooleanConfig;
+use crate::blake2b::blake2b_chip::{Blake2bChip, Blake2bConfig};
+use crate::blake2b::NB_BLAKE2B_ADVICE_COLS;
+use crate::merkle::params::{MerkleTreeParams, MERKLE_DIGEST_SIZE};
+use crate::types::blake2b_word::AssignedBlake2bWord;
+use crate::types::byte::AssignedByte;
+use crate::types::AssignedNative;
+use ff::PrimeField;
+use midnight_proofs::circuit::{Chip, Layouter, Region, Value};
+use midnight_proofs::plonk::{Advice, Column, ConstraintSystem, Error, Fixed};
+
+/// A level of the path of a leaf, from the bottom of the tree to the top: the hashes of the
+/// siblings of the node of the path, in order, and the position of the node among the children of
+/// its parent, as computed by [MerkleTreeParams::path].
+#[derive(Clone, Debug)]
+pub struct MerklePathLevel<F: PrimeField> {
+    /// The 32-byte hashes of the 'arity - 1' siblings, one after the other.
+    pub siblings: Vec<AssignedNative<F>>,
+    /// Position of the node of the path, which is known at proof generation time.
+    pub position: Value<usize>,
+}
+
+/// Selectors and columns for the Merkle chip implementation.
+#[derive(Clone, Debug)]
+pub struct MerkleConfig {
+    /// Config of the Blake2b chip, which computes all the hashes and provides the decomposition
+    /// and selection base operations.
+    blake2b_config: Blake2bConfig,
+    /// Base operations config for the path bits
+    boolean_config: BooleanConfig,
+    /// Columns for the limbs, shared with the Blake2b chip.
+    pub limbs: [Column<Advice>; 8],
+}
+
+/// This is the chip for the membership proofs of Blake2b-256 Merkle trees. It computes the root of
+/// a tree from a leaf and its path, and the circuit proves that the leaf is included in the tree
+/// by constraining the root to a public input.
+///
+/// The children of a node are ordered with path bits, which are witnessed from the position of
+/// the node of the path and constrained to be bits. The node of the path starts as the first
+/// child, followed by its siblings, and the bit j swaps the children j and j + 1, for j from 0 to
+/// 'arity - 2', which moves the node of the path to its position when the bit j is set for every
+/// j smaller than the position. In binary trees, this is a single bit which is set when the node
+/// of the path is the right child.
+#[derive(Clone, Debug)]
+pub struct MerkleChip<F: PrimeField> {
+    config: MerkleConfig,
+    blake2b_chip: Blake2bChip<F>,
+}
+
+impl<F: PrimeField> Chip<F> for MerkleChip<F> {
+    type Config = MerkleConfig;
+    type Loaded = ();
+
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+impl<F: PrimeField> MerkleChip<F> {
+    /// Generation of a fresh circuit from a configuration.
+    pub fn new(config: &MerkleConfig) -> Self {
+        Self {
+            config: config.clone(),
+            blake2b_chip: Blake2bChip::new(&config.blake2b_config),
+        }
+    }
+
+    /// Configuration of the circuit, this includes the configuration of the Blake2b chip and the
+    /// gate of the path bits. It should be called in the configuration of the user circuit before
+    /// instantiating the Merkle gadget.
+    ///
+    /// Note: as in the Blake2b chip, this function enables equality on all necessary columns,
+    /// i.e., it should not be done manually before calling this function.
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        constants: Column<Fixed>,
+        full_number_u64: Column<Advice>,
+        limbs: [Column<Advice>; NB_BLAKE2B_ADVICE_COLS - 1],
+    ) -> <Self as Chip<F>>::Config {
+        let blake2b_config = Blake2bChip::configure(meta, constants, full_number_u64, limbs);
+        let boolean_config = BooleanConfig::configure(meta, limbs);
+        MerkleConfig {
+            blake2b_config,
+            boolean_config,
+            limbs,
+        }
+    }
+
+    /// Loading the tables used in the chip, which are the ones of the Blake2b chip.
+    pub fn load(&self, layouter: &mut impl Layouter<F>) -> Result<(), Error> {
+        self.blake2b_chip.load(layouter)
+    }
+
+    /// Main method of the chip. It computes the root of the tree from the given leaf and its path,
+    /// which must have a level for every level of inner nodes of the tree. The 'leaf' and sibling
+    /// cells should be filled with byte values, and the length of the leaf must be known at
+    /// circuit building time.
+    pub fn compute_root(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        params: &MerkleTreeParams,
+        leaf: &[AssignedNative<F>],
+        path: &[MerklePathLevel<F>],
+    ) -> Result<[AssignedByte<F>; MERKLE_DIGEST_SIZE], Error> {
+        assert_eq!(path.len(), params.depth, "The path must have a level per level of the tree");
+        let mut input = self.blake2b_chip.assign_constant_bytes(layouter, &params.leaf_prefix)?;
+        input.extend_from_slice(leaf);
+        let mut node = self.blake2b_chip.hash_to_array::<MERKLE_DIGEST_SIZE>(layouter, &input)?;
+        for level in path {
+            let mut input =
+                self.blake2b_chip.assign_constant_bytes(layouter, &params.node_prefix)?;
+            input.extend(self.children(layouter, params.arity, &node, level)?);
+            node = self.blake2b_chip.hash_to_array::<MERKLE_DIGEST_SIZE>(layouter, &input)?;
+        }
+        Ok(node)
+    }
+}
+
+impl<F: PrimeField> MerkleChip<F> {
+    /// Returns the bytes of the hashes of the children of the parent of 'node', in order. The
+    /// hashes are placed in words, which are swapped with the path bits, and decomposed back into
+    /// bytes.
+    fn children(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        arity: usize,
+        node: &[AssignedByte<F>; MERKLE_DIGEST_SIZE],
+        level: &MerklePathLevel<F>,
+    ) -> Result<Vec<AssignedNative<F>>, Error> {
+        assert_eq!(
+            level.siblings.len(),
+            (arity - 1) * MERKLE_DIGEST_SIZE,
+            "A level of the path must have the hashes of 'arity - 1' siblings"
+        );
+        level.position.assert_if_known(|position| *position < arity);
+        layouter.assign_region(
+            || "merkle children",
+            |mut region| {
+                let mut offset: usize = 0;
+                let bit_values: Vec<Value<F>> = (0..arity - 1)
+                    .map(|j| level.position.map(|position| F::from((j < position) as u64)))
+                    .collect();
+                let mut bits = Vec::with_capacity(arity - 1);
+                for row_bits in bit_values.chunks(8) {
+                    bits.extend(self.config.boolean_config.generate_bits_row(
+                        &mut region,
+                        &mut offset,
+                        row_bits,
+                        self.config.limbs,
+                    )?);
+                }
+
+                let node: Vec<AssignedNative<F>> =
+                    node.iter().map(AssignedByte::to_native).collect();
+                let mut children = vec![self.hash_words(&node, &mut region, &mut offset)?];
+                for sibling in level.siblings.chunks(MERKLE_DIGEST_SIZE) {
+                    children.push(self.hash_words(sibling, &mut region, &mut offset)?);
+                }
+
+                for (j, bit) in bits.iter().enumerate() {
+                    let (left, right) = (children[j].clone(), children[j + 1].clone());
+                    for word in 0..MERKLE_DIGEST_SIZE / 8 {
+                        children[j][word] = self.blake2b_chip.select(
+                            bit,
+                            &left[word],
+                            &right[word],
+                            &mut region,
+                            &mut offset,
+                        )?;
+                        children[j + 1][word] = self.blake2b_chip.select(
+                            bit,
+                            &right[word],
+                            &left[word],
+                            &mut region,
+                            &mut offset,
+                        )?;
+                    }
+                }
+
+                let mut bytes = Vec::with_capacity(arity * MERKLE_DIGEST_SIZE);
+                for word in children.iter().flatten() {
+                    let row =
+                        self.blake2b_chip.new_row_from_word(word, &mut region, &mut offset)?;
+                    bytes.extend(row.limbs.iter().map(AssignedByte::to_native));
+                }
+                Ok(bytes)
+            },
+        )
+    }
+
+    /// Places the 32 bytes of a hash in 4 range-checked words.
+    fn hash_words(
+        &self,
+        hash: &[AssignedNative<F>],
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+    ) -> Result<Vec<AssignedBlake2bWord<F>>, Error> {
+        hash.chunks(8)
+            .map(|bytes| {
+                let row = self.blake2b_chip.new_row_from_assigned_bytes(
+                    bytes.try_into().unwrap(),
+                    region,
+                    offset,
+                )?;
+                Ok(row.full_number)
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,17 @@
+//! A chip for the membership proofs of Blake2b-256 Merkle trees of any depth and arity, such as the
+//! binary and trinary trees used by Filecoin and Polkadot. This interface works with in/out
+//! consisting of AssignedNative. The algorithm expects its values to be in the range of a Byte,
+//! and will fail if they're not.
+//!
+//! * [MerkleChip] This chip computes the root of a tree from a leaf and the path of the leaf, with
+//!   the [Blake2bChip]. It uses the lookup table of size `2**16` of the Blake2b chip, and a proof
+//!   costs a Blake2b digest per level plus the one of the leaf.
+//!
+//! [Blake2bChip]: crate::blake2b::blake2b_chip::Blake2bChip
+//! [MerkleChip]: crate::merkle::merkle_chip::MerkleChip
+
+/// Shape and prefixes of the trees, and their roots and paths computed outside the circuit.
+pub mod params;
+
+/// The Merkle chip.
+pub mod merkle_chip;
@@ -0,0 +1,110 @@
+use blake2b_simd::Params;
+
+/// Size in bytes of the digests of the tree, which are Blake2b-256 digests.
+pub const MERKLE_DIGEST_SIZE: usize = 32;
+
+/// Shape of a Blake2b-256 Merkle tree and the prefixes that separate the hashes of the leaves from
+/// the hashes of the inner nodes. The hash of a leaf is the digest of the leaf prefix followed by
+/// the leaf, and the hash of a node is the digest of the node prefix followed by the hashes of its
+/// children, in order. Both prefixes are empty by default, and they're always known at circuit
+/// building time.
+///
+/// Besides the parameters, this type computes roots and paths outside the circuit, which are the
+/// inputs of the [MerkleChip].
+///
+/// [MerkleChip]: crate::merkle::merkle_chip::MerkleChip
+#[derive(Clone, Debug)]
+pub struct MerkleTreeParams {
+    pub(crate) depth: usize,
+    pub(crate) arity: usize,
+    pub(crate) leaf_prefix: Vec<u8>,
+    pub(crate) node_prefix: Vec<u8>,
+}
+
+impl MerkleTreeParams {
+    /// Creates the parameters of a tree with the given depth, which is the amount of levels of
+    /// inner nodes, and arity, which is the amount of children of every inner node.
+    pub fn new(depth: usize, arity: usize) -> Self {
+        assert!(arity >= 2, "The arity of a tree must be at least 2");
+        Self {
+            depth,
+            arity,
+            leaf_prefix: vec![],
+            node_prefix: vec![],
+        }
+    }
+
+    /// Sets the prefix of the hashes of the leaves.
+    pub fn leaf_prefix(mut self, prefix: &[u8]) -> Self {
+        self.leaf_prefix = prefix.to_vec();
+        self
+    }
+
+    /// Sets the prefix of the hashes of the inner nodes.
+    pub fn node_prefix(mut self, prefix: &[u8]) -> Self {
+        self.node_prefix = prefix.to_vec();
+        self
+    }
+
+    /// Amount of leaves of the tree, which is the arity to the power of the depth.
+    pub fn leaf_count(&self) -> usize {
+        self.arity.pow(self.depth as u32)
+    }
+
+    /// Computes the root of the tree with the given leaves.
+    pub fn root(&self, leaves: &[Vec<u8>]) -> [u8; MERKLE_DIGEST_SIZE] {
+        self.levels(leaves).last().unwrap()[0]
+    }
+
+    /// Computes the path of the leaf at the given index, from the bottom of the tree to the top.
+    /// Every level holds the position of the node of the path among its siblings, and the hashes
+    /// of those siblings, in order.
+    pub fn path(&self, leaves: &[Vec<u8>], index: usize) -> Vec<(usize, Vec<u8>)> {
+        let levels = self.levels(leaves);
+        let mut node = index;
+        levels[..self.depth]
+            .iter()
+            .map(|level| {
+                let position = node % self.arity;
+                let first_child = node - position;
+                let siblings = (first_child..first_child + self.arity)
+                    .filter(|child| *child != node)
+                    .flat_map(|child| level[child])
+                    .collect();
+                node /= self.arity;
+                (position, siblings)
+            })
+            .collect()
+    }
+
+    /// Hashes of all the nodes of the tree, level by level, starting from the hashes of the leaves.
+    fn levels(&self, leaves: &[Vec<u8>]) -> Vec<Vec<[u8; MERKLE_DIGEST_SIZE]>> {
+        assert_eq!(leaves.len(), self.leaf_count(), "The tree must have arity^depth leaves");
+        let mut levels = vec![leaves
+            .iter()
+            .map(|leaf| blake2b_256(&self.leaf_prefix, &[leaf]))
+            .collect::<Vec<_>>()];
+        for _ in 0..self.depth {
+            let children = levels.last().unwrap();
+            let nodes = children
+                .chunks(self.arity)
+                .map(|chunk| {
+                    let chunk: Vec<&[u8]> = chunk.iter().map(|child| &child[..]).collect();
+                    blake2b_256(&self.node_prefix, &chunk)
+                })
+                .collect();
+            levels.push(nodes);
+        }
+        levels
+    }
+}
+
+/// Blake2b-256 digest of the given prefix followed by the given pieces.
+fn blake2b_256(prefix: &[u8], pieces: &[&[u8]]) -> [u8; MERKLE_DIGEST_SIZE] {
+    let mut state = Params::new().hash_length(MERKLE_DIGEST_SIZE).to_state();
+    state.update(prefix);
+    for piece in pieces {
+        state.update(piece);
+    }
+    state.finalize().as_bytes().try_into().unwrap()
+}
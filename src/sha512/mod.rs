@@ -0,0 +1,21 @@
+//! A chip defining a SHA-512 hash invocation, as defined in FIPS 180-4. This interface works with
+//! inputs consisting of AssignedNative. The algorithm expects its values to be in the range of a
+//! Byte, and will fail if they're not.
+//!
+//! The chip is built over the instructions of the [Uint64Chip], since all the operations of
+//! SHA-512 are over 64-bit words:
+//! * [Sha512Chip] This chip uses the lookup tables of size `2**16` of the Uint64 chip, so all
+//!   circuits instantiating this chip will be at least `2**17` rows. It can share its columns,
+//!   gates and tables with a Blake2b chip of the same circuit.
+//!
+//! [Uint64Chip]: crate::uint64::uint64_chip::Uint64Chip
+//! [Sha512Chip]: crate::sha512::sha512_chip::Sha512Chip
+
+/// Basic definitions and constants for the sha512 chip.
+pub(crate) mod utils;
+
+/// The SHA-512 chip.
+pub mod sha512_chip;
+
+/// Number of advice columns required by the chip.
+pub const NB_SHA512_ADVICE_COLS: usize = 9;
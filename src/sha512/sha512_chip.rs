@@ -0,0 +1,275 @@
+use crate::blake2b::blake2b_chip::Blake2bConfig;
+use crate::blake2b::utils::IV_CONSTANTS;
+use crate::sha512::utils::{
+    padding, ROUND_CONSTANTS, SHA512_BLOCK_SIZE, SHA512_ROUNDS, SHA512_WORD_SIZE,
+};
+use crate::sha512::NB_SHA512_ADVICE_COLS;
+use crate::types::byte::AssignedByte;
+use crate::types::uint64::AssignedU64;
+use crate::types::AssignedNative;
+use crate::uint64::uint64_chip::{Uint64Chip, Uint64Config};
+use ff::PrimeField;
+use midnight_proofs::circuit::{Chip, Layouter};
+use midnight_proofs::plonk::{Advice, Column, ConstraintSystem, Error, Fixed};
+
+/// Selectors and columns for the SHA-512 chip implementation.
+#[derive(Clone, Debug)]
+pub struct Sha512Config {
+    /// Config of the Uint64 chip, which holds the operations over 64-bit words.
+    uint64_config: Uint64Config,
+    /// Columns for the limbs, shared with the Uint64 chip.
+    pub limbs: [Column<Advice>; 8],
+}
+
+/// This is the main chip for the SHA-512 hash function. It is responsible for the entire hash
+/// computation.
+///
+/// Every operation of SHA-512 is an instruction of the [Uint64Chip]: the additions are mod 2^64,
+/// the rotations and shifts of the message schedule and the rounds are limb rotations followed by
+/// bit rotations, and the choice and majority functions use the and and and-not lookups. The
+/// words of SHA-512 are big-endian, so the bytes of the input and of the digest are reversed with
+/// respect to the little-endian limbs of the trace.
+///
+/// [Uint64Chip]: crate::uint64::uint64_chip::Uint64Chip
+#[derive(Clone, Debug)]
+pub struct Sha512Chip<F: PrimeField> {
+    config: Sha512Config,
+    uint64_chip: Uint64Chip<F>,
+}
+
+impl<F: PrimeField> Chip<F> for Sha512Chip<F> {
+    type Config = Sha512Config;
+    type Loaded = ();
+
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+impl<F: PrimeField> Sha512Chip<F> {
+    /// Generation of a fresh circuit from a configuration.
+    pub fn new(config: &Sha512Config) -> Self {
+        Self {
+            config: config.clone(),
+            uint64_chip: Uint64Chip::new(&config.uint64_config),
+        }
+    }
+
+    /// Configuration of the circuit, which is the configuration of the Uint64 chip. It should be
+    /// called in the configuration of the user circuit before instantiating the chip.
+    ///
+    /// Note: as in the Blake2b chip, this function enables equality on all necessary columns,
+    /// i.e., it should not be done manually before calling this function.
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        constants: Column<Fixed>,
+        full_number_u64: Column<Advice>,
+        limbs: [Column<Advice>; NB_SHA512_ADVICE_COLS - 1],
+    ) -> <Self as Chip<F>>::Config {
+        let uint64_config = Uint64Chip::configure(meta, constants, full_number_u64, limbs);
+        Sha512Config {
+            uint64_config,
+            limbs,
+        }
+    }
+
+    /// Configuration of the chip over the configuration of a Blake2b chip of the same circuit,
+    /// which shares its columns, gates and tables with it. See
+    /// [Uint64Chip::configure_with_blake2b].
+    pub fn configure_with_blake2b(
+        meta: &mut ConstraintSystem<F>,
+        blake2b_config: &Blake2bConfig,
+    ) -> <Self as Chip<F>>::Config {
+        let uint64_config = Uint64Chip::configure_with_blake2b(meta, blake2b_config);
+        Sha512Config {
+            limbs: uint64_config.limbs,
+            uint64_config,
+        }
+    }
+
+    /// Loading the tables used in the chip, which are the ones of the Uint64 chip. When the
+    /// configuration is shared with a Blake2b chip, which loads its own tables, only the table of
+    /// the and, or and and-not operations must be loaded, with [Self::load_bitwise_table].
+    pub fn load(&self, layouter: &mut impl Layouter<F>) -> Result<(), Error> {
+        self.uint64_chip.load(layouter)
+    }
+
+    /// Loading the table of the and, or and and-not operations, for circuits in which the tables
+    /// of the Blake2b chip are loaded by a Blake2b chip sharing the configuration.
+    pub fn load_bitwise_table(&self, layouter: &mut impl Layouter<F>) -> Result<(), Error> {
+        self.uint64_chip.load_bitwise_table(layouter)
+    }
+
+    /// Main method of the chip. The 'input' cells should be filled with byte values. The length
+    /// of the input is known when the circuit is built, so the padding is made of constants.
+    pub fn hash(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        input: &[AssignedNative<F>],
+    ) -> Result<[AssignedByte<F>; 64], Error> {
+        let round_constants = ROUND_CONSTANTS
+            .iter()
+            .map(|constant| self.uint64_chip.assign_constant(layouter, *constant))
+            .collect::<Result<Vec<_>, Error>>()?;
+        let mut state: [AssignedU64<F>; 8] = IV_CONSTANTS
+            .iter()
+            .map(|constant| self.uint64_chip.assign_constant(layouter, *constant))
+            .collect::<Result<Vec<_>, Error>>()?
+            .try_into()
+            .unwrap();
+
+        let mut message = input.to_vec();
+        message.extend(self.uint64_chip.assign_constant_bytes(layouter, &padding(input.len()))?);
+
+        for block in message.chunks(SHA512_BLOCK_SIZE) {
+            let block_words = block
+                .chunks(SHA512_WORD_SIZE)
+                .map(|word_bytes| self.word_from_be_bytes(layouter, word_bytes))
+                .collect::<Result<Vec<_>, Error>>()?;
+            state = self.compress(layouter, &state, block_words, &round_constants)?;
+        }
+
+        let mut digest = Vec::with_capacity(64);
+        for word in state.iter() {
+            let mut bytes = self.uint64_chip.to_le_bytes(layouter, word)?;
+            bytes.reverse();
+            digest.extend(bytes);
+        }
+        Ok(digest.try_into().unwrap())
+    }
+}
+
+impl<F: PrimeField> Sha512Chip<F> {
+    /// This method computes the compression function of SHA-512 over a block, given as its 16
+    /// words, and returns the updated state.
+    fn compress(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        state: &[AssignedU64<F>; 8],
+        mut schedule: Vec<AssignedU64<F>>,
+        round_constants: &[AssignedU64<F>],
+    ) -> Result<[AssignedU64<F>; 8], Error> {
+        // w[t] = sigma_1(w[t - 2]) + w[t - 7] + sigma_0(w[t - 15]) + w[t - 16]
+        for t in 16..SHA512_ROUNDS {
+            let sigma_1 = self.sigma(layouter, &schedule[t - 2], [19, 61], 6)?;
+            let sigma_0 = self.sigma(layouter, &schedule[t - 15], [1, 8], 7)?;
+            let word =
+                self.add_all(layouter, &[&sigma_1, &schedule[t - 7], &sigma_0, &schedule[t - 16]])?;
+            schedule.push(word);
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = state.clone();
+        for (word, round_constant) in schedule.iter().zip(round_constants) {
+            // t1 = h + Sigma_1(e) + Ch(e, f, g) + k[t] + w[t]
+            let big_sigma_1 = self.big_sigma(layouter, &e, [14, 18, 41])?;
+            let choice = self.choice(layouter, &e, &f, &g)?;
+            let t1 = self.add_all(layouter, &[&h, &big_sigma_1, &choice, round_constant, word])?;
+
+            // t2 = Sigma_0(a) + Maj(a, b, c)
+            let big_sigma_0 = self.big_sigma(layouter, &a, [28, 34, 39])?;
+            let majority = self.majority(layouter, &a, &b, &c)?;
+            let t2 = self.uint64_chip.add(layouter, &big_sigma_0, &majority)?;
+
+            h = g;
+            g = f;
+            f = e;
+            e = self.uint64_chip.add(layouter, &d, &t1)?;
+            d = c;
+            c = b;
+            b = a;
+            a = self.uint64_chip.add(layouter, &t1, &t2)?;
+        }
+
+        let working_variables = [a, b, c, d, e, f, g, h];
+        let mut new_state = Vec::with_capacity(8);
+        for (word, working_variable) in state.iter().zip(working_variables.iter()) {
+            new_state.push(self.uint64_chip.add(layouter, word, working_variable)?);
+        }
+        Ok(new_state.try_into().unwrap())
+    }
+
+    /// The functions Sigma_0 and Sigma_1 of the rounds, the xor of three rotations of the word.
+    fn big_sigma(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        word: &AssignedU64<F>,
+        rotations: [usize; 3],
+    ) -> Result<AssignedU64<F>, Error> {
+        let first = self.uint64_chip.rotate_right(layouter, word, rotations[0])?;
+        let second = self.uint64_chip.rotate_right(layouter, word, rotations[1])?;
+        let third = self.uint64_chip.rotate_right(layouter, word, rotations[2])?;
+        let partial = self.uint64_chip.xor(layouter, &first, &second)?;
+        self.uint64_chip.xor(layouter, &partial, &third)
+    }
+
+    /// The functions sigma_0 and sigma_1 of the message schedule, the xor of two rotations and a
+    /// shift of the word.
+    fn sigma(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        word: &AssignedU64<F>,
+        rotations: [usize; 2],
+        shift: usize,
+    ) -> Result<AssignedU64<F>, Error> {
+        let first = self.uint64_chip.rotate_right(layouter, word, rotations[0])?;
+        let second = self.uint64_chip.rotate_right(layouter, word, rotations[1])?;
+        let shifted = self.uint64_chip.shift_right(layouter, word, shift)?;
+        let partial = self.uint64_chip.xor(layouter, &first, &second)?;
+        self.uint64_chip.xor(layouter, &partial, &shifted)
+    }
+
+    /// Ch(e, f, g) = (e & f) ^ (!e & g)
+    fn choice(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        e: &AssignedU64<F>,
+        f: &AssignedU64<F>,
+        g: &AssignedU64<F>,
+    ) -> Result<AssignedU64<F>, Error> {
+        let e_and_f = self.uint64_chip.and(layouter, e, f)?;
+        let not_e_and_g = self.uint64_chip.and_not(layouter, e, g)?;
+        self.uint64_chip.xor(layouter, &e_and_f, &not_e_and_g)
+    }
+
+    /// Maj(a, b, c) = (a & b) ^ (a & c) ^ (b & c), computed as (a & b) ^ (c & (a ^ b)), which
+    /// takes an operation less.
+    fn majority(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: &AssignedU64<F>,
+        b: &AssignedU64<F>,
+        c: &AssignedU64<F>,
+    ) -> Result<AssignedU64<F>, Error> {
+        let a_and_b = self.uint64_chip.and(layouter, a, b)?;
+        let a_xor_b = self.uint64_chip.xor(layouter, a, b)?;
+        let c_and_a_xor_b = self.uint64_chip.and(layouter, c, &a_xor_b)?;
+        self.uint64_chip.xor(layouter, &a_and_b, &c_and_a_xor_b)
+    }
+
+    /// Addition mod 2^64 of several words.
+    fn add_all(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        words: &[&AssignedU64<F>],
+    ) -> Result<AssignedU64<F>, Error> {
+        let (first, rest) = words.split_first().expect("There must be words to add");
+        rest.iter()
+            .try_fold((*first).clone(), |sum, word| self.uint64_chip.add(layouter, &sum, word))
+    }
+
+    /// Builds a word of the message from its 8 bytes, in big-endian order. The bytes are
+    /// constrained to be in the range [0, 255].
+    fn word_from_be_bytes(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        bytes: &[AssignedNative<F>],
+    ) -> Result<AssignedU64<F>, Error> {
+        let le_bytes: [AssignedNative<F>; 8] =
+            std::array::from_fn(|i| bytes[SHA512_WORD_SIZE - 1 - i].clone());
+        self.uint64_chip.from_le_bytes(layouter, &le_bytes)
+    }
+}
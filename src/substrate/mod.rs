@@ -0,0 +1,13 @@
+//! Gadgets for the Blake2b hashers of Substrate, which build the storage keys of the state of
+//! Polkadot and the other Substrate chains. This interface works with in/out consisting of
+//! AssignedNative. The algorithm expects its values to be in the range of a Byte, and will fail
+//! if they're not.
+//!
+//! * [SubstrateChip] This chip computes the `blake2_128`, `blake2_128_concat` and `blake2_256`
+//!   hashers over SCALE-encoded keys, and the storage keys of map items built with them. It uses
+//!   the lookup table of size `2**16` of the Blake2b chip.
+//!
+//! [SubstrateChip]: crate::substrate::substrate_chip::SubstrateChip
+
+/// The Substrate chip.
+pub mod substrate_chip;
@@ -0,0 +1,155 @@
+use crate::blake2b::blake2b_chip::{Blake2bChip, Blake2bConfig};
+use crate::blake2b::NB_BLAKE2B_ADVICE_COLS;
+use crate::types::byte::AssignedByte;
+use crate::types::AssignedNative;
+use ff::PrimeField;
+use midnight_proofs::circuit::{Chip, Layouter};
+use midnight_proofs::plonk::{Advice, Column, ConstraintSystem, Error, Fixed};
+
+/// Size in bytes of the prefix of the storage key of a map item, which is the `twox_128` hash of
+/// the name of the pallet followed by the `twox_128` hash of the name of the storage item.
+pub const STORAGE_PREFIX_SIZE: usize = 32;
+
+/// Blake2b hashers of the keys of Substrate storage maps. The `twox` hashers and the identity
+/// hasher aren't built over Blake2b, so they aren't supported.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StorageHasher {
+    /// The 16-byte Blake2b digest of the key.
+    Blake2_128,
+    /// The 16-byte Blake2b digest of the key followed by the key, which allows to iterate over
+    /// the keys of a map.
+    Blake2_128Concat,
+    /// The 32-byte Blake2b digest of the key.
+    Blake2_256,
+}
+
+/// Selectors and columns for the Substrate chip implementation.
+#[derive(Clone, Debug)]
+pub struct SubstrateConfig {
+    /// Config of the Blake2b chip, which computes all the hashes.
+    blake2b_config: Blake2bConfig,
+    /// Columns for the limbs, shared with the Blake2b chip.
+    pub limbs: [Column<Advice>; 8],
+}
+
+/// This is the chip for the Blake2b hashers of Substrate, which are unkeyed Blake2b digests of
+/// 16 or 32 bytes of the SCALE encoding of a key. The storage key of an item of a map is the prefix
+/// of the map, which is known at circuit building time, followed by the hash of the key of the
+/// item, so a circuit can prove which storage key holds the value of a private key.
+#[derive(Clone, Debug)]
+pub struct SubstrateChip<F: PrimeField> {
+    config: SubstrateConfig,
+    blake2b_chip: Blake2bChip<F>,
+}
+
+impl<F: PrimeField> Chip<F> for SubstrateChip<F> {
+    type Config = SubstrateConfig;
+    type Loaded = ();
+
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+impl<F: PrimeField> SubstrateChip<F> {
+    /// Generation of a fresh circuit from a configuration.
+    pub fn new(config: &SubstrateConfig) -> Self {
+        Self {
+            config: config.clone(),
+            blake2b_chip: Blake2bChip::new(&config.blake2b_config),
+        }
+    }
+
+    /// Configuration of the circuit, which is the configuration of the Blake2b chip. It should be
+    /// called in the configuration of the user circuit before instantiating the Substrate gadgets.
+    ///
+    /// Note: as in the Blake2b chip, this function enables equality on all necessary columns,
+    /// i.e., it should not be done manually before calling this function.
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        constants: Column<Fixed>,
+        full_number_u64: Column<Advice>,
+        limbs: [Column<Advice>; NB_BLAKE2B_ADVICE_COLS - 1],
+    ) -> <Self as Chip<F>>::Config {
+        let blake2b_config = Blake2bChip::configure(meta, constants, full_number_u64, limbs);
+        SubstrateConfig {
+            blake2b_config,
+            limbs,
+        }
+    }
+
+    /// Loading the tables used in the chip, which are the ones of the Blake2b chip.
+    pub fn load(&self, layouter: &mut impl Layouter<F>) -> Result<(), Error> {
+        self.blake2b_chip.load(layouter)
+    }
+
+    /// Computes the `blake2_128` hasher of the input, its 16-byte Blake2b digest. The 'input'
+    /// cells should be filled with byte values, and their amount must be known at circuit
+    /// building time.
+    pub fn blake2_128(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        input: &[AssignedNative<F>],
+    ) -> Result<[AssignedByte<F>; 16], Error> {
+        self.blake2b_chip.hash_to_array(layouter, input)
+    }
+
+    /// Computes the `blake2_256` hasher of the input, its 32-byte Blake2b digest.
+    pub fn blake2_256(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        input: &[AssignedNative<F>],
+    ) -> Result<[AssignedByte<F>; 32], Error> {
+        self.blake2b_chip.hash_to_array(layouter, input)
+    }
+
+    /// Computes the `blake2_128_concat` hasher of the input, its 16-byte Blake2b digest followed
+    /// by the input itself. The input cells are range-checked by the hash, so every returned cell
+    /// holds a byte.
+    pub fn blake2_128_concat(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        input: &[AssignedNative<F>],
+    ) -> Result<Vec<AssignedNative<F>>, Error> {
+        let mut output: Vec<AssignedNative<F>> =
+            self.blake2_128(layouter, input)?.iter().map(AssignedByte::to_native).collect();
+        output.extend_from_slice(input);
+        Ok(output)
+    }
+
+    /// Computes the given hasher of the SCALE-encoded 'key'.
+    pub fn hash_key(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        hasher: StorageHasher,
+        key: &[AssignedNative<F>],
+    ) -> Result<Vec<AssignedNative<F>>, Error> {
+        let to_native =
+            |digest: &[AssignedByte<F>]| digest.iter().map(AssignedByte::to_native).collect();
+        Ok(match hasher {
+            StorageHasher::Blake2_128 => to_native(&self.blake2_128(layouter, key)?),
+            StorageHasher::Blake2_128Concat => self.blake2_128_concat(layouter, key)?,
+            StorageHasher::Blake2_256 => to_native(&self.blake2_256(layouter, key)?),
+        })
+    }
+
+    /// Returns the storage key of the item of a map with the given SCALE-encoded 'key', which is
+    /// the 32-byte 'prefix' of the map followed by the hash of the key. The prefix, which is the
+    /// `twox_128` hash of the pallet name followed by the one of the storage item name, is
+    /// computed outside the circuit and becomes part of its constants.
+    pub fn storage_map_key(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        prefix: &[u8; STORAGE_PREFIX_SIZE],
+        hasher: StorageHasher,
+        key: &[AssignedNative<F>],
+    ) -> Result<Vec<AssignedNative<F>>, Error> {
+        let mut storage_key = self.blake2b_chip.assign_constant_bytes(layouter, prefix)?;
+        storage_key.extend(self.hash_key(layouter, hasher, key)?);
+        Ok(storage_key)
+    }
+}
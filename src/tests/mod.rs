@@ -1,6 +1,8 @@
 use super::*;
 use midnight_curves::bls12_381::Fq;
 use ff::Field;
+use midnight_proofs::dev::MockProver;
+use midnight_proofs::plonk::Circuit;
 use std::marker::PhantomData;
 use crate::base_operations::{
     create_limb_decomposition_gate, create_range_check_gate, generate_row_from_word_and_keep_row,
@@ -10,8 +12,17 @@ use crate::types::AssignedNative;
 use crate::types::blake2b_word::Blake2bWord;
 use crate::types::row::AssignedRow;
 
+mod test_argon2;
 mod test_blake2b;
+mod test_blake2s;
+mod test_blake3;
+mod test_cardano;
+mod test_equihash;
+mod test_merkle;
 mod test_negate;
+mod test_sha512;
+mod test_substrate;
+mod test_uint64;
 mod tests_addition;
 mod tests_rotation;
 mod tests_xor;
@@ -35,6 +46,35 @@ where
     Value::known(F::from_u128(number.into()))
 }
 
+/// Bytes of the given length used as inputs of the tests, the byte 'i' is 'i * seed + 1'
+pub(crate) fn bytes_of_length(length: usize, seed: usize) -> Vec<u8> {
+    (0..length).map(|i| (i * seed + 1) as u8).collect()
+}
+
+pub(crate) fn values(bytes: &[u8]) -> Vec<Value<Fq>> {
+    bytes.iter().map(|byte| value_for(*byte)).collect()
+}
+
+pub(crate) fn bytes_to_fields(bytes: &[u8]) -> Vec<Fq> {
+    bytes.iter().map(|byte| Fq::from(*byte as u64)).collect()
+}
+
+/// Runs the MockProver over a circuit of 2^17 rows whose public inputs are the bytes of the
+/// expected output, an element per byte, and checks that all the constraints are satisfied.
+pub(crate) fn verify_circuit<C: Circuit<Fq>>(circuit: &C, expected_output: &[u8]) {
+    verify_circuit_with_public_inputs(17, circuit, vec![bytes_to_fields(expected_output)]);
+}
+
+/// Same as [verify_circuit], for circuits of 2^k rows with any public inputs.
+pub(crate) fn verify_circuit_with_public_inputs<C: Circuit<Fq>>(
+    k: u32,
+    circuit: &C,
+    public_inputs: Vec<Vec<Fq>>,
+) {
+    let prover = MockProver::run(k, circuit, public_inputs).unwrap();
+    prover.verify().unwrap();
+}
+
 pub(crate) fn generate_row_8bits<T, F>(number: T) -> [Value<F>; 9]
 where
     F: PrimeField,
@@ -0,0 +1,92 @@
+use super::*;
+use crate::argon2::argon2_chip::{Argon2Chip, Argon2Config};
+use crate::argon2::params::Argon2Params;
+use midnight_proofs::circuit::SimpleFloorPlanner;
+use midnight_proofs::plonk::{Circuit, Instance};
+use std::array;
+
+/// Circuit that computes the Argon2id tag of a private password and a salt, and constrains it to
+/// equal the public inputs.
+#[derive(Clone, Debug)]
+pub(crate) struct Argon2Circuit<F: PrimeField> {
+    password: Vec<Value<F>>,
+    salt: Vec<Value<F>>,
+    params: Argon2Params,
+}
+
+impl<F: PrimeField> Argon2Circuit<F> {
+    pub(crate) fn new(password: Vec<Value<F>>, salt: Vec<Value<F>>, params: Argon2Params) -> Self {
+        Self {
+            password,
+            salt,
+            params,
+        }
+    }
+
+    /// The inputs are stored in the limb columns, eight bytes per row.
+    fn assign_inputs_to_the_trace(
+        config: &Argon2Config,
+        layouter: &mut impl Layouter<F>,
+        input: &[Value<F>],
+    ) -> Result<Vec<AssignedNative<F>>, Error> {
+        layouter.assign_region(
+            || "Inputs",
+            |mut region| {
+                input
+                    .iter()
+                    .enumerate()
+                    .map(|(index, input_byte)| {
+                        region.assign_advice(
+                            || "Input byte",
+                            config.limbs[index % 8],
+                            index / 8,
+                            || *input_byte,
+                        )
+                    })
+                    .collect()
+            },
+        )
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for Argon2Circuit<F> {
+    type Config = (Argon2Config, Column<Instance>);
+    type Params = ();
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            password: vec![Value::unknown(); self.password.len()],
+            salt: vec![Value::unknown(); self.salt.len()],
+            params: self.params,
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let full_number_u64 = meta.advice_column();
+        let limbs: [Column<Advice>; 8] = array::from_fn(|_| meta.advice_column());
+        let constant_col = meta.fixed_column();
+        let expected_tag = meta.instance_column();
+        meta.enable_equality(expected_tag);
+        (Argon2Chip::configure(meta, constant_col, full_number_u64, limbs), expected_tag)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let assigned_password =
+            Self::assign_inputs_to_the_trace(&config.0, &mut layouter, &self.password)?;
+        let assigned_salt = Self::assign_inputs_to_the_trace(&config.0, &mut layouter, &self.salt)?;
+
+        let chip = Argon2Chip::new(&config.0);
+        chip.load(&mut layouter)?;
+        let tag = chip.hash(&mut layouter, &assigned_password, &assigned_salt, &self.params)?;
+
+        for (i, tag_byte_cell) in tag.iter().enumerate() {
+            layouter.constrain_instance(tag_byte_cell.cell(), config.1, i)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,92 @@
+use super::*;
+use crate::argon2::params::Argon2Params;
+use crate::tests::test_argon2::argon2_circuit::Argon2Circuit;
+use ::argon2::{Algorithm, Argon2, Params, Version};
+
+const PASSWORD: &[u8] = b"correct horse battery staple";
+const SALT: &[u8] = b"somesaltsomesalt";
+
+#[test]
+fn test_argon2id_with_the_minimum_memory_matches_the_reference() {
+    run_argon2_test(PASSWORD, SALT, Argon2Params::new(8, 1, 32));
+}
+
+#[test]
+fn test_argon2id_with_several_iterations_matches_the_reference() {
+    // From the second pass on, the reference blocks are chosen from the whole memory and the new
+    // blocks are xored with the ones they replace
+    run_argon2_test(PASSWORD, SALT, Argon2Params::new(8, 2, 32));
+}
+
+#[test]
+fn test_argon2id_with_a_memory_size_that_is_not_a_multiple_of_4_matches_the_reference() {
+    // 13 KiB of memory size use 12 blocks, but the initial hash takes the given size
+    run_argon2_test(PASSWORD, SALT, Argon2Params::new(13, 1, 32));
+}
+
+#[test]
+fn test_argon2id_with_a_tag_longer_than_a_blake2b_digest_matches_the_reference() {
+    run_argon2_test(b"", SALT, Argon2Params::new(8, 1, 72));
+}
+
+#[test]
+fn test_argon2id_with_a_short_tag_matches_the_reference() {
+    run_argon2_test(PASSWORD, &[0xAB; 8], Argon2Params::new(8, 1, 4));
+}
+
+#[test]
+#[should_panic]
+fn test_argon2id_fails_with_a_wrong_tag() {
+    let params = Argon2Params::new(8, 1, 32);
+    let mut expected_tag = reference_tag(PASSWORD, SALT, &params);
+    expected_tag[0] ^= 1;
+    verify_circuit_with_public_inputs(
+        18,
+        &argon2_circuit(PASSWORD, SALT, params),
+        vec![bytes_to_fields(&expected_tag)],
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_argon2id_fails_with_a_wrong_password() {
+    let params = Argon2Params::new(8, 1, 32);
+    let expected_tag = reference_tag(b"correct horse battery stapler", SALT, &params);
+    verify_circuit_with_public_inputs(
+        18,
+        &argon2_circuit(PASSWORD, SALT, params),
+        vec![bytes_to_fields(&expected_tag)],
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_argon2id_params_should_have_at_least_8_kib_of_memory() {
+    Argon2Params::new(7, 1, 32);
+}
+
+fn run_argon2_test(password: &[u8], salt: &[u8], params: Argon2Params) {
+    let expected_tag = reference_tag(password, salt, &params);
+    verify_circuit_with_public_inputs(
+        18,
+        &argon2_circuit(password, salt, params),
+        vec![bytes_to_fields(&expected_tag)],
+    );
+}
+
+/// Argon2id tag computed by the reference implementation of the `argon2` crate
+fn reference_tag(password: &[u8], salt: &[u8], params: &Argon2Params) -> Vec<u8> {
+    let reference_params =
+        Params::new(params.memory_size, params.iterations, 1, Some(params.tag_length)).unwrap();
+    let mut tag = vec![0u8; params.tag_length];
+    Argon2::new(Algorithm::Argon2id, Version::V0x13, reference_params)
+        .hash_password_into(password, salt, &mut tag)
+        .unwrap();
+    tag
+}
+
+fn argon2_circuit(password: &[u8], salt: &[u8], params: Argon2Params) -> Argon2Circuit<Fq> {
+    let password = password.iter().map(|byte| value_for(*byte)).collect();
+    let salt = salt.iter().map(|byte| value_for(*byte)).collect();
+    Argon2Circuit::new(password, salt, params)
+}
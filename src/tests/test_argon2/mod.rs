@@ -0,0 +1,4 @@
+use super::*;
+
+mod argon2_circuit;
+mod argon2_tests;
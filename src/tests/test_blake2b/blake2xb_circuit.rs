@@ -0,0 +1,87 @@
+use super::*;
+use crate::blake2b::blake2b_chip::{Blake2bChip, Blake2bConfig};
+use crate::blake2b::params::ParamBytes;
+use crate::usage_utils::blake2b_circuit::Blake2bCircuit;
+use midnight_proofs::circuit::SimpleFloorPlanner;
+use midnight_proofs::plonk::{Circuit, Instance};
+use std::array;
+
+/// Circuit that computes 'output_size' bytes of Blake2Xb of an input and a key, with an optional
+/// constant personalization.
+#[derive(Clone, Debug)]
+pub(crate) struct Blake2xbCircuit<F: PrimeField> {
+    input: Vec<Value<F>>,
+    key: Vec<Value<F>>,
+    personalization: Option<[u8; 16]>,
+    output_size: usize,
+}
+
+impl<F: PrimeField> Blake2xbCircuit<F> {
+    pub(crate) fn new(
+        input: Vec<Value<F>>,
+        key: Vec<Value<F>>,
+        personalization: Option<[u8; 16]>,
+        output_size: usize,
+    ) -> Self {
+        Self {
+            input,
+            key,
+            personalization,
+            output_size,
+        }
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for Blake2xbCircuit<F> {
+    type Config = (Blake2bConfig, Column<Instance>);
+    type Params = ();
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            input: vec![Value::unknown(); self.input.len()],
+            key: vec![Value::unknown(); self.key.len()],
+            ..self.clone()
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let full_number_u64 = meta.advice_column();
+        let limbs: [Column<Advice>; 8] = array::from_fn(|_| meta.advice_column());
+        let constant_col = meta.fixed_column();
+        let expected_output = meta.instance_column();
+        meta.enable_equality(expected_output);
+        (Blake2bChip::configure(meta, constant_col, full_number_u64, limbs), expected_output)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let assigned_input = Blake2bCircuit::assign_inputs_to_the_trace(
+            config.0.clone(),
+            &mut layouter,
+            &self.input,
+        )?;
+        let assigned_key =
+            Blake2bCircuit::assign_inputs_to_the_trace(config.0.clone(), &mut layouter, &self.key)?;
+        let personalization = self.personalization.map(ParamBytes::Constant);
+
+        let chip = Blake2bChip::new(&config.0);
+        chip.load(&mut layouter)?;
+        let result = chip.hash_blake2xb(
+            &mut layouter,
+            &assigned_input,
+            &assigned_key,
+            self.output_size,
+            None,
+            personalization.as_ref(),
+        )?;
+
+        for (i, output_byte_cell) in result.iter().enumerate() {
+            layouter.constrain_instance(output_byte_cell.cell(), config.1, i)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,93 @@
+use super::*;
+use crate::tests::test_blake2b::blake2xb_circuit::Blake2xbCircuit;
+
+#[test]
+fn test_blake2xb_with_a_single_byte_of_output_matches_the_reference() {
+    run_blake2xb_test(&bytes_of_length(50, 3), &[], None, 1);
+}
+
+#[test]
+fn test_blake2xb_with_a_single_output_node_matches_the_reference() {
+    run_blake2xb_test(&bytes_of_length(50, 3), &[], None, 64);
+}
+
+#[test]
+fn test_blake2xb_of_an_empty_input_matches_the_reference() {
+    run_blake2xb_test(&[], &[], None, 129);
+}
+
+#[test]
+fn test_blake2xb_with_key_and_personalization_matches_the_reference() {
+    let key = bytes_of_length(64, 7);
+    run_blake2xb_test(&bytes_of_length(200, 3), &key, Some(*b"ZcashPrevoutHash"), 200);
+}
+
+#[test]
+#[should_panic]
+fn test_blake2xb_fails_with_the_prefix_of_a_longer_output() {
+    let input = bytes_of_length(50, 3);
+    let expected_output = reference_blake2xb(&input, &[], None, 100);
+    verify_circuit(&blake2xb_circuit(&input, &[], None, 64), &expected_output[..64]);
+}
+
+#[test]
+#[should_panic(expected = "XOF output size must be between 1 and 2^32 - 2 bytes")]
+fn test_blake2xb_should_receive_a_non_empty_output_size() {
+    verify_circuit(&blake2xb_circuit(&[], &[], None, 0), &[]);
+}
+
+fn run_blake2xb_test(
+    input: &[u8],
+    key: &[u8],
+    personalization: Option<[u8; 16]>,
+    output_size: usize,
+) {
+    let expected_output = reference_blake2xb(input, key, personalization, output_size);
+    verify_circuit(&blake2xb_circuit(input, key, personalization, output_size), &expected_output);
+}
+
+/// Blake2Xb as defined in the BLAKE2X specification, built from the parameters of the reference
+/// implementation of Blake2b
+fn reference_blake2xb(
+    input: &[u8],
+    key: &[u8],
+    personalization: Option<[u8; 16]>,
+    output_size: usize,
+) -> Vec<u8> {
+    let personalization = personalization.unwrap_or_default();
+    let xof_length = (output_size as u64) << 32;
+    let root_digest = blake2b_simd::Params::new()
+        .key(key)
+        .personal(&personalization)
+        .node_offset(xof_length)
+        .hash(input);
+    (0..output_size.div_ceil(64))
+        .flat_map(|node_offset| {
+            blake2b_simd::Params::new()
+                .hash_length(64.min(output_size - node_offset * 64))
+                .personal(&personalization)
+                .fanout(0)
+                .max_depth(0)
+                .max_leaf_length(64)
+                .node_offset(xof_length | node_offset as u64)
+                .inner_hash_length(64)
+                .hash(root_digest.as_bytes())
+                .as_bytes()
+                .to_vec()
+        })
+        .collect()
+}
+
+fn blake2xb_circuit(
+    input: &[u8],
+    key: &[u8],
+    personalization: Option<[u8; 16]>,
+    output_size: usize,
+) -> Blake2xbCircuit<Fq> {
+    Blake2xbCircuit::new(
+        input.iter().map(|x| value_for(*x)).collect(),
+        key.iter().map(|x| value_for(*x)).collect(),
+        personalization,
+        output_size,
+    )
+}
@@ -0,0 +1,41 @@
+use crate::usage_utils::circuit_runner::Bn256CircuitRunner;
+use crate::tests::test_blake2b::vector_tests::obtain_test_cases;
+
+fn run_test(input: &String, key: &String, expected: &String) {
+    Bn256CircuitRunner::mocked_preprocess_inputs_synthesize_prove_and_verify(input, key, expected);
+}
+
+#[test]
+fn test_hashes_in_circuit_over_bn256() {
+    let test_cases = obtain_test_cases();
+
+    // A representative subset of the vectors: unkeyed and keyed messages around the block
+    // boundaries
+    for i in [0, 1, 127, 128, 129, 255, 256, 257, 383, 384, 385, 511] {
+        let case = &test_cases[i];
+        println!("Running test case {}", i);
+        run_test(&case.input, &case.key, &case.out);
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_wrong_hash_in_circuit_over_bn256() {
+    let input = String::from("0001");
+    let out = String::from("2c08798dc641aba9dee435e22519a4729a09b2bfe0ff00ef2dcd8ed6f8a07d15eaf4aee52bbf18ab5608a6190f70b90486c8a7d4873710b1115d3debbb4327b5");
+    let key = String::from("");
+
+    run_test(&input, &key, &out);
+}
+
+#[test]
+fn test_with_real_snark_over_bn256() {
+    let input = String::from("0001");
+    let out = String::from("1c08798dc641aba9dee435e22519a4729a09b2bfe0ff00ef2dcd8ed6f8a07d15eaf4aee52bbf18ab5608a6190f70b90486c8a7d4873710b1115d3debbb4327b5");
+    let key = String::from("");
+
+    assert!(Bn256CircuitRunner::real_preprocess_inputs_synthesize_prove_and_verify(
+        input, out, key
+    )
+    .is_ok());
+}
@@ -0,0 +1,101 @@
+use super::*;
+use crate::blake2b::blake2b_chip::{Blake2bChip, Blake2bConfig};
+use crate::usage_utils::blake2b_circuit::Blake2bCircuit;
+use midnight_proofs::circuit::SimpleFloorPlanner;
+use midnight_proofs::plonk::{Circuit, Instance};
+use std::array;
+
+/// Circuit that chains calls to the standalone compression function, starting from the given
+/// chaining value. Every block comes with its counter, and only the last block is compressed with
+/// the final block flag. The resulting chaining value is constrained to the instance column.
+#[derive(Clone, Debug)]
+pub(crate) struct CompressionCircuit<F: PrimeField> {
+    chaining_value: Vec<Value<F>>,
+    blocks: Vec<Vec<Value<F>>>,
+    counters: Vec<Value<F>>,
+    is_last_node: bool,
+}
+
+impl<F: PrimeField> CompressionCircuit<F> {
+    pub(crate) fn new(
+        chaining_value: Vec<Value<F>>,
+        blocks: Vec<Vec<Value<F>>>,
+        counters: Vec<Value<F>>,
+        is_last_node: bool,
+    ) -> Self {
+        Self {
+            chaining_value,
+            blocks,
+            counters,
+            is_last_node,
+        }
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for CompressionCircuit<F> {
+    type Config = (Blake2bConfig, Column<Instance>);
+    type Params = ();
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            chaining_value: vec![Value::unknown(); 8],
+            blocks: vec![vec![Value::unknown(); 16]; self.blocks.len()],
+            counters: vec![Value::unknown(); self.counters.len()],
+            is_last_node: self.is_last_node,
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let full_number_u64 = meta.advice_column();
+        let limbs: [Column<Advice>; 8] = array::from_fn(|_| meta.advice_column());
+        let constant_col = meta.fixed_column();
+        let expected_chaining_value = meta.instance_column();
+        meta.enable_equality(expected_chaining_value);
+        (
+            Blake2bChip::configure(meta, constant_col, full_number_u64, limbs),
+            expected_chaining_value,
+        )
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let chip = Blake2bChip::new(&config.0);
+        chip.load(&mut layouter)?;
+
+        let mut chaining_value: [AssignedNative<F>; 8] =
+            Blake2bCircuit::assign_inputs_to_the_trace(
+                config.0.clone(),
+                &mut layouter,
+                &self.chaining_value,
+            )?
+            .try_into()
+            .unwrap();
+        for (index, (block, counter)) in self.blocks.iter().zip(self.counters.iter()).enumerate() {
+            let assigned_block =
+                Blake2bCircuit::assign_inputs_to_the_trace(config.0.clone(), &mut layouter, block)?;
+            let assigned_counter = Blake2bCircuit::assign_inputs_to_the_trace(
+                config.0.clone(),
+                &mut layouter,
+                &[*counter],
+            )?;
+            let is_last_block = index == self.blocks.len() - 1;
+            chaining_value = chip.compress_words(
+                &mut layouter,
+                &chaining_value,
+                &assigned_block.try_into().unwrap(),
+                &assigned_counter[0],
+                is_last_block,
+                is_last_block && self.is_last_node,
+            )?;
+        }
+
+        for (i, word) in chaining_value.iter().enumerate() {
+            layouter.constrain_instance(word.cell(), config.1, i)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,79 @@
+use super::*;
+use crate::blake2b::utils::IV_CONSTANTS;
+use crate::tests::test_blake2b::compression_circuit::CompressionCircuit;
+use blake2_rfc::blake2b::blake2b;
+
+#[test]
+fn test_compression_of_a_single_block_yields_the_digest() {
+    let message = bytes_of_length(100, 3);
+    let expected_output = blake2b(64, &[], &message);
+    run_compression_test(&message, false, expected_output.as_bytes());
+}
+
+#[test]
+fn test_chained_compressions_yield_the_digest() {
+    let message = bytes_of_length(300, 3);
+    let expected_output = blake2b(64, &[], &message);
+    run_compression_test(&message, false, expected_output.as_bytes());
+}
+
+#[test]
+fn test_compression_with_the_last_node_flag_yields_the_digest_of_a_last_node() {
+    let message = bytes_of_length(100, 3);
+    let expected_output = blake2b_simd::Params::new().last_node(true).hash(&message);
+    run_compression_test(&message, true, expected_output.as_bytes());
+}
+
+#[test]
+#[should_panic]
+fn test_compression_fails_with_a_wrong_counter() {
+    let message = bytes_of_length(100, 3);
+    let expected_output = blake2b(64, &[], &message);
+    let (chaining_value, blocks, mut counters) = compression_inputs(&message);
+    counters[0] = value_for(99u64);
+    let circuit = CompressionCircuit::new(chaining_value, blocks, counters, false);
+    verify_circuit_with_public_inputs(
+        17,
+        &circuit,
+        vec![chaining_value_fields(expected_output.as_bytes())],
+    );
+}
+
+fn run_compression_test(message: &[u8], is_last_node: bool, expected_output: &[u8]) {
+    let (chaining_value, blocks, counters) = compression_inputs(message);
+    let circuit = CompressionCircuit::new(chaining_value, blocks, counters, is_last_node);
+    verify_circuit_with_public_inputs(17, &circuit, vec![chaining_value_fields(expected_output)]);
+}
+
+type CompressionInputs = (Vec<Value<Fq>>, Vec<Vec<Value<Fq>>>, Vec<Value<Fq>>);
+
+/// Builds the initial chaining value of an unkeyed hash of 64 bytes, the blocks of the padded
+/// message and the counter of each block
+fn compression_inputs(message: &[u8]) -> CompressionInputs {
+    let mut chaining_value = IV_CONSTANTS;
+    chaining_value[0] ^= 0x01010040;
+    let blocks_count = message.len().div_ceil(128).max(1);
+    let mut padded_message = message.to_vec();
+    padded_message.resize(blocks_count * 128, 0);
+    let blocks = padded_message
+        .chunks(128)
+        .map(|block| {
+            block
+                .chunks(8)
+                .map(|word| value_for(u64::from_le_bytes(word.try_into().unwrap())))
+                .collect()
+        })
+        .collect();
+    let counters = (0..blocks_count)
+        .map(|index| value_for(((index + 1) * 128).min(message.len()) as u64))
+        .collect();
+    (chaining_value.iter().map(|word| value_for(*word)).collect(), blocks, counters)
+}
+
+/// The chaining value is exposed as 8 words, the bytes of the digest in little-endian order
+fn chaining_value_fields(expected_output: &[u8]) -> Vec<Fq> {
+    expected_output
+        .chunks(8)
+        .map(|word| Fq::from(u64::from_le_bytes(word.try_into().unwrap())))
+        .collect()
+}
@@ -0,0 +1,108 @@
+use super::*;
+use crate::blake2b::packing::DigestPacking;
+use crate::tests::test_blake2b::vector_tests::obtain_test_cases;
+use crate::usage_utils::circuit_runner::{Bn256CircuitRunner, CircuitRunner};
+use blake2b_simd::Params;
+
+#[test]
+fn test_digest_packed_in_words_matches_the_vectors() {
+    let test_cases = obtain_test_cases();
+    for i in [0, 3, 256, 300] {
+        let case = &test_cases[i];
+        CircuitRunner::mocked_preprocess_inputs_synthesize_prove_and_verify_with_digest_packing(
+            &case.input,
+            &case.key,
+            &case.out,
+            DigestPacking::Words,
+        );
+    }
+}
+
+#[test]
+fn test_digest_packed_in_field_elements_matches_the_vectors() {
+    let test_cases = obtain_test_cases();
+    for i in [0, 3, 256, 300] {
+        let case = &test_cases[i];
+        CircuitRunner::mocked_preprocess_inputs_synthesize_prove_and_verify_with_digest_packing(
+            &case.input,
+            &case.key,
+            &case.out,
+            DigestPacking::FieldElements,
+        );
+    }
+}
+
+#[test]
+fn test_digest_packed_in_field_elements_over_bn256() {
+    let case = &obtain_test_cases()[129];
+    Bn256CircuitRunner::mocked_preprocess_inputs_synthesize_prove_and_verify_with_digest_packing(
+        &case.input,
+        &case.key,
+        &case.out,
+        DigestPacking::FieldElements,
+    );
+}
+
+#[test]
+fn test_packing_sizes_of_the_public_inputs() {
+    let digest = [0xffu8; 64];
+    assert_eq!(DigestPacking::Bytes.pack::<Fq>(&digest).len(), 64);
+    assert_eq!(DigestPacking::Words.pack::<Fq>(&digest).len(), 8);
+    assert_eq!(DigestPacking::FieldElements.pack::<Fq>(&digest).len(), 3);
+    assert_eq!(DigestPacking::FieldElements.pack::<Fq>(&digest[..32]).len(), 2);
+    assert_eq!(DigestPacking::Words.pack::<Fq>(&digest[..8]), vec![Fq::from(u64::MAX)]);
+}
+
+#[test]
+fn test_32_byte_digest_packed_in_two_field_elements() {
+    let input = bytes_of_length(200, 3);
+    let digest = Params::new().hash_length(32).hash(&input).as_bytes().to_vec();
+    run_packed_digest_test(&input, 32, DigestPacking::FieldElements, &digest);
+}
+
+#[test]
+fn test_digest_of_an_odd_size_packed_in_words() {
+    let input = bytes_of_length(20, 3);
+    let digest = Params::new().hash_length(20).hash(&input).as_bytes().to_vec();
+    run_packed_digest_test(&input, 20, DigestPacking::Words, &digest);
+}
+
+#[test]
+#[should_panic]
+fn test_packed_digest_fails_with_another_digest() {
+    let input = bytes_of_length(200, 3);
+    let mut digest = Params::new().hash_length(32).hash(&input).as_bytes().to_vec();
+    digest[31] ^= 1;
+    run_packed_digest_test(&input, 32, DigestPacking::FieldElements, &digest);
+}
+
+#[test]
+#[should_panic]
+fn test_packed_digest_fails_with_the_digest_packed_in_another_way() {
+    let input = bytes_of_length(200, 3);
+    let digest = Params::new().hash_length(32).hash(&input).as_bytes().to_vec();
+    let circuit = CircuitRunner::create_circuit_for_inputs(values(&input), 200, vec![], 0, 32)
+        .with_digest_packing(DigestPacking::Words);
+    let public_inputs = DigestPacking::FieldElements.pack::<Fq>(&digest);
+    let prover = CircuitRunner::mock_prove_with_public_inputs_ref(&public_inputs, &circuit);
+    CircuitRunner::verify_mock_prover(prover);
+}
+
+fn run_packed_digest_test(
+    input: &[u8],
+    output_size: usize,
+    digest_packing: DigestPacking,
+    expected_digest: &[u8],
+) {
+    let circuit = CircuitRunner::create_circuit_for_inputs(
+        values(input),
+        input.len(),
+        vec![],
+        0,
+        output_size,
+    )
+    .with_digest_packing(digest_packing);
+    let public_inputs = digest_packing.pack::<Fq>(expected_digest);
+    let prover = CircuitRunner::mock_prove_with_public_inputs_ref(&public_inputs, &circuit);
+    CircuitRunner::verify_mock_prover(prover);
+}
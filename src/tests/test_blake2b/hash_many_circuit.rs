@@ -0,0 +1,95 @@
+use super::*;
+use crate::blake2b::blake2b_chip::{Blake2bChip, Blake2bConfig};
+use crate::usage_utils::blake2b_circuit::Blake2bCircuit;
+use midnight_proofs::circuit::SimpleFloorPlanner;
+use midnight_proofs::plonk::{Circuit, Instance};
+use std::array;
+
+/// Circuit that hashes several messages with the same key in a single call to
+/// [Blake2bChip::hash_many], or in a call to [Blake2bChip::hash] per message to compare their
+/// costs, and constrains the digests, one after the other, to equal the public inputs.
+#[derive(Clone, Debug)]
+pub(crate) struct HashManyCircuit<F: PrimeField> {
+    inputs: Vec<Vec<Value<F>>>,
+    key: Vec<Value<F>>,
+    output_size: usize,
+    separate_hashes: bool,
+}
+
+impl<F: PrimeField> HashManyCircuit<F> {
+    pub(crate) fn new(inputs: Vec<Vec<Value<F>>>, key: Vec<Value<F>>, output_size: usize) -> Self {
+        Self {
+            inputs,
+            key,
+            output_size,
+            separate_hashes: false,
+        }
+    }
+
+    /// The same circuit, hashing every message in its own call to [Blake2bChip::hash].
+    pub(crate) fn with_separate_hashes(self) -> Self {
+        Self {
+            separate_hashes: true,
+            ..self
+        }
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for HashManyCircuit<F> {
+    type Config = (Blake2bConfig, Column<Instance>);
+    type Params = ();
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            inputs: self.inputs.iter().map(|input| vec![Value::unknown(); input.len()]).collect(),
+            key: vec![Value::unknown(); self.key.len()],
+            output_size: self.output_size,
+            separate_hashes: self.separate_hashes,
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let full_number_u64 = meta.advice_column();
+        let limbs: [Column<Advice>; 8] = array::from_fn(|_| meta.advice_column());
+        let constant_col = meta.fixed_column();
+        let expected_digests = meta.instance_column();
+        meta.enable_equality(expected_digests);
+        (Blake2bChip::configure(meta, constant_col, full_number_u64, limbs), expected_digests)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let assigned_inputs = self
+            .inputs
+            .iter()
+            .map(|input| {
+                Blake2bCircuit::assign_inputs_to_the_trace(config.0.clone(), &mut layouter, input)
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        let assigned_key =
+            Blake2bCircuit::assign_inputs_to_the_trace(config.0.clone(), &mut layouter, &self.key)?;
+
+        let chip = Blake2bChip::new(&config.0);
+        chip.load(&mut layouter)?;
+        let digests = if self.separate_hashes {
+            assigned_inputs
+                .iter()
+                .map(|input| {
+                    chip.hash(&mut layouter, input, &assigned_key, self.output_size, None, None)
+                })
+                .collect::<Result<Vec<_>, Error>>()?
+        } else {
+            chip.hash_many(&mut layouter, &assigned_inputs, &assigned_key, self.output_size)?
+        };
+
+        let digest_bytes = digests.iter().flat_map(|digest| digest.iter().take(self.output_size));
+        for (i, digest_byte_cell) in digest_bytes.enumerate() {
+            layouter.constrain_instance(digest_byte_cell.cell(), config.1, i)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,68 @@
+use super::*;
+use crate::tests::test_blake2b::hash_many_circuit::HashManyCircuit;
+use blake2b_simd::Params;
+use midnight_proofs::dev::cost_model::circuit_model;
+use midnight_proofs::dev::MockProver;
+
+#[test]
+fn test_hash_many_matches_the_reference_for_messages_of_different_lengths() {
+    let inputs = [0, 3, 128, 129, 200].map(|length| bytes_of_length(length, 3)).to_vec();
+    run_hash_many_test(&inputs, &[], 32, &reference_digests(&inputs, &[], 32));
+}
+
+#[test]
+fn test_keyed_hash_many_matches_the_reference() {
+    let inputs = [10, 64, 256].map(|length| bytes_of_length(length, 5)).to_vec();
+    let key = bytes_of_length(32, 7);
+    run_hash_many_test(&inputs, &key, 64, &reference_digests(&inputs, &key, 64));
+}
+
+#[test]
+fn test_hash_many_of_a_single_message_matches_the_reference() {
+    let inputs = vec![bytes_of_length(150, 3)];
+    run_hash_many_test(&inputs, &[], 64, &reference_digests(&inputs, &[], 64));
+}
+
+#[test]
+#[should_panic]
+fn test_hash_many_fails_with_the_digests_in_another_order() {
+    let inputs = [20, 30].map(|length| bytes_of_length(length, 3)).to_vec();
+    let mut expected_digests = reference_digests(&inputs, &[], 32);
+    expected_digests.rotate_left(32);
+    run_hash_many_test(&inputs, &[], 32, &expected_digests);
+}
+
+#[test]
+fn test_hash_many_saves_the_constant_rows_of_every_message_but_the_first() {
+    // Every hash assigns a row with the iv constants and a row with the initial state
+    const CONSTANT_ROWS: usize = 2;
+    let inputs = [10, 20, 30, 40].map(|length| bytes_of_length(length, 3)).to_vec();
+    let circuit =
+        HashManyCircuit::new(inputs.iter().map(|input| values(input)).collect(), vec![], 32);
+    let rows = |circuit: &HashManyCircuit<Fq>| circuit_model::<Fq, 48, 32>(circuit).rows;
+    let hash_many_rows = rows(&circuit);
+    let separate_hashes_rows = rows(&circuit.with_separate_hashes());
+    assert_eq!(separate_hashes_rows - hash_many_rows, (inputs.len() - 1) * CONSTANT_ROWS);
+}
+
+/// Digests of the reference implementation, one after the other
+fn reference_digests(inputs: &[Vec<u8>], key: &[u8], output_size: usize) -> Vec<u8> {
+    inputs
+        .iter()
+        .flat_map(|input| {
+            Params::new().hash_length(output_size).key(key).hash(input).as_bytes().to_vec()
+        })
+        .collect()
+}
+
+fn run_hash_many_test(inputs: &[Vec<u8>], key: &[u8], output_size: usize, expected_digests: &[u8]) {
+    let circuit = HashManyCircuit::<Fq>::new(
+        inputs.iter().map(|input| values(input)).collect(),
+        values(key),
+        output_size,
+    );
+    let expected_digests: Vec<Fq> =
+        expected_digests.iter().map(|byte| Fq::from(*byte as u64)).collect();
+    let prover = MockProver::run(17, &circuit, vec![expected_digests]).unwrap();
+    prover.verify().unwrap();
+}
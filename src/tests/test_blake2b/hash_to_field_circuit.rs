@@ -0,0 +1,64 @@
+use super::*;
+use crate::blake2b::blake2b_chip::{Blake2bChip, Blake2bConfig};
+use crate::usage_utils::blake2b_circuit::Blake2bCircuit;
+use midnight_proofs::circuit::SimpleFloorPlanner;
+use midnight_proofs::plonk::{Circuit, Instance};
+use num_bigint::BigUint;
+use std::array;
+
+/// Circuit that hashes a message with a 64-byte digest and reduces the digest modulo the native
+/// field, with [Blake2bChip::hash_to_field], or modulo the given modulus, with
+/// [Blake2bChip::reduce_digest]. The result is constrained to equal the public input.
+#[derive(Clone, Debug)]
+pub(crate) struct HashToFieldCircuit<F: PrimeField> {
+    input: Vec<Value<F>>,
+    modulus: Option<BigUint>,
+}
+
+impl<F: PrimeField> HashToFieldCircuit<F> {
+    pub(crate) fn new(input: Vec<Value<F>>, modulus: Option<BigUint>) -> Self {
+        Self { input, modulus }
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for HashToFieldCircuit<F> {
+    type Config = (Blake2bConfig, Column<Instance>);
+    type Params = ();
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::new(vec![Value::unknown(); self.input.len()], self.modulus.clone())
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let full_number_u64 = meta.advice_column();
+        let limbs: [Column<Advice>; 8] = array::from_fn(|_| meta.advice_column());
+        let constant_col = meta.fixed_column();
+        let expected_element = meta.instance_column();
+        meta.enable_equality(expected_element);
+        let config = Blake2bChip::configure(meta, constant_col, full_number_u64, limbs);
+        (Blake2bChip::configure_hash_to_field(meta, &config), expected_element)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let assigned_input = Blake2bCircuit::assign_inputs_to_the_trace(
+            config.0.clone(),
+            &mut layouter,
+            &self.input,
+        )?;
+
+        let chip = Blake2bChip::new(&config.0);
+        chip.load(&mut layouter)?;
+        let digest = chip.hash(&mut layouter, &assigned_input, &[], 64, None, None)?;
+        let element = match &self.modulus {
+            None => chip.hash_to_field(&mut layouter, &digest)?,
+            Some(modulus) => chip.reduce_digest(&mut layouter, &digest, modulus)?,
+        };
+
+        layouter.constrain_instance(element.cell(), config.1, 0)
+    }
+}
@@ -0,0 +1,94 @@
+use super::*;
+use crate::tests::test_blake2b::hash_to_field_circuit::HashToFieldCircuit;
+use blake2b_simd::Params;
+use ff::FromUniformBytes;
+use midnight_curves::bn256::Fr;
+use midnight_proofs::dev::MockProver;
+use num_bigint::BigUint;
+
+/// Order of the prime subgroup of Ed25519, 2^252 + 27742317777372353535851937790883648493
+const ED25519_ORDER: &str =
+    "7237005577332262213973186563042994240857116359379907606001950938285454250989";
+
+#[test]
+fn test_hash_to_field_matches_from_uniform_bytes() {
+    for length in [0, 3, 128, 200] {
+        let input = bytes_of_length(length, 7);
+        let expected = Fq::from_uniform_bytes(&digest(&input));
+        run_hash_to_field_test(&input, None, expected);
+    }
+}
+
+#[test]
+fn test_hash_to_field_over_bn256_matches_from_uniform_bytes() {
+    let input = bytes_of_length(100, 7);
+    let expected = Fr::from_uniform_bytes(&digest(&input));
+    run_hash_to_field_test(&input, None, expected);
+}
+
+#[test]
+fn test_reduce_digest_modulo_the_bn256_scalar_field_matches_from_uniform_bytes() {
+    let modulus = BigUint::parse_bytes(&Fr::MODULUS.as_bytes()[2..], 16).unwrap();
+    for length in [0, 150] {
+        let input = bytes_of_length(length, 7);
+        let scalar = Fr::from_uniform_bytes(&digest(&input));
+        let expected = Fq::from_uniform_bytes(&wide_bytes(scalar.to_repr().as_ref()));
+        run_hash_to_field_test(&input, Some(modulus.clone()), expected);
+    }
+}
+
+#[test]
+fn test_reduce_digest_modulo_the_ed25519_order() {
+    let modulus = BigUint::parse_bytes(ED25519_ORDER.as_bytes(), 10).unwrap();
+    let input = bytes_of_length(64, 7);
+    let remainder = BigUint::from_bytes_le(&digest(&input)) % &modulus;
+    let expected = Fq::from_uniform_bytes(&wide_bytes(&remainder.to_bytes_le()));
+    run_hash_to_field_test(&input, Some(modulus), expected);
+}
+
+#[test]
+fn test_reduce_digest_modulo_a_single_word() {
+    let modulus = BigUint::from(u64::MAX - 58);
+    let input = bytes_of_length(10, 7);
+    let remainder = BigUint::from_bytes_le(&digest(&input)) % &modulus;
+    let expected = Fq::from(remainder.to_u64_digits()[0]);
+    run_hash_to_field_test(&input, Some(modulus), expected);
+}
+
+#[test]
+#[should_panic]
+fn test_hash_to_field_fails_with_the_big_endian_reduction() {
+    let input = bytes_of_length(50, 7);
+    let mut reversed_digest = digest(&input);
+    reversed_digest.reverse();
+    run_hash_to_field_test(&input, None, Fq::from_uniform_bytes(&reversed_digest));
+}
+
+#[test]
+#[should_panic]
+fn test_reduce_digest_fails_with_the_reduction_modulo_the_native_field() {
+    let modulus = BigUint::parse_bytes(ED25519_ORDER.as_bytes(), 10).unwrap();
+    let input = bytes_of_length(50, 7);
+    run_hash_to_field_test(&input, Some(modulus), Fq::from_uniform_bytes(&digest(&input)));
+}
+
+fn digest(input: &[u8]) -> [u8; 64] {
+    Params::new().hash_length(64).hash(input).as_bytes().try_into().unwrap()
+}
+
+/// Little-endian bytes of an integer, zero-padded to 64 bytes
+fn wide_bytes(bytes: &[u8]) -> [u8; 64] {
+    let mut wide = [0; 64];
+    wide[..bytes.len()].copy_from_slice(bytes);
+    wide
+}
+
+fn run_hash_to_field_test<F>(input: &[u8], modulus: Option<BigUint>, expected: F)
+where
+    F: PrimeField + FromUniformBytes<64> + Ord,
+{
+    let input = input.iter().map(|byte| value_for(*byte)).collect();
+    let circuit = HashToFieldCircuit::<F>::new(input, modulus);
+    let prover = MockProver::run(17, &circuit, vec![vec![expected]]).unwrap();
+    prover.verify().unwrap();
+}
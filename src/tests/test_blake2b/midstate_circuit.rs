@@ -0,0 +1,68 @@
+use super::*;
+use crate::blake2b::blake2b_chip::{Blake2bChip, Blake2bConfig};
+use crate::blake2b::midstate::Blake2bMidstate;
+use crate::usage_utils::blake2b_circuit::Blake2bCircuit;
+use midnight_proofs::circuit::SimpleFloorPlanner;
+use midnight_proofs::plonk::{Circuit, Instance};
+use std::array;
+
+/// Circuit that hashes an input after the public prefix of a [Blake2bMidstate].
+#[derive(Clone, Debug)]
+pub(crate) struct MidstateCircuit<F: PrimeField> {
+    midstate: Blake2bMidstate,
+    input: Vec<Value<F>>,
+    output_size: usize,
+}
+
+impl<F: PrimeField> MidstateCircuit<F> {
+    pub(crate) fn new(midstate: Blake2bMidstate, input: Vec<Value<F>>, output_size: usize) -> Self {
+        Self {
+            midstate,
+            input,
+            output_size,
+        }
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for MidstateCircuit<F> {
+    type Config = (Blake2bConfig, Column<Instance>);
+    type Params = ();
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            input: vec![Value::unknown(); self.input.len()],
+            ..self.clone()
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let full_number_u64 = meta.advice_column();
+        let limbs: [Column<Advice>; 8] = array::from_fn(|_| meta.advice_column());
+        let constant_col = meta.fixed_column();
+        let expected_final_state = meta.instance_column();
+        meta.enable_equality(expected_final_state);
+        (Blake2bChip::configure(meta, constant_col, full_number_u64, limbs), expected_final_state)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let assigned_input = Blake2bCircuit::assign_inputs_to_the_trace(
+            config.0.clone(),
+            &mut layouter,
+            &self.input,
+        )?;
+
+        let chip = Blake2bChip::new(&config.0);
+        chip.load(&mut layouter)?;
+        let result = chip.hash_with_midstate(&mut layouter, &self.midstate, &assigned_input)?;
+
+        for (i, global_state_byte_cell) in result.iter().enumerate().take(self.output_size) {
+            layouter.constrain_instance(global_state_byte_cell.cell(), config.1, i)?;
+        }
+        Ok(())
+    }
+}
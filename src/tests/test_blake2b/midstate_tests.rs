@@ -0,0 +1,68 @@
+use super::*;
+use crate::blake2b::midstate::Blake2bMidstate;
+use crate::blake2b::params::{Blake2bParams, ParamBytes};
+use crate::tests::test_blake2b::midstate_circuit::MidstateCircuit;
+use blake2_rfc::blake2b::blake2b;
+
+#[test]
+fn test_midstate_of_several_blocks_matches_the_reference() {
+    run_midstate_test(256, 50);
+}
+
+#[test]
+fn test_midstate_of_an_empty_prefix_matches_the_reference() {
+    run_midstate_test(0, 300);
+}
+
+#[test]
+fn test_midstate_followed_by_a_whole_block_matches_the_reference() {
+    run_midstate_test(128, 128);
+}
+
+#[test]
+fn test_midstate_with_personalization_matches_the_reference() {
+    let prefix = bytes_of_length(128, 3);
+    let input = bytes_of_length(40, 7);
+    let personalization = *b"ZcashPrevoutHash";
+    let expected_output = blake2b_simd::Params::new()
+        .hash_length(32)
+        .personal(&personalization)
+        .hash(&[prefix.clone(), input.clone()].concat());
+    let params =
+        Blake2bParams::<Fq>::new(32).personalization(ParamBytes::Constant(personalization));
+    let midstate = Blake2bMidstate::new(&params, &prefix);
+    verify_circuit(&midstate_circuit(midstate, &input, 32), expected_output.as_bytes());
+}
+
+#[test]
+#[should_panic]
+fn test_midstate_fails_with_the_digest_of_another_prefix() {
+    let prefix = bytes_of_length(128, 3);
+    let input = bytes_of_length(50, 7);
+    let other_prefix = bytes_of_length(128, 5);
+    let expected_output = blake2b(64, &[], &[other_prefix, input.clone()].concat());
+    let midstate = Blake2bMidstate::new(&Blake2bParams::<Fq>::new(64), &prefix);
+    verify_circuit(&midstate_circuit(midstate, &input, 64), expected_output.as_bytes());
+}
+
+#[test]
+#[should_panic(expected = "The prefix of a midstate must be made of whole blocks")]
+fn test_midstate_should_receive_a_prefix_of_whole_blocks() {
+    Blake2bMidstate::new(&Blake2bParams::<Fq>::new(64), &bytes_of_length(100, 3));
+}
+
+fn run_midstate_test(prefix_length: usize, input_length: usize) {
+    let prefix = bytes_of_length(prefix_length, 3);
+    let input = bytes_of_length(input_length, 7);
+    let expected_output = blake2b(64, &[], &[prefix.clone(), input.clone()].concat());
+    let midstate = Blake2bMidstate::new(&Blake2bParams::<Fq>::new(64), &prefix);
+    verify_circuit(&midstate_circuit(midstate, &input, 64), expected_output.as_bytes());
+}
+
+fn midstate_circuit(
+    midstate: Blake2bMidstate,
+    input: &[u8],
+    output_size: usize,
+) -> MidstateCircuit<Fq> {
+    MidstateCircuit::new(midstate, input.iter().map(|x| value_for(*x)).collect(), output_size)
+}
@@ -4,4 +4,28 @@ mod smoke_tests;
 mod vector_tests;
 mod variable_output_length_tests;
 mod variable_key_length_tests;
+mod variable_input_length_circuit;
+mod variable_input_length_tests;
+mod private_key_length_circuit;
+mod private_key_length_tests;
+mod salt_and_personalization_circuit;
+mod salt_and_personalization_tests;
+mod params_circuit;
+mod params_tests;
+mod tree_circuit;
+mod tree_tests;
+mod blake2xb_circuit;
+mod blake2xb_tests;
+mod streaming_circuit;
+mod streaming_tests;
+mod midstate_circuit;
+mod midstate_tests;
+mod compression_circuit;
+mod compression_tests;
+mod digest_packing_tests;
+mod hash_many_circuit;
+mod hash_many_tests;
+mod hash_to_field_circuit;
+mod hash_to_field_tests;
+mod bn256_tests;
 mod circuit_in_production;
@@ -0,0 +1,69 @@
+use super::*;
+use crate::blake2b::blake2b_chip::{Blake2bChip, Blake2bConfig};
+use crate::blake2b::params::Blake2bParams;
+use crate::usage_utils::blake2b_circuit::Blake2bCircuit;
+use midnight_proofs::circuit::SimpleFloorPlanner;
+use midnight_proofs::plonk::{Circuit, Instance};
+use std::array;
+
+/// Circuit that hashes an input and a key with the whole parameter block given by a
+/// [Blake2bParams].
+#[derive(Clone, Debug)]
+pub(crate) struct ParamsCircuit<F: PrimeField> {
+    input: Vec<Value<F>>,
+    key: Vec<Value<F>>,
+    params: Blake2bParams<F>,
+}
+
+impl<F: PrimeField> ParamsCircuit<F> {
+    pub(crate) fn new(input: Vec<Value<F>>, key: Vec<Value<F>>, params: Blake2bParams<F>) -> Self {
+        Self { input, key, params }
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for ParamsCircuit<F> {
+    type Config = (Blake2bConfig, Column<Instance>);
+    type Params = ();
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            input: vec![Value::unknown(); self.input.len()],
+            key: vec![Value::unknown(); self.key.len()],
+            params: self.params.clone(),
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let full_number_u64 = meta.advice_column();
+        let limbs: [Column<Advice>; 8] = array::from_fn(|_| meta.advice_column());
+        let constant_col = meta.fixed_column();
+        let expected_final_state = meta.instance_column();
+        meta.enable_equality(expected_final_state);
+        (Blake2bChip::configure(meta, constant_col, full_number_u64, limbs), expected_final_state)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let assigned_input = Blake2bCircuit::assign_inputs_to_the_trace(
+            config.0.clone(),
+            &mut layouter,
+            &self.input,
+        )?;
+        let assigned_key =
+            Blake2bCircuit::assign_inputs_to_the_trace(config.0.clone(), &mut layouter, &self.key)?;
+
+        let chip = Blake2bChip::new(&config.0);
+        chip.load(&mut layouter)?;
+        let result =
+            chip.hash_with_params(&mut layouter, &assigned_input, &assigned_key, &self.params)?;
+
+        for (i, global_state_byte_cell) in result.iter().enumerate().take(self.params.output_size) {
+            layouter.constrain_instance(global_state_byte_cell.cell(), config.1, i)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,98 @@
+use super::*;
+use crate::blake2b::params::{Blake2bParams, ParamBytes};
+use crate::tests::test_blake2b::params_circuit::ParamsCircuit;
+use midnight_proofs::dev::MockProver;
+
+#[test]
+fn test_default_params_match_a_sequential_hash() {
+    let input = bytes_of_length(200, 3);
+    let expected_output = blake2b_simd::Params::new().hash(&input);
+    run_params_test(&input, &[], Blake2bParams::new(64), expected_output.as_bytes());
+}
+
+#[test]
+fn test_params_of_a_leaf_match_the_reference() {
+    let input = bytes_of_length(200, 3);
+    let expected_output = blake2b_simd::Params::new()
+        .hash_length(32)
+        .fanout(4)
+        .max_depth(2)
+        .max_leaf_length(4096)
+        .node_offset(3)
+        .inner_hash_length(64)
+        .hash(&input);
+    let params =
+        Blake2bParams::new(32).fanout(4).depth(2).leaf_length(4096).node_offset(3).inner_length(64);
+    run_params_test(&input, &[], params, expected_output.as_bytes());
+}
+
+#[test]
+fn test_params_of_an_inner_node_match_the_reference() {
+    let input = bytes_of_length(128, 5);
+    let expected_output = blake2b_simd::Params::new()
+        .fanout(0)
+        .max_depth(255)
+        .node_depth(1)
+        .inner_hash_length(32)
+        .hash(&input);
+    let params = Blake2bParams::new(64).fanout(0).depth(255).node_depth(1).inner_length(32);
+    run_params_test(&input, &[], params, expected_output.as_bytes());
+}
+
+#[test]
+fn test_full_parameter_block_with_key_matches_the_reference() {
+    let input = bytes_of_length(300, 3);
+    let key = bytes_of_length(32, 7);
+    let salt = *b"0123456789abcdef";
+    let personalization = *b"ZcashPrevoutHash";
+    let expected_output = blake2b_simd::Params::new()
+        .hash_length(48)
+        .key(&key)
+        .salt(&salt)
+        .personal(&personalization)
+        .fanout(2)
+        .max_depth(3)
+        .max_leaf_length(0xdeadbeef)
+        .node_offset(0x0123456789abcdef)
+        .node_depth(2)
+        .inner_hash_length(48)
+        .hash(&input);
+    let params = Blake2bParams::new(48)
+        .salt(ParamBytes::Constant(salt))
+        .personalization(ParamBytes::Constant(personalization))
+        .fanout(2)
+        .depth(3)
+        .leaf_length(0xdeadbeef)
+        .node_offset(0x0123456789abcdef)
+        .node_depth(2)
+        .inner_length(48);
+    run_params_test(&input, &key, params, expected_output.as_bytes());
+}
+
+#[test]
+#[should_panic]
+fn test_params_fail_with_the_digest_of_another_node_offset() {
+    let input = bytes_of_length(200, 3);
+    let expected_output =
+        blake2b_simd::Params::new().fanout(2).max_depth(2).node_offset(1).hash(&input);
+    let params = Blake2bParams::new(64).fanout(2).depth(2).node_offset(0);
+    run_params_test(&input, &[], params, expected_output.as_bytes());
+}
+
+#[test]
+#[should_panic(expected = "Inner length must be between 0 and 64 bytes")]
+fn test_params_should_receive_a_valid_inner_length() {
+    Blake2bParams::<Fq>::new(64).inner_length(65);
+}
+
+fn run_params_test(input: &[u8], key: &[u8], params: Blake2bParams<Fq>, expected_output: &[u8]) {
+    let circuit = ParamsCircuit::new(
+        input.iter().map(|x| value_for(*x)).collect(),
+        key.iter().map(|x| value_for(*x)).collect(),
+        params,
+    );
+    let expected_output_fields: Vec<Fq> =
+        expected_output.iter().map(|x| Fq::from(*x as u64)).collect();
+    let prover = MockProver::run(17, &circuit, vec![expected_output_fields]).unwrap();
+    prover.verify().unwrap();
+}
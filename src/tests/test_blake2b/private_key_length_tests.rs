@@ -0,0 +1,84 @@
+use super::*;
+use crate::tests::test_blake2b::private_key_length_circuit::PrivateKeyLengthCircuit;
+use blake2_rfc::blake2b::blake2b;
+
+#[test]
+fn test_private_key_length_hashes_keys_of_every_size_class() {
+    let input = bytes_of_length(50, 3);
+    for key_length in [1, 16, 63, 64] {
+        run_private_key_length_test(&input, key_length, 64);
+    }
+}
+
+#[test]
+fn test_private_key_length_hashes_an_empty_input() {
+    run_private_key_length_test(&[], 20, 32);
+}
+
+#[test]
+fn test_private_key_length_hashes_an_input_of_more_than_one_block() {
+    let input = bytes_of_length(300, 5);
+    run_private_key_length_test(&input, 33, 64);
+}
+
+#[test]
+#[should_panic]
+fn test_private_key_length_fails_with_a_wrong_digest() {
+    let input = bytes_of_length(50, 3);
+    let key = bytes_of_length(10, 11);
+    let mut expected_output = blake2b(64, &key, &input).as_bytes().to_vec();
+    expected_output[5] ^= 1;
+    let circuit = private_key_length_circuit(&input, &key, 10, 64);
+    verify_circuit(&circuit, &expected_output);
+}
+
+#[test]
+#[should_panic]
+fn test_private_key_length_fails_with_a_wrong_key_length() {
+    let input = bytes_of_length(50, 3);
+    let key = bytes_of_length(10, 11);
+    let expected_output = blake2b(64, &key, &input).as_bytes().to_vec();
+    let circuit = private_key_length_circuit(&input, &key, 9, 64);
+    verify_circuit(&circuit, &expected_output);
+}
+
+#[test]
+#[should_panic]
+fn test_private_key_length_fails_with_an_empty_key() {
+    let input = bytes_of_length(50, 3);
+    let expected_output = blake2b(64, &[], &input).as_bytes().to_vec();
+    let circuit = private_key_length_circuit(&input, &[], 0, 64);
+    verify_circuit(&circuit, &expected_output);
+}
+
+#[test]
+#[should_panic(expected = "Key cells can't be empty when the key length is private")]
+fn test_private_key_length_should_receive_key_cells() {
+    let circuit = PrivateKeyLengthCircuit::<Fq>::new(vec![], vec![], value_for(0u64), 64);
+    verify_circuit(&circuit, &[0u8; 64]);
+}
+
+fn run_private_key_length_test(input: &[u8], key_length: usize, output_size: usize) {
+    let key = bytes_of_length(key_length, 11);
+    let expected_output = blake2b(output_size, &key, input).as_bytes().to_vec();
+    let circuit = private_key_length_circuit(input, &key, key_length, output_size);
+    verify_circuit(&circuit, &expected_output);
+}
+
+/// The key is completed with zeros up to 64 bytes, so every key length yields the same circuit
+fn private_key_length_circuit(
+    input: &[u8],
+    key: &[u8],
+    key_length: usize,
+    output_size: usize,
+) -> PrivateKeyLengthCircuit<Fq> {
+    let input_values = input.iter().map(|x| value_for(*x)).collect();
+    let mut key_values: Vec<Value<Fq>> = key.iter().map(|x| value_for(*x)).collect();
+    key_values.resize(64, value_for(0u8));
+    PrivateKeyLengthCircuit::new(
+        input_values,
+        key_values,
+        value_for(key_length as u64),
+        output_size,
+    )
+}
@@ -0,0 +1,111 @@
+use super::*;
+use crate::blake2b::blake2b_chip::{Blake2bChip, Blake2bConfig};
+use crate::blake2b::params::ParamBytes;
+use crate::usage_utils::blake2b_circuit::Blake2bCircuit;
+use midnight_proofs::circuit::SimpleFloorPlanner;
+use midnight_proofs::plonk::{Circuit, Instance};
+use std::array;
+
+/// Circuit that hashes an input with an optional salt and an optional personalization. When
+/// 'assign_params' is set, the parameters are assigned as witnesses in the trace, otherwise they're
+/// constants of the circuit.
+#[derive(Clone, Debug)]
+pub(crate) struct SaltAndPersonalizationCircuit<F: PrimeField> {
+    input: Vec<Value<F>>,
+    key: Vec<Value<F>>,
+    salt: Option<[u8; 16]>,
+    personalization: Option<[u8; 16]>,
+    assign_params: bool,
+    output_size: usize,
+}
+
+impl<F: PrimeField> SaltAndPersonalizationCircuit<F> {
+    pub(crate) fn new(
+        input: Vec<Value<F>>,
+        key: Vec<Value<F>>,
+        salt: Option<[u8; 16]>,
+        personalization: Option<[u8; 16]>,
+        assign_params: bool,
+        output_size: usize,
+    ) -> Self {
+        Self {
+            input,
+            key,
+            salt,
+            personalization,
+            assign_params,
+            output_size,
+        }
+    }
+
+    fn param_bytes(
+        &self,
+        config: &Blake2bConfig,
+        layouter: &mut impl Layouter<F>,
+        bytes: Option<[u8; 16]>,
+    ) -> Result<Option<ParamBytes<F>>, Error> {
+        let Some(bytes) = bytes else { return Ok(None) };
+        if !self.assign_params {
+            return Ok(Some(ParamBytes::Constant(bytes)));
+        }
+        let values: Vec<Value<F>> = bytes.iter().map(|byte| value_for(*byte)).collect();
+        let assigned =
+            Blake2bCircuit::assign_inputs_to_the_trace(config.clone(), layouter, &values)?;
+        Ok(Some(ParamBytes::Assigned(assigned.try_into().unwrap())))
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for SaltAndPersonalizationCircuit<F> {
+    type Config = (Blake2bConfig, Column<Instance>);
+    type Params = ();
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            input: vec![Value::unknown(); self.input.len()],
+            key: vec![Value::unknown(); self.key.len()],
+            ..self.clone()
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let full_number_u64 = meta.advice_column();
+        let limbs: [Column<Advice>; 8] = array::from_fn(|_| meta.advice_column());
+        let constant_col = meta.fixed_column();
+        let expected_final_state = meta.instance_column();
+        meta.enable_equality(expected_final_state);
+        (Blake2bChip::configure(meta, constant_col, full_number_u64, limbs), expected_final_state)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let assigned_input = Blake2bCircuit::assign_inputs_to_the_trace(
+            config.0.clone(),
+            &mut layouter,
+            &self.input,
+        )?;
+        let assigned_key =
+            Blake2bCircuit::assign_inputs_to_the_trace(config.0.clone(), &mut layouter, &self.key)?;
+        let salt = self.param_bytes(&config.0, &mut layouter, self.salt)?;
+        let personalization = self.param_bytes(&config.0, &mut layouter, self.personalization)?;
+
+        let chip = Blake2bChip::new(&config.0);
+        chip.load(&mut layouter)?;
+        let result = chip.hash(
+            &mut layouter,
+            &assigned_input,
+            &assigned_key,
+            self.output_size,
+            salt.as_ref(),
+            personalization.as_ref(),
+        )?;
+
+        for (i, global_state_byte_cell) in result.iter().enumerate().take(self.output_size) {
+            layouter.constrain_instance(global_state_byte_cell.cell(), config.1, i)?;
+        }
+        Ok(())
+    }
+}
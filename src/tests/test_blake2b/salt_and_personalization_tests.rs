@@ -0,0 +1,101 @@
+use super::*;
+use crate::tests::test_blake2b::salt_and_personalization_circuit::SaltAndPersonalizationCircuit;
+use blake2_rfc::blake2b::Blake2b;
+
+const ZCASH_PREVOUT_HASH: &[u8; 16] = b"ZcashPrevoutHash";
+const SALT: &[u8; 16] = b"0123456789abcdef";
+
+#[test]
+fn test_constant_personalization_matches_the_reference() {
+    run_salt_and_personalization_test(None, Some(*ZCASH_PREVOUT_HASH), 0, false, 32);
+}
+
+#[test]
+fn test_assigned_personalization_matches_the_reference() {
+    run_salt_and_personalization_test(None, Some(*ZCASH_PREVOUT_HASH), 0, true, 32);
+}
+
+#[test]
+fn test_constant_salt_matches_the_reference() {
+    run_salt_and_personalization_test(Some(*SALT), None, 0, false, 64);
+}
+
+#[test]
+fn test_assigned_salt_and_personalization_with_key_match_the_reference() {
+    run_salt_and_personalization_test(Some(*SALT), Some(*ZCASH_PREVOUT_HASH), 20, true, 64);
+}
+
+#[test]
+#[should_panic]
+fn test_assigned_personalization_fails_with_the_digest_of_another_personalization() {
+    let input = bytes_of_length(150, 3);
+    let expected_output = reference_digest(&input, &[], None, Some(*b"ZcashOutputsHash"), 32);
+    let circuit =
+        salt_and_personalization_circuit(&input, &[], None, Some(*ZCASH_PREVOUT_HASH), true, 32);
+    verify_circuit(&circuit, &expected_output);
+}
+
+fn run_salt_and_personalization_test(
+    salt: Option<[u8; 16]>,
+    personalization: Option<[u8; 16]>,
+    key_length: usize,
+    assign_params: bool,
+    output_size: usize,
+) {
+    let input = bytes_of_length(150, 3);
+    let key = bytes_of_length(key_length, 7);
+    let expected_output = reference_digest(&input, &key, salt, personalization, output_size);
+    let circuit = salt_and_personalization_circuit(
+        &input,
+        &key,
+        salt,
+        personalization,
+        assign_params,
+        output_size,
+    );
+    verify_circuit(&circuit, &expected_output);
+}
+
+/// Digest of the reference implementation, built from its raw parameter block
+fn reference_digest(
+    input: &[u8],
+    key: &[u8],
+    salt: Option<[u8; 16]>,
+    personalization: Option<[u8; 16]>,
+    output_size: usize,
+) -> Vec<u8> {
+    let mut parameter_block = [0u64; 8];
+    parameter_block[0] = 0x01010000 ^ ((key.len() as u64) << 8) ^ output_size as u64;
+    for (index, bytes) in [(4, salt), (6, personalization)] {
+        if let Some(bytes) = bytes {
+            parameter_block[index] = u64::from_le_bytes(bytes[..8].try_into().unwrap());
+            parameter_block[index + 1] = u64::from_le_bytes(bytes[8..].try_into().unwrap());
+        }
+    }
+    let mut state = Blake2b::with_parameter_block(&parameter_block);
+    if !key.is_empty() {
+        let mut key_block = key.to_vec();
+        key_block.resize(128, 0);
+        state.update(&key_block);
+    }
+    state.update(input);
+    state.finalize().as_bytes().to_vec()
+}
+
+fn salt_and_personalization_circuit(
+    input: &[u8],
+    key: &[u8],
+    salt: Option<[u8; 16]>,
+    personalization: Option<[u8; 16]>,
+    assign_params: bool,
+    output_size: usize,
+) -> SaltAndPersonalizationCircuit<Fq> {
+    SaltAndPersonalizationCircuit::new(
+        input.iter().map(|x| value_for(*x)).collect(),
+        key.iter().map(|x| value_for(*x)).collect(),
+        salt,
+        personalization,
+        assign_params,
+        output_size,
+    )
+}
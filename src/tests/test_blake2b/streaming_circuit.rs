@@ -0,0 +1,78 @@
+use super::*;
+use crate::blake2b::blake2b_chip::{Blake2bChip, Blake2bConfig};
+use crate::blake2b::params::Blake2bParams;
+use crate::blake2b::state::Blake2bState;
+use crate::usage_utils::blake2b_circuit::Blake2bCircuit;
+use midnight_proofs::circuit::SimpleFloorPlanner;
+use midnight_proofs::plonk::{Circuit, Instance};
+use std::array;
+
+/// Circuit that hashes a message given in pieces through a [Blake2bState]. Every piece is
+/// assigned right before its update, so the updates are interleaved with other regions.
+#[derive(Clone, Debug)]
+pub(crate) struct StreamingCircuit<F: PrimeField> {
+    pieces: Vec<Vec<Value<F>>>,
+    key: Vec<Value<F>>,
+    params: Blake2bParams<F>,
+}
+
+impl<F: PrimeField> StreamingCircuit<F> {
+    pub(crate) fn new(
+        pieces: Vec<Vec<Value<F>>>,
+        key: Vec<Value<F>>,
+        params: Blake2bParams<F>,
+    ) -> Self {
+        Self {
+            pieces,
+            key,
+            params,
+        }
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for StreamingCircuit<F> {
+    type Config = (Blake2bConfig, Column<Instance>);
+    type Params = ();
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            pieces: self.pieces.iter().map(|piece| vec![Value::unknown(); piece.len()]).collect(),
+            key: vec![Value::unknown(); self.key.len()],
+            params: self.params.clone(),
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let full_number_u64 = meta.advice_column();
+        let limbs: [Column<Advice>; 8] = array::from_fn(|_| meta.advice_column());
+        let constant_col = meta.fixed_column();
+        let expected_final_state = meta.instance_column();
+        meta.enable_equality(expected_final_state);
+        (Blake2bChip::configure(meta, constant_col, full_number_u64, limbs), expected_final_state)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let chip = Blake2bChip::new(&config.0);
+        chip.load(&mut layouter)?;
+
+        let assigned_key =
+            Blake2bCircuit::assign_inputs_to_the_trace(config.0.clone(), &mut layouter, &self.key)?;
+        let mut state = Blake2bState::init(&chip, &mut layouter, &self.params, &assigned_key)?;
+        for piece in self.pieces.iter() {
+            let assigned_piece =
+                Blake2bCircuit::assign_inputs_to_the_trace(config.0.clone(), &mut layouter, piece)?;
+            state.update(&mut layouter, &assigned_piece)?;
+        }
+        let result = state.finalize(&mut layouter)?;
+
+        for (i, global_state_byte_cell) in result.iter().enumerate().take(self.params.output_size) {
+            layouter.constrain_instance(global_state_byte_cell.cell(), config.1, i)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,74 @@
+use super::*;
+use crate::blake2b::params::{Blake2bParams, ParamBytes};
+use crate::tests::test_blake2b::streaming_circuit::StreamingCircuit;
+use blake2_rfc::blake2b::blake2b;
+
+#[test]
+fn test_streaming_of_pieces_of_several_sizes_matches_the_reference() {
+    run_streaming_test(&[50, 100, 200], 0, 64);
+}
+
+#[test]
+fn test_streaming_of_pieces_that_end_at_block_boundaries_matches_the_reference() {
+    run_streaming_test(&[128, 0, 128], 0, 32);
+}
+
+#[test]
+fn test_streaming_without_updates_matches_the_reference() {
+    run_streaming_test(&[], 0, 64);
+}
+
+#[test]
+fn test_streaming_with_key_without_updates_matches_the_reference() {
+    run_streaming_test(&[], 32, 64);
+}
+
+#[test]
+fn test_streaming_with_key_matches_the_reference() {
+    run_streaming_test(&[10, 300], 64, 48);
+}
+
+#[test]
+fn test_streaming_with_personalization_matches_the_reference() {
+    let message = bytes_of_length(150, 3);
+    let personalization = *b"ZcashPrevoutHash";
+    let expected_output =
+        blake2b_simd::Params::new().hash_length(32).personal(&personalization).hash(&message);
+    let params = Blake2bParams::new(32).personalization(ParamBytes::Constant(personalization));
+    let circuit = streaming_circuit(&message, &[100, 50], &[], params);
+    verify_circuit(&circuit, expected_output.as_bytes());
+}
+
+#[test]
+#[should_panic]
+fn test_streaming_fails_with_a_wrong_digest() {
+    let message = bytes_of_length(150, 3);
+    let mut expected_output = blake2b(64, &[], &message).as_bytes().to_vec();
+    expected_output[0] ^= 1;
+    let circuit = streaming_circuit(&message, &[75, 75], &[], Blake2bParams::new(64));
+    verify_circuit(&circuit, &expected_output);
+}
+
+fn run_streaming_test(piece_lengths: &[usize], key_length: usize, output_size: usize) {
+    let message = bytes_of_length(piece_lengths.iter().sum(), 3);
+    let key = bytes_of_length(key_length, 7);
+    let expected_output = blake2b(output_size, &key, &message).as_bytes().to_vec();
+    let circuit = streaming_circuit(&message, piece_lengths, &key, Blake2bParams::new(output_size));
+    verify_circuit(&circuit, &expected_output);
+}
+
+fn streaming_circuit(
+    message: &[u8],
+    piece_lengths: &[usize],
+    key: &[u8],
+    params: Blake2bParams<Fq>,
+) -> StreamingCircuit<Fq> {
+    let mut pieces = Vec::new();
+    let mut start = 0;
+    for piece_length in piece_lengths {
+        let piece = &message[start..start + piece_length];
+        pieces.push(piece.iter().map(|x| value_for(*x)).collect());
+        start += piece_length;
+    }
+    StreamingCircuit::new(pieces, key.iter().map(|x| value_for(*x)).collect(), params)
+}
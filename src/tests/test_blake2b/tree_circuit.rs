@@ -0,0 +1,82 @@
+use super::*;
+use crate::blake2b::blake2b_chip::{Blake2bChip, Blake2bConfig};
+use crate::blake2b::params::Blake2bParams;
+use crate::usage_utils::blake2b_circuit::Blake2bCircuit;
+use midnight_proofs::circuit::SimpleFloorPlanner;
+use midnight_proofs::plonk::{Circuit, Instance};
+use std::array;
+
+/// Tree hashing mode computed by a [TreeCircuit].
+#[derive(Clone, Debug)]
+pub(crate) enum TreeMode<F: PrimeField> {
+    Blake2bp(usize),
+    Tree(Blake2bParams<F>),
+}
+
+/// Circuit that computes a tree hash of an input, either Blake2bp or a generic tree hash.
+#[derive(Clone, Debug)]
+pub(crate) struct TreeCircuit<F: PrimeField> {
+    input: Vec<Value<F>>,
+    mode: TreeMode<F>,
+}
+
+impl<F: PrimeField> TreeCircuit<F> {
+    pub(crate) fn new(input: Vec<Value<F>>, mode: TreeMode<F>) -> Self {
+        Self { input, mode }
+    }
+
+    fn output_size(&self) -> usize {
+        match &self.mode {
+            TreeMode::Blake2bp(output_size) => *output_size,
+            TreeMode::Tree(params) => params.output_size,
+        }
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for TreeCircuit<F> {
+    type Config = (Blake2bConfig, Column<Instance>);
+    type Params = ();
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            input: vec![Value::unknown(); self.input.len()],
+            mode: self.mode.clone(),
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let full_number_u64 = meta.advice_column();
+        let limbs: [Column<Advice>; 8] = array::from_fn(|_| meta.advice_column());
+        let constant_col = meta.fixed_column();
+        let expected_final_state = meta.instance_column();
+        meta.enable_equality(expected_final_state);
+        (Blake2bChip::configure(meta, constant_col, full_number_u64, limbs), expected_final_state)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let assigned_input = Blake2bCircuit::assign_inputs_to_the_trace(
+            config.0.clone(),
+            &mut layouter,
+            &self.input,
+        )?;
+
+        let chip = Blake2bChip::new(&config.0);
+        chip.load(&mut layouter)?;
+        let result = match &self.mode {
+            TreeMode::Blake2bp(output_size) => {
+                chip.hash_blake2bp(&mut layouter, &assigned_input, *output_size)?
+            }
+            TreeMode::Tree(params) => chip.hash_tree(&mut layouter, &assigned_input, params)?,
+        };
+
+        for (i, global_state_byte_cell) in result.iter().enumerate().take(self.output_size()) {
+            layouter.constrain_instance(global_state_byte_cell.cell(), config.1, i)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,108 @@
+use super::*;
+use crate::blake2b::params::Blake2bParams;
+use crate::tests::test_blake2b::tree_circuit::{TreeCircuit, TreeMode};
+use midnight_proofs::dev::MockProver;
+
+#[test]
+fn test_blake2bp_of_an_empty_input_matches_the_reference() {
+    let expected_output = blake2b_simd::blake2bp::blake2bp(&[]);
+    run_tree_test(&[], TreeMode::Blake2bp(64), expected_output.as_bytes());
+}
+
+#[test]
+fn test_blake2bp_of_several_blocks_matches_the_reference() {
+    let input = bytes_of_length(700, 3);
+    let expected_output = blake2b_simd::blake2bp::blake2bp(&input);
+    run_tree_test(&input, TreeMode::Blake2bp(64), expected_output.as_bytes());
+}
+
+#[test]
+fn test_blake2bp_with_a_short_output_matches_the_reference() {
+    let input = bytes_of_length(300, 5);
+    let expected_output = blake2b_simd::blake2bp::Params::new().hash_length(32).hash(&input);
+    run_tree_test(&input, TreeMode::Blake2bp(32), expected_output.as_bytes());
+}
+
+#[test]
+fn test_tree_hash_of_several_levels_matches_the_reference() {
+    let input = bytes_of_length(300, 3);
+    let expected_output = reference_tree_hash(&input, 2, 128, 32, 64);
+    let params = Blake2bParams::new(64).fanout(2).depth(255).leaf_length(128).inner_length(32);
+    run_tree_test(&input, TreeMode::Tree(params), &expected_output);
+}
+
+#[test]
+fn test_tree_hash_of_a_single_leaf_matches_the_reference() {
+    let input = bytes_of_length(100, 3);
+    let expected_output = reference_tree_hash(&input, 4, 128, 64, 48);
+    let params = Blake2bParams::new(48).fanout(4).depth(255).leaf_length(128).inner_length(64);
+    run_tree_test(&input, TreeMode::Tree(params), &expected_output);
+}
+
+#[test]
+#[should_panic]
+fn test_blake2bp_fails_with_a_sequential_digest() {
+    let input = bytes_of_length(300, 5);
+    let expected_output = blake2b_simd::blake2b(&input);
+    run_tree_test(&input, TreeMode::Blake2bp(64), expected_output.as_bytes());
+}
+
+#[test]
+#[should_panic(expected = "Fanout must be at least 2 in a tree hash")]
+fn test_tree_hash_should_receive_a_fanout_of_at_least_2() {
+    let params = Blake2bParams::new(64).leaf_length(128).inner_length(64);
+    run_tree_test(&[], TreeMode::Tree(params), &[0u8; 64]);
+}
+
+/// Tree hash of the reference implementation, built level by level with unlimited depth
+fn reference_tree_hash(
+    input: &[u8],
+    fanout: u8,
+    leaf_length: u32,
+    inner_length: usize,
+    output_size: usize,
+) -> Vec<u8> {
+    let mut level: Vec<Vec<u8>> = if input.is_empty() {
+        vec![vec![]]
+    } else {
+        input.chunks(leaf_length as usize).map(|leaf| leaf.to_vec()).collect()
+    };
+    let mut node_depth = 0;
+    loop {
+        let is_root_level = level.len() == 1;
+        let digests: Vec<u8> = level
+            .iter()
+            .enumerate()
+            .flat_map(|(node_offset, node_input)| {
+                blake2b_simd::Params::new()
+                    .hash_length(if is_root_level { output_size } else { inner_length })
+                    .fanout(fanout)
+                    .max_depth(255)
+                    .max_leaf_length(leaf_length)
+                    .node_offset(node_offset as u64)
+                    .node_depth(node_depth)
+                    .inner_hash_length(inner_length)
+                    .last_node(node_offset == level.len() - 1)
+                    .hash(node_input)
+                    .as_bytes()
+                    .to_vec()
+            })
+            .collect();
+        if is_root_level {
+            return digests;
+        }
+        level = digests
+            .chunks(fanout as usize * inner_length)
+            .map(|children| children.to_vec())
+            .collect();
+        node_depth += 1;
+    }
+}
+
+fn run_tree_test(input: &[u8], mode: TreeMode<Fq>, expected_output: &[u8]) {
+    let circuit = TreeCircuit::new(input.iter().map(|x| value_for(*x)).collect(), mode);
+    let expected_output_fields: Vec<Fq> =
+        expected_output.iter().map(|x| Fq::from(*x as u64)).collect();
+    let prover = MockProver::run(17, &circuit, vec![expected_output_fields]).unwrap();
+    prover.verify().unwrap();
+}
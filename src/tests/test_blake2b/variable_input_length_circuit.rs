@@ -0,0 +1,96 @@
+use super::*;
+use crate::blake2b::blake2b_chip::{Blake2bChip, Blake2bConfig};
+use crate::usage_utils::blake2b_circuit::Blake2bCircuit;
+use midnight_proofs::circuit::SimpleFloorPlanner;
+use midnight_proofs::plonk::{Circuit, Instance};
+use std::array;
+
+/// Circuit that hashes an input whose length is only known at proof generation time. The
+/// circuit only depends on the maximum input size, the key size and the output size.
+#[derive(Clone, Debug)]
+pub(crate) struct VariableInputLengthCircuit<F: PrimeField> {
+    input: Vec<Value<F>>,
+    input_length: Value<F>,
+    key: Vec<Value<F>>,
+    max_input_size: usize,
+    key_size: usize,
+    output_size: usize,
+}
+
+impl<F: PrimeField> VariableInputLengthCircuit<F> {
+    pub(crate) fn new(
+        input: Vec<Value<F>>,
+        input_length: Value<F>,
+        key: Vec<Value<F>>,
+        output_size: usize,
+    ) -> Self {
+        Self {
+            max_input_size: input.len(),
+            key_size: key.len(),
+            input,
+            input_length,
+            key,
+            output_size,
+        }
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for VariableInputLengthCircuit<F> {
+    type Config = (Blake2bConfig, Column<Instance>);
+    type Params = ();
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            input: vec![Value::unknown(); self.max_input_size],
+            input_length: Value::unknown(),
+            key: vec![Value::unknown(); self.key_size],
+            max_input_size: self.max_input_size,
+            key_size: self.key_size,
+            output_size: self.output_size,
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let full_number_u64 = meta.advice_column();
+        let limbs: [Column<Advice>; 8] = array::from_fn(|_| meta.advice_column());
+        let constant_col = meta.fixed_column();
+        let expected_final_state = meta.instance_column();
+        meta.enable_equality(expected_final_state);
+        (Blake2bChip::configure(meta, constant_col, full_number_u64, limbs), expected_final_state)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let assigned_input = Blake2bCircuit::assign_inputs_to_the_trace(
+            config.0.clone(),
+            &mut layouter,
+            &self.input,
+        )?;
+        let assigned_input_length = Blake2bCircuit::assign_inputs_to_the_trace(
+            config.0.clone(),
+            &mut layouter,
+            &[self.input_length],
+        )?;
+        let assigned_key =
+            Blake2bCircuit::assign_inputs_to_the_trace(config.0.clone(), &mut layouter, &self.key)?;
+
+        let chip = Blake2bChip::new(&config.0);
+        chip.load(&mut layouter)?;
+        let result = chip.hash_with_variable_input_length(
+            &mut layouter,
+            &assigned_input,
+            &assigned_input_length[0],
+            &assigned_key,
+            self.output_size,
+        )?;
+
+        for (i, global_state_byte_cell) in result.iter().enumerate().take(self.output_size) {
+            layouter.constrain_instance(global_state_byte_cell.cell(), config.1, i)?;
+        }
+        Ok(())
+    }
+}
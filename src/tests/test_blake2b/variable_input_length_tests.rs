@@ -0,0 +1,102 @@
+use super::*;
+use crate::tests::test_blake2b::variable_input_length_circuit::VariableInputLengthCircuit;
+use blake2_rfc::blake2b::blake2b;
+
+#[test]
+fn test_variable_input_length_hashes_messages_shorter_than_one_block() {
+    for input_length in [0, 1, 64, 127] {
+        run_variable_input_length_test(128, input_length, &[], 64);
+    }
+}
+
+#[test]
+fn test_variable_input_length_hashes_messages_at_block_boundaries() {
+    for input_length in [128, 129, 255, 256] {
+        run_variable_input_length_test(256, input_length, &[], 64);
+    }
+}
+
+#[test]
+fn test_variable_input_length_hashes_an_empty_message_with_maximum_length_0() {
+    run_variable_input_length_test(0, 0, &[], 64);
+}
+
+#[test]
+fn test_variable_input_length_hashes_messages_with_key() {
+    let key = [7u8; 32];
+    for input_length in [0, 100, 200] {
+        run_variable_input_length_test(200, input_length, &key, 32);
+    }
+}
+
+#[test]
+fn test_variable_input_length_hashes_only_the_key_with_maximum_length_0() {
+    run_variable_input_length_test(0, 0, &[1u8; 64], 64);
+}
+
+#[test]
+#[should_panic]
+fn test_variable_input_length_fails_with_a_wrong_digest() {
+    let input = message_of_length(100);
+    let mut expected_output = blake2b(64, &[], &input).as_bytes().to_vec();
+    expected_output[0] ^= 1;
+    let circuit = variable_input_length_circuit(&input, 256, 100, &[], 64);
+    verify_circuit(&circuit, &expected_output);
+}
+
+#[test]
+#[should_panic]
+fn test_variable_input_length_fails_if_a_byte_after_the_length_is_not_zero() {
+    let mut input = message_of_length(101);
+    let expected_output = blake2b(64, &[], &input[..100]).as_bytes().to_vec();
+    input[100] = 1;
+    let circuit = variable_input_length_circuit(&input, 256, 100, &[], 64);
+    verify_circuit(&circuit, &expected_output);
+}
+
+#[test]
+#[should_panic]
+fn test_variable_input_length_fails_if_the_length_exceeds_the_maximum() {
+    let input = message_of_length(200);
+    let mut padded_input = input.clone();
+    padded_input.resize(256, 0);
+    let expected_output = blake2b(64, &[], &padded_input[..201]).as_bytes().to_vec();
+    let circuit = variable_input_length_circuit(&input, 200, 201, &[], 64);
+    verify_circuit(&circuit, &expected_output);
+}
+
+fn run_variable_input_length_test(
+    max_input_size: usize,
+    input_length: usize,
+    key: &[u8],
+    output_size: usize,
+) {
+    let input = message_of_length(input_length);
+    let expected_output = blake2b(output_size, key, &input).as_bytes().to_vec();
+    let circuit =
+        variable_input_length_circuit(&input, max_input_size, input_length, key, output_size);
+    verify_circuit(&circuit, &expected_output);
+}
+
+fn message_of_length(length: usize) -> Vec<u8> {
+    (0..length).map(|i| (i * 7 + 3) as u8).collect()
+}
+
+/// The message is completed with zeros up to 'max_input_size'
+fn variable_input_length_circuit(
+    input: &[u8],
+    max_input_size: usize,
+    input_length: usize,
+    key: &[u8],
+    output_size: usize,
+) -> VariableInputLengthCircuit<Fq> {
+    let mut input_values: Vec<Value<Fq>> = input.iter().map(|x| value_for(*x)).collect();
+    input_values.resize(max_input_size, value_for(0u8));
+    let key_values = key.iter().map(|x| value_for(*x)).collect();
+    VariableInputLengthCircuit::new(
+        input_values,
+        value_for(input_length as u64),
+        key_values,
+        output_size,
+    )
+}
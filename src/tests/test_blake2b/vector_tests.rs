@@ -2,11 +2,11 @@ use serde::Deserialize;
 use crate::usage_utils::circuit_runner::CircuitRunner;
 
 #[derive(Deserialize, Debug)]
-struct TestCase {
+pub(super) struct TestCase {
     #[serde(rename = "in")]
-    input: String,
-    key: String,
-    out: String,
+    pub(super) input: String,
+    pub(super) key: String,
+    pub(super) out: String,
 }
 
 pub(crate) fn run_test(input: &String, key: &String, expected: &String) {
@@ -60,7 +60,7 @@ fn test_hashes_in_circuit_with_key() {
     }
 }
 
-fn obtain_test_cases() -> Vec<TestCase> {
+pub(super) fn obtain_test_cases() -> Vec<TestCase> {
     let file_content = std::fs::read_to_string("./test_vector.json").expect("Failed to read file");
     serde_json::from_str(&file_content).expect("Failed to parse JSON")
 }
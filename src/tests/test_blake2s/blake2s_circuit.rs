@@ -0,0 +1,91 @@
+use super::*;
+use crate::blake2s::blake2s_chip::{Blake2sChip, Blake2sConfig};
+use midnight_proofs::circuit::SimpleFloorPlanner;
+use midnight_proofs::plonk::{Circuit, Instance};
+use std::array;
+
+/// Circuit that hashes an input with an optional key using the Blake2s chip, and constrains the
+/// first 'output_size' bytes of the digest to equal the public inputs.
+#[derive(Clone, Debug)]
+pub(crate) struct Blake2sCircuit<F: PrimeField> {
+    input: Vec<Value<F>>,
+    key: Vec<Value<F>>,
+    output_size: usize,
+}
+
+impl<F: PrimeField> Blake2sCircuit<F> {
+    pub(crate) fn new(input: Vec<Value<F>>, key: Vec<Value<F>>, output_size: usize) -> Self {
+        Self {
+            input,
+            key,
+            output_size,
+        }
+    }
+
+    /// The inputs are stored in the limb columns, eight bytes per row.
+    fn assign_inputs_to_the_trace(
+        config: &Blake2sConfig,
+        layouter: &mut impl Layouter<F>,
+        input: &[Value<F>],
+    ) -> Result<Vec<AssignedNative<F>>, Error> {
+        layouter.assign_region(
+            || "Inputs",
+            |mut region| {
+                input
+                    .iter()
+                    .enumerate()
+                    .map(|(index, input_byte)| {
+                        region.assign_advice(
+                            || "Input byte",
+                            config.limbs[index % 8],
+                            index / 8,
+                            || *input_byte,
+                        )
+                    })
+                    .collect()
+            },
+        )
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for Blake2sCircuit<F> {
+    type Config = (Blake2sConfig, Column<Instance>);
+    type Params = ();
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            input: vec![Value::unknown(); self.input.len()],
+            key: vec![Value::unknown(); self.key.len()],
+            output_size: self.output_size,
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let full_number_u64 = meta.advice_column();
+        let limbs: [Column<Advice>; 8] = array::from_fn(|_| meta.advice_column());
+        let constant_col = meta.fixed_column();
+        let expected_final_state = meta.instance_column();
+        meta.enable_equality(expected_final_state);
+        (Blake2sChip::configure(meta, constant_col, full_number_u64, limbs), expected_final_state)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let assigned_input =
+            Self::assign_inputs_to_the_trace(&config.0, &mut layouter, &self.input)?;
+        let assigned_key = Self::assign_inputs_to_the_trace(&config.0, &mut layouter, &self.key)?;
+
+        let chip = Blake2sChip::new(&config.0);
+        chip.load(&mut layouter)?;
+        let result = chip.hash(&mut layouter, &assigned_input, &assigned_key, self.output_size)?;
+
+        for (i, digest_byte_cell) in result.iter().enumerate().take(self.output_size) {
+            layouter.constrain_instance(digest_byte_cell.cell(), config.1, i)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,92 @@
+use super::*;
+use crate::tests::test_blake2s::blake2s_circuit::Blake2sCircuit;
+use blake2_rfc::blake2s::blake2s;
+
+/// BLAKE2s-256("abc"), from Appendix B of RFC 7693
+const RFC_7693_ABC_DIGEST: &str =
+    "508c5e8c327c14e2e1a72ba34eeb452f37458b209ed63a294d999b4c86675982";
+
+#[test]
+fn test_blake2s_of_abc_matches_rfc_7693() {
+    let expected_output = hex::decode(RFC_7693_ABC_DIGEST).unwrap();
+    verify_circuit(&blake2s_circuit(b"abc", &[], 32), &expected_output);
+}
+
+#[test]
+fn test_blake2s_of_empty_input_matches_the_reference() {
+    run_blake2s_test(&[], &[], 32);
+}
+
+#[test]
+fn test_blake2s_of_rfc_7693_self_test_inputs_match_the_reference() {
+    // Unkeyed inputs of the self-test of Appendix E of RFC 7693, around the block boundaries
+    for (input_length, output_size) in [(3, 16), (64, 20), (65, 28), (255, 32)] {
+        run_blake2s_test(&selftest_seq(input_length, input_length as u32), &[], output_size);
+    }
+}
+
+#[test]
+fn test_keyed_blake2s_of_rfc_7693_self_test_inputs_match_the_reference() {
+    // Keyed inputs of the self-test of Appendix E of RFC 7693, with the key of each output size
+    for (input_length, output_size) in [(0, 32), (3, 20), (64, 16), (65, 32)] {
+        let key = selftest_seq(output_size, output_size as u32);
+        run_blake2s_test(&selftest_seq(input_length, input_length as u32), &key, output_size);
+    }
+}
+
+#[test]
+fn test_blake2s_with_maximum_key_and_several_blocks_matches_the_reference() {
+    let key: Vec<u8> = (0..32).collect();
+    let input: Vec<u8> = (0..200).map(|i| i as u8).collect();
+    run_blake2s_test(&input, &key, 32);
+}
+
+#[test]
+#[should_panic]
+fn test_blake2s_fails_with_a_wrong_digest() {
+    let mut expected_output = hex::decode(RFC_7693_ABC_DIGEST).unwrap();
+    expected_output[31] ^= 1;
+    verify_circuit(&blake2s_circuit(b"abc", &[], 32), &expected_output);
+}
+
+#[test]
+#[should_panic]
+fn test_blake2s_fails_with_the_digest_of_another_output_size() {
+    let input = selftest_seq(65, 65);
+    let expected_output = blake2s(32, &[], &input).as_bytes()[..16].to_vec();
+    verify_circuit(&blake2s_circuit(&input, &[], 16), &expected_output);
+}
+
+#[test]
+#[should_panic(expected = "Key size must be between 0 and 32 bytes")]
+fn test_blake2s_chip_should_receive_a_key_of_at_most_32_bytes() {
+    let key = [0u8; 33];
+    verify_circuit(&blake2s_circuit(b"abc", &key, 32), &[0u8; 32]);
+}
+
+fn run_blake2s_test(input: &[u8], key: &[u8], output_size: usize) {
+    let expected_output = blake2s(output_size, key, input).as_bytes().to_vec();
+    verify_circuit(&blake2s_circuit(input, key, output_size), &expected_output);
+}
+
+/// Deterministic sequence of bytes of the self-test of Appendix E of RFC 7693
+fn selftest_seq(length: usize, seed: u32) -> Vec<u8> {
+    let mut a = 0xDEAD4BADu32.wrapping_mul(seed);
+    let mut b = 1u32;
+    (0..length)
+        .map(|_| {
+            let t = a.wrapping_add(b);
+            a = b;
+            b = t;
+            (t >> 24) as u8
+        })
+        .collect()
+}
+
+fn blake2s_circuit(input: &[u8], key: &[u8], output_size: usize) -> Blake2sCircuit<Fq> {
+    Blake2sCircuit::new(
+        input.iter().map(|x| value_for(*x)).collect(),
+        key.iter().map(|x| value_for(*x)).collect(),
+        output_size,
+    )
+}
@@ -0,0 +1,4 @@
+use super::*;
+
+mod blake2s_circuit;
+mod blake2s_tests;
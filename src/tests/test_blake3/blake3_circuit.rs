@@ -0,0 +1,80 @@
+use super::*;
+use crate::blake3::blake3_chip::{Blake3Chip, Blake3Config};
+use midnight_proofs::circuit::SimpleFloorPlanner;
+use midnight_proofs::plonk::{Circuit, Instance};
+use std::array;
+
+/// Circuit that hashes an input using the BLAKE3 chip, and constrains the digest to equal the
+/// public inputs.
+#[derive(Clone, Debug)]
+pub(crate) struct Blake3Circuit<F: PrimeField> {
+    input: Vec<Value<F>>,
+}
+
+impl<F: PrimeField> Blake3Circuit<F> {
+    pub(crate) fn new(input: Vec<Value<F>>) -> Self {
+        Self { input }
+    }
+
+    /// The inputs are stored in the limb columns, eight bytes per row.
+    fn assign_inputs_to_the_trace(
+        config: &Blake3Config,
+        layouter: &mut impl Layouter<F>,
+        input: &[Value<F>],
+    ) -> Result<Vec<AssignedNative<F>>, Error> {
+        layouter.assign_region(
+            || "Inputs",
+            |mut region| {
+                input
+                    .iter()
+                    .enumerate()
+                    .map(|(index, input_byte)| {
+                        region.assign_advice(
+                            || "Input byte",
+                            config.limbs[index % 8],
+                            index / 8,
+                            || *input_byte,
+                        )
+                    })
+                    .collect()
+            },
+        )
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for Blake3Circuit<F> {
+    type Config = (Blake3Config, Column<Instance>);
+    type Params = ();
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::new(vec![Value::unknown(); self.input.len()])
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let full_number_u64 = meta.advice_column();
+        let limbs: [Column<Advice>; 8] = array::from_fn(|_| meta.advice_column());
+        let constant_col = meta.fixed_column();
+        let expected_digest = meta.instance_column();
+        meta.enable_equality(expected_digest);
+        (Blake3Chip::configure(meta, constant_col, full_number_u64, limbs), expected_digest)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let assigned_input =
+            Self::assign_inputs_to_the_trace(&config.0, &mut layouter, &self.input)?;
+
+        let chip = Blake3Chip::new(&config.0);
+        chip.load(&mut layouter)?;
+        let result = chip.hash(&mut layouter, &assigned_input)?;
+
+        for (i, digest_byte_cell) in result.iter().enumerate() {
+            layouter.constrain_instance(digest_byte_cell.cell(), config.1, i)?;
+        }
+        Ok(())
+    }
+}
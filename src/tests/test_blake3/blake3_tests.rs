@@ -0,0 +1,60 @@
+use super::*;
+use crate::tests::test_blake3::blake3_circuit::Blake3Circuit;
+use ::blake3::hash;
+
+/// BLAKE3 of the empty input, from the test vectors of the reference implementation
+const EMPTY_INPUT_DIGEST: &str = "af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262";
+
+#[test]
+fn test_blake3_of_empty_input_matches_the_reference_vector() {
+    let expected_output = hex::decode(EMPTY_INPUT_DIGEST).unwrap();
+    verify_circuit(&blake3_circuit(&[]), &expected_output);
+}
+
+#[test]
+fn test_blake3_of_single_chunk_inputs_matches_the_reference() {
+    // Lengths of the test vectors of the reference implementation around the block boundaries
+    for input_length in [1, 64, 65, 1023, 1024] {
+        run_blake3_test(&test_vector_input(input_length));
+    }
+}
+
+#[test]
+fn test_blake3_of_multi_chunk_inputs_matches_the_reference() {
+    // Two chunks, a full tree of two chunks plus a partial chunk, and a full tree of four chunks
+    for input_length in [1025, 2049, 4096] {
+        run_blake3_test(&test_vector_input(input_length));
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_blake3_fails_with_a_wrong_digest() {
+    let mut expected_output = hex::decode(EMPTY_INPUT_DIGEST).unwrap();
+    expected_output[31] ^= 1;
+    verify_circuit(&blake3_circuit(&[]), &expected_output);
+}
+
+#[test]
+#[should_panic]
+fn test_blake3_fails_with_the_digest_of_a_single_chunk() {
+    // The first chunk of a two-chunk input isn't the root, so its output isn't the digest
+    let input = test_vector_input(1025);
+    let expected_output = hash(&input[..1024]).as_bytes().to_vec();
+    verify_circuit(&blake3_circuit(&input), &expected_output);
+}
+
+fn run_blake3_test(input: &[u8]) {
+    let expected_output = hash(input).as_bytes().to_vec();
+    verify_circuit(&blake3_circuit(input), &expected_output);
+}
+
+/// Input of the test vectors of the reference implementation, a repeating sequence of the bytes
+/// from 0 to 250
+fn test_vector_input(length: usize) -> Vec<u8> {
+    (0..length).map(|i| (i % 251) as u8).collect()
+}
+
+fn blake3_circuit(input: &[u8]) -> Blake3Circuit<Fq> {
+    Blake3Circuit::new(input.iter().map(|x| value_for(*x)).collect())
+}
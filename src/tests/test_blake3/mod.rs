@@ -0,0 +1,4 @@
+use super::*;
+
+mod blake3_circuit;
+mod blake3_tests;
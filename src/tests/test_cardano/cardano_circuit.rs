@@ -0,0 +1,160 @@
+use super::*;
+use crate::cardano::cardano_chip::{CardanoChip, CardanoConfig};
+use crate::cardano::credential::{Credential, ScriptLanguage};
+use crate::types::byte::AssignedByte;
+use midnight_proofs::circuit::SimpleFloorPlanner;
+use midnight_proofs::plonk::{Circuit, Instance};
+use std::array;
+
+/// Private input from which a credential is obtained in the circuit.
+#[derive(Clone, Debug)]
+pub(crate) enum CredentialInput<F: PrimeField> {
+    /// The credential is the key hash of this verification key.
+    VerificationKey(Vec<Value<F>>),
+    /// The credential is the hash of this script.
+    Script(ScriptLanguage, Vec<Value<F>>),
+    /// The credential is this script hash.
+    ScriptHash(Vec<Value<F>>),
+}
+
+impl<F: PrimeField> CredentialInput<F> {
+    fn without_witnesses(&self) -> Self {
+        let unknown = |values: &Vec<Value<F>>| vec![Value::unknown(); values.len()];
+        match self {
+            Self::VerificationKey(key) => Self::VerificationKey(unknown(key)),
+            Self::Script(language, script) => Self::Script(*language, unknown(script)),
+            Self::ScriptHash(hash) => Self::ScriptHash(unknown(hash)),
+        }
+    }
+}
+
+/// Kind of the address built in the circuit, with the inputs of its credentials.
+#[derive(Clone, Debug)]
+pub(crate) enum AddressInput<F: PrimeField> {
+    Base(CredentialInput<F>, CredentialInput<F>),
+    Enterprise(CredentialInput<F>),
+    Reward(CredentialInput<F>),
+}
+
+/// Circuit that builds a Shelley address from private credential inputs and constrains its bytes
+/// to equal the public inputs.
+#[derive(Clone, Debug)]
+pub(crate) struct CardanoAddressCircuit<F: PrimeField> {
+    network_id: u8,
+    address: AddressInput<F>,
+}
+
+impl<F: PrimeField> CardanoAddressCircuit<F> {
+    pub(crate) fn new(network_id: u8, address: AddressInput<F>) -> Self {
+        Self {
+            network_id,
+            address,
+        }
+    }
+
+    /// The inputs are stored in the limb columns, eight bytes per row.
+    fn assign_inputs_to_the_trace(
+        config: &CardanoConfig,
+        layouter: &mut impl Layouter<F>,
+        input: &[Value<F>],
+    ) -> Result<Vec<AssignedNative<F>>, Error> {
+        layouter.assign_region(
+            || "Inputs",
+            |mut region| {
+                input
+                    .iter()
+                    .enumerate()
+                    .map(|(index, input_byte)| {
+                        region.assign_advice(
+                            || "Input byte",
+                            config.limbs[index % 8],
+                            index / 8,
+                            || *input_byte,
+                        )
+                    })
+                    .collect()
+            },
+        )
+    }
+
+    fn credential(
+        chip: &CardanoChip<F>,
+        config: &CardanoConfig,
+        layouter: &mut impl Layouter<F>,
+        input: &CredentialInput<F>,
+    ) -> Result<Credential<F>, Error> {
+        let to_native = |hash: [AssignedByte<F>; 28]| hash.map(|byte| byte.to_native());
+        Ok(match input {
+            CredentialInput::VerificationKey(key) => {
+                let key = Self::assign_inputs_to_the_trace(config, layouter, key)?;
+                Credential::Key(to_native(chip.key_hash(layouter, &key)?))
+            }
+            CredentialInput::Script(language, script) => {
+                let script = Self::assign_inputs_to_the_trace(config, layouter, script)?;
+                Credential::Script(to_native(chip.script_hash(layouter, *language, &script)?))
+            }
+            CredentialInput::ScriptHash(hash) => {
+                let hash = Self::assign_inputs_to_the_trace(config, layouter, hash)?;
+                Credential::Script(hash.try_into().unwrap())
+            }
+        })
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for CardanoAddressCircuit<F> {
+    type Config = (CardanoConfig, Column<Instance>);
+    type Params = ();
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        let address = match &self.address {
+            AddressInput::Base(payment, stake) => {
+                AddressInput::Base(payment.without_witnesses(), stake.without_witnesses())
+            }
+            AddressInput::Enterprise(payment) => {
+                AddressInput::Enterprise(payment.without_witnesses())
+            }
+            AddressInput::Reward(stake) => AddressInput::Reward(stake.without_witnesses()),
+        };
+        Self::new(self.network_id, address)
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let full_number_u64 = meta.advice_column();
+        let limbs: [Column<Advice>; 8] = array::from_fn(|_| meta.advice_column());
+        let constant_col = meta.fixed_column();
+        let expected_address = meta.instance_column();
+        meta.enable_equality(expected_address);
+        (CardanoChip::configure(meta, constant_col, full_number_u64, limbs), expected_address)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let chip = CardanoChip::new(&config.0);
+        chip.load(&mut layouter)?;
+
+        let address = match &self.address {
+            AddressInput::Base(payment, stake) => {
+                let payment = Self::credential(&chip, &config.0, &mut layouter, payment)?;
+                let stake = Self::credential(&chip, &config.0, &mut layouter, stake)?;
+                chip.base_address(&mut layouter, self.network_id, &payment, &stake)?
+            }
+            AddressInput::Enterprise(payment) => {
+                let payment = Self::credential(&chip, &config.0, &mut layouter, payment)?;
+                chip.enterprise_address(&mut layouter, self.network_id, &payment)?
+            }
+            AddressInput::Reward(stake) => {
+                let stake = Self::credential(&chip, &config.0, &mut layouter, stake)?;
+                chip.reward_address(&mut layouter, self.network_id, &stake)?
+            }
+        };
+
+        for (i, address_byte_cell) in address.iter().enumerate() {
+            layouter.constrain_instance(address_byte_cell.cell(), config.1, i)?;
+        }
+        Ok(())
+    }
+}
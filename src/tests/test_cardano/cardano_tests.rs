@@ -0,0 +1,138 @@
+use super::*;
+use crate::cardano::credential::{ScriptLanguage, MAINNET_NETWORK_ID, TESTNET_NETWORK_ID};
+use crate::tests::test_cardano::cardano_circuit::{
+    AddressInput, CardanoAddressCircuit, CredentialInput,
+};
+use blake2b_simd::Params;
+use midnight_proofs::dev::MockProver;
+
+// Keys, script hash and mainnet addresses of the test vectors of CIP-19
+const PAYMENT_VERIFICATION_KEY: &str =
+    "addr_vk1w0l2sr2zgfm26ztc6nl9xy8ghsk5sh6ldwemlpmp9xylzy4dtf7st80zhd";
+const STAKE_VERIFICATION_KEY: &str =
+    "stake_vk1px4j0r2fk7ux5p23shz8f3y5y2qam7s954rgf3lg5merqcj6aetsft99wu";
+const SCRIPT_HASH: &str = "script1cda3khwqv60360rp5m7akt50m6ttapacs8rqhn5w342z7r35m37";
+
+const BASE_ADDRESS_KEY_KEY: &str = "addr1qx2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzer3n0d3vllmyqwsx5wktcd8cc3sq835lu7drv2xwl2wywfgse35a3x";
+const BASE_ADDRESS_SCRIPT_KEY: &str = "addr1z8phkx6acpnf78fuvxn0mkew3l0fd058hzquvz7w36x4gten0d3vllmyqwsx5wktcd8cc3sq835lu7drv2xwl2wywfgs9yc0hh";
+const BASE_ADDRESS_KEY_SCRIPT: &str = "addr1yx2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzerkr0vd4msrxnuwnccdxlhdjar77j6lg0wypcc9uar5d2shs2z78ve";
+const BASE_ADDRESS_SCRIPT_SCRIPT: &str = "addr1x8phkx6acpnf78fuvxn0mkew3l0fd058hzquvz7w36x4gt7r0vd4msrxnuwnccdxlhdjar77j6lg0wypcc9uar5d2shskhj42g";
+const ENTERPRISE_ADDRESS_KEY: &str = "addr1vx2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzers66hrl8";
+const ENTERPRISE_ADDRESS_SCRIPT: &str =
+    "addr1w8phkx6acpnf78fuvxn0mkew3l0fd058hzquvz7w36x4gtcyjy7wx";
+const REWARD_ADDRESS_KEY: &str = "stake1uyehkck0lajq8gr28t9uxnuvgcqrc6070x3k9r8048z8y5gh6ffgw";
+const REWARD_ADDRESS_SCRIPT: &str = "stake178phkx6acpnf78fuvxn0mkew3l0fd058hzquvz7w36x4gtcccycj5";
+
+#[test]
+fn test_base_address_with_key_credentials_matches_mainnet() {
+    let address = AddressInput::Base(payment_key(), stake_key());
+    run_address_test(MAINNET_NETWORK_ID, address, &bech32_bytes(BASE_ADDRESS_KEY_KEY));
+}
+
+#[test]
+fn test_base_address_with_a_script_payment_credential_matches_mainnet() {
+    let address = AddressInput::Base(script_hash(), stake_key());
+    run_address_test(MAINNET_NETWORK_ID, address, &bech32_bytes(BASE_ADDRESS_SCRIPT_KEY));
+}
+
+#[test]
+fn test_base_address_with_a_script_stake_credential_matches_mainnet() {
+    let address = AddressInput::Base(payment_key(), script_hash());
+    run_address_test(MAINNET_NETWORK_ID, address, &bech32_bytes(BASE_ADDRESS_KEY_SCRIPT));
+}
+
+#[test]
+fn test_base_address_with_script_credentials_matches_mainnet() {
+    let address = AddressInput::Base(script_hash(), script_hash());
+    run_address_test(MAINNET_NETWORK_ID, address, &bech32_bytes(BASE_ADDRESS_SCRIPT_SCRIPT));
+}
+
+#[test]
+fn test_enterprise_address_with_a_key_credential_matches_mainnet() {
+    let address = AddressInput::Enterprise(payment_key());
+    run_address_test(MAINNET_NETWORK_ID, address, &bech32_bytes(ENTERPRISE_ADDRESS_KEY));
+}
+
+#[test]
+fn test_enterprise_address_with_a_script_credential_matches_mainnet() {
+    let address = AddressInput::Enterprise(script_hash());
+    run_address_test(MAINNET_NETWORK_ID, address, &bech32_bytes(ENTERPRISE_ADDRESS_SCRIPT));
+}
+
+#[test]
+fn test_reward_address_with_a_key_credential_matches_mainnet() {
+    let address = AddressInput::Reward(stake_key());
+    run_address_test(MAINNET_NETWORK_ID, address, &bech32_bytes(REWARD_ADDRESS_KEY));
+}
+
+#[test]
+fn test_reward_address_with_a_script_credential_matches_mainnet() {
+    let address = AddressInput::Reward(script_hash());
+    run_address_test(MAINNET_NETWORK_ID, address, &bech32_bytes(REWARD_ADDRESS_SCRIPT));
+}
+
+#[test]
+fn test_script_hashes_are_prefixed_with_the_language_tag() {
+    // A native script requiring the signature of the payment key: [0, payment key hash]
+    let mut script = vec![0x82, 0x00, 0x58, 0x1c];
+    script.extend(&bech32_bytes(BASE_ADDRESS_KEY_KEY)[1..29]);
+    for language in [ScriptLanguage::Native, ScriptLanguage::PlutusV3] {
+        let mut expected_address = vec![0x70];
+        expected_address.extend(reference_script_hash(language, &script));
+        let address = AddressInput::Enterprise(CredentialInput::Script(language, values(&script)));
+        run_address_test(TESTNET_NETWORK_ID, address, &expected_address);
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_base_address_fails_with_swapped_keys() {
+    let address = AddressInput::Base(stake_key(), payment_key());
+    run_address_test(MAINNET_NETWORK_ID, address, &bech32_bytes(BASE_ADDRESS_KEY_KEY));
+}
+
+#[test]
+#[should_panic]
+fn test_base_address_fails_with_the_wrong_network() {
+    let address = AddressInput::Base(payment_key(), stake_key());
+    run_address_test(TESTNET_NETWORK_ID, address, &bech32_bytes(BASE_ADDRESS_KEY_KEY));
+}
+
+#[test]
+#[should_panic(expected = "A verification key must have 32 bytes")]
+fn test_key_hash_fails_with_a_short_verification_key() {
+    let key = &bech32_bytes(PAYMENT_VERIFICATION_KEY)[..31];
+    let address = AddressInput::Enterprise(CredentialInput::VerificationKey(values(key)));
+    run_address_test(MAINNET_NETWORK_ID, address, &bech32_bytes(ENTERPRISE_ADDRESS_KEY));
+}
+
+fn payment_key() -> CredentialInput<Fq> {
+    CredentialInput::VerificationKey(values(&bech32_bytes(PAYMENT_VERIFICATION_KEY)))
+}
+
+fn stake_key() -> CredentialInput<Fq> {
+    CredentialInput::VerificationKey(values(&bech32_bytes(STAKE_VERIFICATION_KEY)))
+}
+
+fn script_hash() -> CredentialInput<Fq> {
+    CredentialInput::ScriptHash(values(&bech32_bytes(SCRIPT_HASH)))
+}
+
+/// Data of a bech32 string, as 8-bit bytes
+fn bech32_bytes(string: &str) -> Vec<u8> {
+    bech32::decode(string).unwrap().1
+}
+
+fn reference_script_hash(language: ScriptLanguage, script: &[u8]) -> Vec<u8> {
+    let mut input = vec![language.tag()];
+    input.extend_from_slice(script);
+    Params::new().hash_length(28).hash(&input).as_bytes().to_vec()
+}
+
+fn run_address_test(network_id: u8, address: AddressInput<Fq>, expected_address: &[u8]) {
+    let circuit = CardanoAddressCircuit::new(network_id, address);
+    let expected_address: Vec<Fq> =
+        expected_address.iter().map(|byte| Fq::from(*byte as u64)).collect();
+    let prover = MockProver::run(17, &circuit, vec![expected_address]).unwrap();
+    prover.verify().unwrap();
+}
@@ -0,0 +1,6 @@
+use super::*;
+
+mod cardano_circuit;
+mod cardano_tests;
+mod transaction_id_circuit;
+mod transaction_id_tests;
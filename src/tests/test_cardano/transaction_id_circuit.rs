@@ -0,0 +1,110 @@
+use super::*;
+use crate::cardano::cardano_chip::{CardanoChip, CardanoConfig};
+use crate::cardano::cbor::CborField;
+use midnight_proofs::circuit::SimpleFloorPlanner;
+use midnight_proofs::plonk::{Circuit, Instance};
+use std::array;
+
+/// Circuit that computes the id of a private transaction body and reads some of its fields. The
+/// id, the contents of the byte string fields and the values of the integer fields are
+/// constrained to equal the public inputs, in that order.
+#[derive(Clone, Debug)]
+pub(crate) struct TransactionIdCircuit<F: PrimeField> {
+    body: Vec<Value<F>>,
+    body_length: Value<F>,
+    byte_strings: Vec<CborField>,
+    integers: Vec<CborField>,
+}
+
+impl<F: PrimeField> TransactionIdCircuit<F> {
+    pub(crate) fn new(
+        body: Vec<Value<F>>,
+        body_length: Value<F>,
+        byte_strings: Vec<CborField>,
+        integers: Vec<CborField>,
+    ) -> Self {
+        Self {
+            body,
+            body_length,
+            byte_strings,
+            integers,
+        }
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for TransactionIdCircuit<F> {
+    type Config = (CardanoConfig, Column<Instance>);
+    type Params = ();
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::new(
+            vec![Value::unknown(); self.body.len()],
+            Value::unknown(),
+            self.byte_strings.clone(),
+            self.integers.clone(),
+        )
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let full_number_u64 = meta.advice_column();
+        let limbs: [Column<Advice>; 8] = array::from_fn(|_| meta.advice_column());
+        let constant_col = meta.fixed_column();
+        let expected_outputs = meta.instance_column();
+        meta.enable_equality(expected_outputs);
+        (CardanoChip::configure(meta, constant_col, full_number_u64, limbs), expected_outputs)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let chip = CardanoChip::new(&config.0);
+        chip.load(&mut layouter)?;
+
+        // The body is stored in the limb columns, eight bytes per row, followed by its length
+        let (body, body_length) = layouter.assign_region(
+            || "Inputs",
+            |mut region| {
+                let body = self
+                    .body
+                    .iter()
+                    .enumerate()
+                    .map(|(index, body_byte)| {
+                        region.assign_advice(
+                            || "Body byte",
+                            config.0.limbs[index % 8],
+                            index / 8,
+                            || *body_byte,
+                        )
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                let body_length = region.assign_advice(
+                    || "Body length",
+                    config.0.limbs[0],
+                    self.body.len().div_ceil(8),
+                    || self.body_length,
+                )?;
+                Ok((body, body_length))
+            },
+        )?;
+
+        let mut outputs: Vec<AssignedNative<F>> = chip
+            .transaction_id(&mut layouter, &body, &body_length)?
+            .iter()
+            .map(|byte| byte.to_native())
+            .collect();
+        for field in &self.byte_strings {
+            outputs.extend(chip.cbor_bytes(&mut layouter, &body, &body_length, field)?);
+        }
+        for field in &self.integers {
+            outputs.push(chip.cbor_uint(&mut layouter, &body, &body_length, field)?);
+        }
+
+        for (i, output_cell) in outputs.iter().enumerate() {
+            layouter.constrain_instance(output_cell.cell(), config.1, i)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,207 @@
+use super::*;
+use crate::cardano::cbor::{locate_fee, locate_output_address, locate_output_coin, CborField};
+use crate::tests::test_cardano::transaction_id_circuit::TransactionIdCircuit;
+use blake2b_simd::Params;
+use midnight_proofs::dev::MockProver;
+
+const MAXIMUM_BODY_LENGTH: usize = 256;
+
+#[test]
+fn test_transaction_id_and_output_of_a_map_output() {
+    let body = transaction_body();
+    let address = locate_output_address(&body, 0);
+    assert_eq!(address.length, 59);
+    run_transaction_id_test(
+        &body,
+        body.len(),
+        vec![address],
+        vec![locate_output_coin(&body, 0), locate_fee(&body)],
+        &[&base_address()[..]],
+        &[2_000_000, 170_000],
+    );
+}
+
+#[test]
+fn test_transaction_id_and_output_of_a_legacy_output_with_assets() {
+    let body = transaction_body();
+    run_transaction_id_test(
+        &body,
+        body.len(),
+        vec![locate_output_address(&body, 1)],
+        vec![locate_output_coin(&body, 1)],
+        &[&enterprise_address()[..]],
+        &[1_500_000],
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_transaction_id_fails_with_the_wrong_body_length() {
+    let body = transaction_body();
+    let mut longer_body = body.clone();
+    longer_body.push(0);
+    let mut circuit_body = body.clone();
+    circuit_body.resize(MAXIMUM_BODY_LENGTH, 0);
+    let circuit = TransactionIdCircuit::new(
+        values(&circuit_body),
+        value_for(longer_body.len() as u64),
+        vec![],
+        vec![],
+    );
+    let prover = MockProver::run(17, &circuit, vec![bytes_to_fields(&transaction_id(&body))]);
+    prover.unwrap().verify().unwrap();
+}
+
+#[test]
+#[should_panic]
+fn test_cbor_uint_fails_with_the_wrong_amount() {
+    let body = transaction_body();
+    run_transaction_id_test(
+        &body,
+        body.len(),
+        vec![],
+        vec![locate_output_coin(&body, 0)],
+        &[],
+        &[2_000_001],
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_cbor_bytes_fails_with_a_field_in_another_place() {
+    let body = transaction_body();
+    let address = locate_output_address(&body, 0);
+    let moved_address = CborField {
+        offset: address.offset + 1,
+        ..address
+    };
+    run_transaction_id_test(
+        &body,
+        body.len(),
+        vec![moved_address],
+        vec![],
+        &[&base_address()],
+        &[],
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_cbor_bytes_fails_with_a_field_past_the_end_of_the_body() {
+    // A body cut in the middle of the address, whose last bytes are zero, would otherwise be read
+    // from the padding
+    let mut body = transaction_body();
+    let address = locate_output_address(&body, 0);
+    let end = address.offset + address.length;
+    body[end - 4..end].fill(0);
+    let mut address_bytes = base_address();
+    address_bytes[53..].fill(0);
+    run_transaction_id_test(
+        &body[..end - 4],
+        end - 4,
+        vec![address],
+        vec![],
+        &[&address_bytes],
+        &[],
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_cbor_bytes_fails_with_the_field_inside_another_item() {
+    // The forged body has the header and the bytes of the address at the same offset, but inside
+    // the id of its input, and its only output pays another address
+    let body = transaction_body();
+    let address = locate_output_address(&body, 0);
+    let forged_body = forged_transaction_body(address.offset + address.length);
+    assert_eq!(
+        forged_body[address.offset..address.offset + address.length],
+        body[address.offset..address.offset + address.length]
+    );
+    run_transaction_id_test(
+        &forged_body,
+        forged_body.len(),
+        vec![address],
+        vec![],
+        &[&base_address()],
+        &[],
+    );
+}
+
+/// A Conway transaction body spending a single input, with an output encoded as a map, an output
+/// encoded as an array that also holds other assets, and a fee.
+fn transaction_body() -> Vec<u8> {
+    let mut body = vec![0xa3];
+    // Inputs: a set with a single transaction id and index
+    body.extend([0x00, 0xd9, 0x01, 0x02, 0x81, 0x82, 0x58, 0x20]);
+    body.extend((0..32).map(|i| 0xa0 + i as u8));
+    body.push(0x00);
+    // Outputs
+    body.extend([0x01, 0x82]);
+    body.extend([0xa2, 0x00, 0x58, 0x39]);
+    body.extend(base_address());
+    body.extend([0x01, 0x1a, 0x00, 0x1e, 0x84, 0x80]);
+    body.extend([0x82, 0x58, 0x1d]);
+    body.extend(enterprise_address());
+    body.extend([0x82, 0x1a, 0x00, 0x16, 0xe3, 0x60, 0xa1, 0x41, 0x00, 0xa1, 0x40, 0x01]);
+    // Fee
+    body.extend([0x02, 0x1a, 0x00, 0x02, 0x98, 0x10]);
+    body
+}
+
+/// A transaction body whose input id is the first 'end' bytes of [transaction_body] after the
+/// header of the id, and whose only output pays the enterprise address.
+fn forged_transaction_body(end: usize) -> Vec<u8> {
+    let honest_body = transaction_body();
+    let mut body = vec![0xa3];
+    body.extend([0x00, 0xd9, 0x01, 0x02, 0x81, 0x82, 0x58, (end - 9) as u8]);
+    body.extend(&honest_body[9..end]);
+    body.push(0x00);
+    body.extend([0x01, 0x81, 0x82, 0x58, 0x1d]);
+    body.extend(enterprise_address());
+    body.extend([0x1a, 0x00, 0x1e, 0x84, 0x80]);
+    body.extend([0x02, 0x1a, 0x00, 0x02, 0x98, 0x10]);
+    body
+}
+
+fn base_address() -> Vec<u8> {
+    let mut address = vec![0x01];
+    address.extend((0..56).map(|i| (7 * i + 3) as u8));
+    address
+}
+
+fn enterprise_address() -> Vec<u8> {
+    let mut address = vec![0x61];
+    address.extend((0..28).map(|i| (11 * i + 5) as u8));
+    address
+}
+
+fn transaction_id(body: &[u8]) -> Vec<u8> {
+    Params::new().hash_length(32).hash(body).as_bytes().to_vec()
+}
+
+fn run_transaction_id_test(
+    body: &[u8],
+    body_length: usize,
+    byte_strings: Vec<CborField>,
+    integers: Vec<CborField>,
+    expected_byte_strings: &[&[u8]],
+    expected_integers: &[u64],
+) {
+    let mut expected_outputs = bytes_to_fields(&transaction_id(&body[..body_length]));
+    for bytes in expected_byte_strings {
+        expected_outputs.extend(bytes_to_fields(bytes));
+    }
+    expected_outputs.extend(expected_integers.iter().map(|integer| Fq::from(*integer)));
+
+    let mut padded_body = body.to_vec();
+    padded_body.resize(MAXIMUM_BODY_LENGTH, 0);
+    let circuit = TransactionIdCircuit::new(
+        values(&padded_body),
+        value_for(body_length as u64),
+        byte_strings,
+        integers,
+    );
+    let prover = MockProver::run(17, &circuit, vec![expected_outputs]).unwrap();
+    prover.verify().unwrap();
+}
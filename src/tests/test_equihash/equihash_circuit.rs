@@ -0,0 +1,79 @@
+use super::*;
+use crate::equihash::equihash_chip::{EquihashChip, EquihashConfig};
+use midnight_proofs::circuit::SimpleFloorPlanner;
+use midnight_proofs::plonk::Circuit;
+use std::array;
+
+/// Circuit that verifies that the private indices are a solution of the puzzle of the private
+/// header.
+#[derive(Clone, Debug)]
+pub(crate) struct EquihashCircuit<F: PrimeField> {
+    header: Vec<Value<F>>,
+    solution: Vec<Value<F>>,
+}
+
+impl<F: PrimeField> EquihashCircuit<F> {
+    pub(crate) fn new(header: Vec<Value<F>>, solution: Vec<Value<F>>) -> Self {
+        Self { header, solution }
+    }
+
+    /// The inputs are stored in the limb columns, eight values per row.
+    fn assign_inputs_to_the_trace(
+        config: &EquihashConfig,
+        layouter: &mut impl Layouter<F>,
+        input: &[Value<F>],
+    ) -> Result<Vec<AssignedNative<F>>, Error> {
+        layouter.assign_region(
+            || "Inputs",
+            |mut region| {
+                input
+                    .iter()
+                    .enumerate()
+                    .map(|(index, input_value)| {
+                        region.assign_advice(
+                            || "Input value",
+                            config.limbs[index % 8],
+                            index / 8,
+                            || *input_value,
+                        )
+                    })
+                    .collect()
+            },
+        )
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for EquihashCircuit<F> {
+    type Config = EquihashConfig;
+    type Params = ();
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            header: vec![Value::unknown(); self.header.len()],
+            solution: vec![Value::unknown(); self.solution.len()],
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let full_number_u64 = meta.advice_column();
+        let limbs: [Column<Advice>; 8] = array::from_fn(|_| meta.advice_column());
+        let constant_col = meta.fixed_column();
+        EquihashChip::configure(meta, constant_col, full_number_u64, limbs)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let assigned_header =
+            Self::assign_inputs_to_the_trace(&config, &mut layouter, &self.header)?;
+        let assigned_solution =
+            Self::assign_inputs_to_the_trace(&config, &mut layouter, &self.solution)?;
+
+        let chip = EquihashChip::new(&config);
+        chip.load(&mut layouter)?;
+        chip.verify_solution(&mut layouter, &assigned_header, &assigned_solution)
+    }
+}
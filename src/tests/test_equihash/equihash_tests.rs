@@ -0,0 +1,139 @@
+use super::*;
+use crate::equihash::solution::indices_from_minimal;
+use crate::tests::test_equihash::equihash_circuit::EquihashCircuit;
+
+/// Input of the puzzle, a 140-byte header whose nonce is a little endian 4 in the bytes 108 to 112,
+/// and the minimal encoding of a solution for it, found with Wagner's algorithm.
+const HEADER: &str = concat!(
+    "030a11181f262d343b424950575e656c737a81888f969da4abb2b9c0c7ced5dce3eaf1f8ff060d141b222930",
+    "373e454c535a61686f767d848b9299a0a7aeb5bcc3cad1d8dfe6edf4fb020910171e252c333a41484f565d64",
+    "6b727980878e959ca3aab1b8bfc6cdd4dbe2e9f0040000000000000000000000000000000000000000000000",
+    "0000000000000000",
+);
+const MINIMAL_SOLUTION: &str = concat!(
+    "01121bac2543de302485441026d7da29616e3e774e13ac728128db6f16efcde71895781af9fd92de5510080a",
+    "0cca8da05c6d6994a2f747229fbcc7043119f62b59d56ca3932af5563083649d36cead445e96d5e10ea899dd",
+    "f4c3ff018a7f12cf7fb43700d3e4d15375188871e7d6d8c44b8b77a5a24855e6f5db4f93dcec0edee51922e2",
+    "daf7c6e388340464be52272b76bbc01c97a7ee5fde129d3a08c30d969c4b2cdafa49a8770281e102ec979d39",
+    "c340b39eea5b2ae0e8cb9ab7f81edacfa4bc19bdff09ccd2024d9d6e7d065c3bc0d421e79669cf15a0d6e6f5",
+    "122787cf6b1e711b3a883a2649d4b2cfe46eb992bc22a9047e8e6cd00c39e8b70642035858659537a7b25196",
+    "bf0ea6f2aef51c1d5b3fee53cd98596965e27185031678a6f9e04df72ae51c0cb46e1bd764a74b21957593f4",
+    "92f1b549516d9d54e3433ebe1f7baf8ceaaaa93c599e82d07cfa0e0209810f9fd09d3e5f954fa32c7ceeddf1",
+    "bf9ebd493a15eac93c56125228e3fb51b572e670a9e52435c8a816dcb92281521dce9621b17d3ef83728d9fe",
+    "142b7e36f98ed72b54df699dd7696aacd38d9ef1d81c57260b72bc31d987516139241101296e91b46e9636fb",
+    "a6210573f6b39b4209c3ded338533838b56c8abb3f451653bd2172a92047b898f2c15276a3dcd06476fc5824",
+    "d453424eeb294ff0c7e5f673db1b19e3e8d50e670d909f51e408e536ac7958040ddfa3c6e3557c5d721594fb",
+    "20951541d996d04305b25e76367a251e3cc816d54da9a2ddddab7173cc4e536d4bb719da78e48c8cc89d2195",
+    "add6b79427fa70eb589d26b813998ff71641d46b5b14c1e0ef8f2593a4c759bdeea936a03542e2ffbb19d66f",
+    "55590f256741e9294dc314ff1c221823fe3f4b97bf075cdd12ef1b1a2cc66791fcef5a1ba1ed6bdbdbc81fa1",
+    "182377271cd23550c70efe15013180dac80c2d15fbe295c71c3170a6c5fabdef7c0832e168180e3954e68213",
+    "05ff687fc4c8a646efca177991a16c56cf21c57ac3b655629e695de9ce8ccf3489cc471e269abf92122784fa",
+    "fbbe7dfa8e97b2d509ef2b17ad10d267fc01c527c32badabd69fbf0f961c28c6261ef04d8fb67776398d626c",
+    "bdd980d518d035f552b45a21a64f8199e4bbf4d1a3da42f5d2e02b59700f12ea1e7f6b68d7885c64d668d228",
+    "a9ba00a806654e96efccab8b3cb4620214db4c2d95ffda2a2e19ccff36db2733d78bf6057beaf749219d7c92",
+    "2e2f21d55b6528e42b51f911041e66a4e0a1a92e0d6d92344741cc7299e967f1df0578e951f1d2ab5a38e882",
+    "0a0907de5bd43f211f3f50cd8733c992c78e5726f72af8399d8befd3b5c80a32f3c0af8665f08b1475922c5a",
+    "31a12164940d95e722dfc07f250637c31dccfd4babab1e6f23d2593a76166b707549430103995580025f356f",
+    "b5c0db72c932f3e9b0c72bed9069392b702049794702dc636fe1aa02e8e41c4b66269b387c4504e32f269e1d",
+    "9ae38322568917c2e4c5c25091d4ce2602f24898d6c2d34052d80edb7a470e6e4ef4b91504017829d545c0ab",
+    "1ee400c7c6771ee596439c1c21778a64d6e6e8197fbcde69bd3ef394f273923d719917ebf2386fe2e6c5a32e",
+    "f1a32223c43a8eb9fbf88823f68575f51fa3e1e8fdc7c07369f2d7086eb93794062587e95f06567c657be3b9",
+    "db3222dd2be09c9aa80c54be1ec7c3ec367d48c140b53e0918c8da38ea5e0a65f69cef6451b1d26c16b92ff1",
+    "25661d4eb7303016136b44c58f0262b2a493e993b1d2f9ea0dd889e610375b13bbdf1c1bd9ac263fb83c989a",
+    "87eb9f6ca428b7ab19ef77dc19efa846e544eab8c5c1f195b3af1aefc7a52b89414b0ec885a14fb2c9d1fa91",
+    "f99d86243f14d024cb69873ac8d7cda7cd4322303e558e55",
+);
+
+/// Header of the block 1 of the Zcash mainnet, whose hash is
+/// 0007bc227e1c57a4a70e237cad00e7b7ce565155ab49166bc57397a26d339283, and the minimal encoding of
+/// its solution, both taken from the serialized block.
+const ZCASH_BLOCK_1_HEADER: &str = concat!(
+    "0400000008ce3d9731b000c08338455c8a4a6bd05da16e26b11daa1b917184ece80f04000946edb9c083c994",
+    "2d92305444527765fad789c438c717783276a9f7fbf61b850000000000000000000000000000000000000000",
+    "000000000000000000000000ac7a1358ffff071f7534e8cf161ff2e49d54bdb3bfbcde8cdbf2fc5963c9ec7d",
+    "86aed4a67e975790",
+);
+const ZCASH_BLOCK_1_MINIMAL_SOLUTION: &str = concat!(
+    "002b2ee0d2f5d0c1ebf5a265b6f5b428f2fdc9aaea07078a6c5cab4f1bbfcd56489863deae6ea3fd8d3d0762",
+    "e8e5295ff2670c9e90d8e8c68a54a40927e82a65e1d44ced20d835818e172d7b7f5ffe0245d0c3860a3f11af",
+    "5658d68b6a7253b4684ffef5242fefa77a0bfc3437e8d94df9dc57510f5a128e676dd9ddf23f0ef75b460090",
+    "f507499585541ab53a470c547ea02723d3a979930941157792c4362e42d3b9faca342a5c05a56909b046b5e9",
+    "2e2870fca7c932ae2c2fdd97d75b6e0ecb501701c1250246093c73efc5ec2838aeb80b59577741aa5ccdf4a6",
+    "31b79f70fc419e28714fa22108d991c29052b2f5f72294c355b57504369313470ecdd8e0ae97fc48e243a38c",
+    "2ee7315bb05b7de9602047e97449c81e46746513221738dc729d7077a1771cea858865d85261e71e82003ccf",
+    "bba2416358f023251206d6ef4c5596bc35b2b5bce3e9351798aa2c9904723034e5815c7512d260cc957df5db",
+    "6adf9ed7272483312d1e68c60955a944e713355089876a704aef06359238f6de5a618f7bd0b4552ba72d05a6",
+    "165e582f62d55ff2e1b76991971689ba3bee16a520fd85380a6e5a31de4dd4654d561101ce0ca390862d5774",
+    "921eae2c284008692e9e08562144e8aa1f399a9d3fab0c4559c1f12bc945e626f7a89668613e8829767f4116",
+    "ee9a4f832cf7c3ade3a7aba8cb04de39edd94d0d05093ed642adf9fbd9d373a80832ffd1c62034e4341546b3",
+    "515f0e42e6d8570393c6754be5cdb7753b4709527d3f164aebf3d315934f7b3736a1b31052f6cc5699758950",
+    "331163b3df05b9772e9bf99c8c77f8960e10a15edb06200106f45742d740c422c86b7e4f5a52d3732aa79ee5",
+    "4cfc92f76e03c268ae226477c19924e733caf95b8f350233a5312f4ed349d3ad76f032358f83a6d0d6f83b2a",
+    "456742aad7f3e615fa72286300f0ea1c9793831ef3a5a4ae08640a6e32f53d1cba0be284b25e923d0d110ba2",
+    "27e54725632efcbbe17c05a9cde976504f6aece0c461b562cfae1b85d5f6782ee27b3e332ac0775f681682ce",
+    "524b32889f1dc4231226f1aada0703beaf8d41732c9647a0a940a86f8a1be7f239c44fcaa7ed7a055506bdbe",
+    "1df848f9e047226bee1b6d788a03f6e352eead99b419cfc41741942dbeb7a5c55788d5a3e636d8aab7b36b4d",
+    "b71d16700373bbc1cdeba8f9b1db10bf39a621bc737ea4f4e333698d6e09b51ac7a97fb6fd117ccad1d6b6b3",
+    "a7451699d5bfe448650396d7b58867b3b0872be13ad0b43da267df0ad77025155f04e20c56d6a9befb3e9c7d",
+    "23b82cbf3a534295ebda540682cc81be9273781b92519c858f9c25294fbacf75c3b3c15bda6d36de1c83336f",
+    "93e96910dbdcb190d6ef123c98565ff6df1e903f57d4e4df167ba6b829d6d9713eb2126b0cf869940204137b",
+    "abcc6a1b7cb2f0b94318a7460e5d1a605c249bd2e72123ebad332332c18adcb285ed8874dbde084ebcd4f744",
+    "465350d57110f037fffed1569d642c258749e65b0d13e117eaa37014a769b5ab479b7c77178880e77099f999",
+    "abe712e543dbbf626ca9bcfddc42ff2f109d21c8bd464894e55ae504fdf81e1a7694180225da7dac8879abd1",
+    "036cf26bb50532b8cf138b337a1a1bd1a43f8dd70b7399e2690c8e7a5a1fe099026b8f2a6f65fc0dbedda15b",
+    "a65e0abd66c7176fb426980549892b4817de78e345a7aeab05744c3def4a2f283b4255b02c91c1af7354a368",
+    "c67a11703c642a385c7453131ce3a78b24c5e22ab7e136a38498ce82082181884418cb4d6c2920f258a3ad20",
+    "cfbe7104af1c6c6cb5e58bf29a9901721ad19c0a260cd09a3a772443a45aea4a5c439a95834ef5dc2e263432",
+    "78947b7b796f796ae9bcadb29e2899a1d7313e6f7bfb6f8b",
+);
+
+#[test]
+fn test_equihash_accepts_a_valid_solution() {
+    verify_circuit_with_public_inputs(21, &equihash_circuit(&header(), &solution()), vec![]);
+}
+
+#[test]
+fn test_equihash_accepts_the_solution_of_a_zcash_mainnet_block() {
+    let header = hex::decode(ZCASH_BLOCK_1_HEADER).unwrap();
+    let solution = indices_from_minimal(&hex::decode(ZCASH_BLOCK_1_MINIMAL_SOLUTION).unwrap());
+    verify_circuit_with_public_inputs(21, &equihash_circuit(&header, &solution), vec![]);
+}
+
+#[test]
+#[should_panic]
+fn test_equihash_fails_with_the_solution_of_another_header() {
+    let mut header = header();
+    header[108] += 1;
+    verify_circuit_with_public_inputs(21, &equihash_circuit(&header, &solution()), vec![]);
+}
+
+#[test]
+#[should_panic]
+fn test_equihash_fails_with_unordered_subtrees() {
+    // The strings still collide, but the first index of the left half is now the largest one
+    let mut solution = solution();
+    solution.rotate_left(256);
+    verify_circuit_with_public_inputs(21, &equihash_circuit(&header(), &solution), vec![]);
+}
+
+#[test]
+#[should_panic(expected = "A solution must have 512 indices")]
+fn test_equihash_fails_with_a_solution_of_the_wrong_length() {
+    let mut solution = solution();
+    solution.pop();
+    verify_circuit_with_public_inputs(21, &equihash_circuit(&header(), &solution), vec![]);
+}
+
+fn header() -> Vec<u8> {
+    hex::decode(HEADER).unwrap()
+}
+
+fn solution() -> Vec<u32> {
+    indices_from_minimal(&hex::decode(MINIMAL_SOLUTION).unwrap())
+}
+
+fn equihash_circuit(header: &[u8], solution: &[u32]) -> EquihashCircuit<Fq> {
+    let header = header.iter().map(|byte| value_for(*byte)).collect();
+    let solution = solution.iter().map(|index| value_for(*index)).collect();
+    EquihashCircuit::new(header, solution)
+}
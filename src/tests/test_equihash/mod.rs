@@ -0,0 +1,4 @@
+use super::*;
+
+mod equihash_circuit;
+mod equihash_tests;
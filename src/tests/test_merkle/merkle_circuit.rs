@@ -0,0 +1,101 @@
+use super::*;
+use crate::merkle::merkle_chip::{MerkleChip, MerkleConfig, MerklePathLevel};
+use crate::merkle::params::MerkleTreeParams;
+use midnight_proofs::circuit::SimpleFloorPlanner;
+use midnight_proofs::plonk::{Circuit, Instance};
+use std::array;
+
+/// Circuit that computes the root of a tree from a private leaf and its private path, and
+/// constrains the root to equal the public inputs.
+#[derive(Clone, Debug)]
+pub(crate) struct MerkleCircuit<F: PrimeField> {
+    params: MerkleTreeParams,
+    leaf: Vec<Value<F>>,
+    path: Vec<(Value<usize>, Vec<Value<F>>)>,
+}
+
+impl<F: PrimeField> MerkleCircuit<F> {
+    pub(crate) fn new(
+        params: MerkleTreeParams,
+        leaf: Vec<Value<F>>,
+        path: Vec<(Value<usize>, Vec<Value<F>>)>,
+    ) -> Self {
+        Self { params, leaf, path }
+    }
+
+    /// The inputs are stored in the limb columns, eight bytes per row.
+    fn assign_inputs_to_the_trace(
+        config: &MerkleConfig,
+        layouter: &mut impl Layouter<F>,
+        input: &[Value<F>],
+    ) -> Result<Vec<AssignedNative<F>>, Error> {
+        layouter.assign_region(
+            || "Inputs",
+            |mut region| {
+                input
+                    .iter()
+                    .enumerate()
+                    .map(|(index, input_byte)| {
+                        region.assign_advice(
+                            || "Input byte",
+                            config.limbs[index % 8],
+                            index / 8,
+                            || *input_byte,
+                        )
+                    })
+                    .collect()
+            },
+        )
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for MerkleCircuit<F> {
+    type Config = (MerkleConfig, Column<Instance>);
+    type Params = ();
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        let path = self
+            .path
+            .iter()
+            .map(|(_, siblings)| (Value::unknown(), vec![Value::unknown(); siblings.len()]))
+            .collect();
+        Self::new(self.params.clone(), vec![Value::unknown(); self.leaf.len()], path)
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let full_number_u64 = meta.advice_column();
+        let limbs: [Column<Advice>; 8] = array::from_fn(|_| meta.advice_column());
+        let constant_col = meta.fixed_column();
+        let expected_root = meta.instance_column();
+        meta.enable_equality(expected_root);
+        (MerkleChip::configure(meta, constant_col, full_number_u64, limbs), expected_root)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let chip = MerkleChip::new(&config.0);
+        chip.load(&mut layouter)?;
+
+        let leaf = Self::assign_inputs_to_the_trace(&config.0, &mut layouter, &self.leaf)?;
+        let path = self
+            .path
+            .iter()
+            .map(|(position, siblings)| {
+                Ok(MerklePathLevel {
+                    siblings: Self::assign_inputs_to_the_trace(&config.0, &mut layouter, siblings)?,
+                    position: *position,
+                })
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let root = chip.compute_root(&mut layouter, &self.params, &leaf, &path)?;
+        for (i, root_byte_cell) in root.iter().enumerate() {
+            layouter.constrain_instance(root_byte_cell.cell(), config.1, i)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,84 @@
+use super::*;
+use crate::merkle::params::MerkleTreeParams;
+use crate::tests::test_merkle::merkle_circuit::MerkleCircuit;
+use midnight_proofs::dev::MockProver;
+
+#[test]
+fn test_binary_tree_membership() {
+    let params = MerkleTreeParams::new(3, 2);
+    let leaves = leaves(params.leaf_count(), 40);
+    let path = params.path(&leaves, 5);
+    run_merkle_test(&params, &leaves[5], path, &params.root(&leaves));
+}
+
+#[test]
+fn test_binary_tree_membership_with_domain_separation_for_every_leaf() {
+    let params = MerkleTreeParams::new(2, 2).leaf_prefix(&[0x00]).node_prefix(&[0x01]);
+    let leaves = leaves(params.leaf_count(), 16);
+    for index in 0..params.leaf_count() {
+        let path = params.path(&leaves, index);
+        run_merkle_test(&params, &leaves[index], path, &params.root(&leaves));
+    }
+}
+
+#[test]
+fn test_trinary_tree_membership_for_every_position() {
+    let params = MerkleTreeParams::new(2, 3).leaf_prefix(b"leaf").node_prefix(b"node");
+    let leaves = leaves(params.leaf_count(), 32);
+    for index in [0, 4, 8] {
+        let path = params.path(&leaves, index);
+        run_merkle_test(&params, &leaves[index], path, &params.root(&leaves));
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_membership_fails_with_the_wrong_position() {
+    let params = MerkleTreeParams::new(2, 2);
+    let leaves = leaves(params.leaf_count(), 16);
+    let mut path = params.path(&leaves, 0);
+    path[1].0 = 1;
+    run_merkle_test(&params, &leaves[0], path, &params.root(&leaves));
+}
+
+#[test]
+#[should_panic]
+fn test_membership_fails_with_a_leaf_outside_of_the_tree() {
+    let params = MerkleTreeParams::new(2, 2);
+    let leaves = leaves(params.leaf_count(), 16);
+    let path = params.path(&leaves, 2);
+    let mut leaf = leaves[2].clone();
+    leaf[0] ^= 1;
+    run_merkle_test(&params, &leaf, path, &params.root(&leaves));
+}
+
+#[test]
+#[should_panic]
+fn test_membership_fails_without_the_prefixes_of_the_tree() {
+    let params = MerkleTreeParams::new(2, 2).leaf_prefix(&[0x00]).node_prefix(&[0x01]);
+    let leaves = leaves(params.leaf_count(), 16);
+    let path = params.path(&leaves, 1);
+    let circuit_params = MerkleTreeParams::new(2, 2);
+    run_merkle_test(&circuit_params, &leaves[1], path, &params.root(&leaves));
+}
+
+/// Leaves of the given length, each one filled with its index
+fn leaves(leaf_count: usize, leaf_length: usize) -> Vec<Vec<u8>> {
+    (0..leaf_count).map(|index| vec![index as u8; leaf_length]).collect()
+}
+
+fn run_merkle_test(
+    params: &MerkleTreeParams,
+    leaf: &[u8],
+    path: Vec<(usize, Vec<u8>)>,
+    expected_root: &[u8],
+) {
+    let path = path
+        .iter()
+        .map(|(position, siblings)| (Value::known(*position), values(siblings)))
+        .collect();
+    let circuit = MerkleCircuit::new(params.clone(), values(leaf), path);
+    let expected_root: Vec<Fq> = expected_root.iter().map(|byte| Fq::from(*byte as u64)).collect();
+    let prover = MockProver::run(17, &circuit, vec![expected_root]).unwrap();
+    prover.verify().unwrap();
+}
@@ -0,0 +1,4 @@
+use super::*;
+
+mod merkle_circuit;
+mod merkle_tests;
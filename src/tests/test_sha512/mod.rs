@@ -0,0 +1,4 @@
+use super::*;
+
+mod sha512_circuit;
+mod sha512_tests;
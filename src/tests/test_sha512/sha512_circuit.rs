@@ -0,0 +1,139 @@
+use super::*;
+use crate::blake2b::blake2b_chip::{Blake2bChip, Blake2bConfig};
+use crate::sha512::sha512_chip::{Sha512Chip, Sha512Config};
+use midnight_proofs::circuit::SimpleFloorPlanner;
+use midnight_proofs::plonk::{Circuit, Instance};
+use std::array;
+
+/// Circuit that hashes an input using the SHA-512 chip, and constrains the digest to equal the
+/// public inputs.
+#[derive(Clone, Debug)]
+pub(crate) struct Sha512Circuit<F: PrimeField> {
+    input: Vec<Value<F>>,
+}
+
+impl<F: PrimeField> Sha512Circuit<F> {
+    pub(crate) fn new(input: Vec<Value<F>>) -> Self {
+        Self { input }
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for Sha512Circuit<F> {
+    type Config = (Sha512Config, Column<Instance>);
+    type Params = ();
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::new(vec![Value::unknown(); self.input.len()])
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let full_number_u64 = meta.advice_column();
+        let limbs: [Column<Advice>; 8] = array::from_fn(|_| meta.advice_column());
+        let constant_col = meta.fixed_column();
+        let expected_digest = meta.instance_column();
+        meta.enable_equality(expected_digest);
+        (Sha512Chip::configure(meta, constant_col, full_number_u64, limbs), expected_digest)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let assigned_input =
+            assign_inputs_to_the_trace(config.0.limbs, &mut layouter, &self.input)?;
+
+        let chip = Sha512Chip::new(&config.0);
+        chip.load(&mut layouter)?;
+        let result = chip.hash(&mut layouter, &assigned_input)?;
+
+        for (i, digest_byte_cell) in result.iter().enumerate() {
+            layouter.constrain_instance(digest_byte_cell.cell(), config.1, i)?;
+        }
+        Ok(())
+    }
+}
+
+/// Circuit that hashes an input with both SHA-512 and Blake2b, with a SHA-512 chip configured
+/// over the configuration of the Blake2b chip. The public inputs are the SHA-512 digest followed
+/// by the Blake2b one.
+#[derive(Clone, Debug)]
+pub(crate) struct SharedSha512Circuit<F: PrimeField> {
+    input: Vec<Value<F>>,
+}
+
+impl<F: PrimeField> SharedSha512Circuit<F> {
+    pub(crate) fn new(input: Vec<Value<F>>) -> Self {
+        Self { input }
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for SharedSha512Circuit<F> {
+    type Config = (Blake2bConfig, Sha512Config, Column<Instance>);
+    type Params = ();
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::new(vec![Value::unknown(); self.input.len()])
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let full_number_u64 = meta.advice_column();
+        let limbs: [Column<Advice>; 8] = array::from_fn(|_| meta.advice_column());
+        let constant_col = meta.fixed_column();
+        let expected_digests = meta.instance_column();
+        meta.enable_equality(expected_digests);
+        let blake2b_config = Blake2bChip::configure(meta, constant_col, full_number_u64, limbs);
+        let sha512_config = Sha512Chip::configure_with_blake2b(meta, &blake2b_config);
+        (blake2b_config, sha512_config, expected_digests)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let assigned_input =
+            assign_inputs_to_the_trace(config.0.limbs, &mut layouter, &self.input)?;
+
+        let blake2b_chip = Blake2bChip::new(&config.0);
+        let sha512_chip = Sha512Chip::new(&config.1);
+        blake2b_chip.load(&mut layouter)?;
+        sha512_chip.load_bitwise_table(&mut layouter)?;
+
+        let sha512_digest = sha512_chip.hash(&mut layouter, &assigned_input)?;
+        let blake2b_digest =
+            blake2b_chip.hash(&mut layouter, &assigned_input, &[], 64, None, None)?;
+
+        for (i, digest_byte_cell) in sha512_digest.iter().chain(blake2b_digest.iter()).enumerate() {
+            layouter.constrain_instance(digest_byte_cell.cell(), config.2, i)?;
+        }
+        Ok(())
+    }
+}
+
+/// The inputs are stored in the limb columns, eight bytes per row.
+fn assign_inputs_to_the_trace<F: PrimeField>(
+    limbs: [Column<Advice>; 8],
+    layouter: &mut impl Layouter<F>,
+    input: &[Value<F>],
+) -> Result<Vec<AssignedNative<F>>, Error> {
+    layouter.assign_region(
+        || "Inputs",
+        |mut region| {
+            input
+                .iter()
+                .enumerate()
+                .map(|(index, input_byte)| {
+                    region.assign_advice(
+                        || "Input byte",
+                        limbs[index % 8],
+                        index / 8,
+                        || *input_byte,
+                    )
+                })
+                .collect()
+        },
+    )
+}
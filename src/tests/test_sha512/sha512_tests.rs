@@ -0,0 +1,74 @@
+use super::*;
+use crate::tests::test_sha512::sha512_circuit::{Sha512Circuit, SharedSha512Circuit};
+use sha2::{Digest, Sha512};
+
+/// SHA-512("abc"), from Appendix C.1 of FIPS 180-2
+const FIPS_180_2_ABC_DIGEST: &str = "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a\
+                                     2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f";
+
+/// Two-block message of Appendix C.2 of FIPS 180-2, and its digest
+const FIPS_180_2_TWO_BLOCK_MESSAGE: &[u8] = b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmn\
+                                              hijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu";
+const FIPS_180_2_TWO_BLOCK_DIGEST: &str = "8e959b75dae313da8cf4f72814fc143f8f7779c6eb9f7fa17299aeadb6889018\
+                                           501d289e4900f7e4331b99dec4b5433ac7d329eeb6dd26545e96e55b874be909";
+
+#[test]
+fn test_sha512_of_abc_matches_fips_180_2() {
+    let expected_output = hex::decode(FIPS_180_2_ABC_DIGEST).unwrap();
+    verify_circuit(&sha512_circuit(b"abc"), &expected_output);
+}
+
+#[test]
+fn test_sha512_of_two_block_message_matches_fips_180_2() {
+    let expected_output = hex::decode(FIPS_180_2_TWO_BLOCK_DIGEST).unwrap();
+    verify_circuit(&sha512_circuit(FIPS_180_2_TWO_BLOCK_MESSAGE), &expected_output);
+}
+
+#[test]
+fn test_sha512_of_empty_input_matches_the_reference() {
+    run_sha512_test(&[]);
+}
+
+#[test]
+fn test_sha512_around_the_padding_boundaries_matches_the_reference() {
+    // The length fits in the block of the input up to 111 bytes, and needs another block from 112
+    for input_length in [111, 112, 128] {
+        let input: Vec<u8> = (0..input_length).map(|i| (i * 7) as u8).collect();
+        run_sha512_test(&input);
+    }
+}
+
+#[test]
+fn test_sha512_sharing_the_configuration_with_blake2b_matches_both_references() {
+    let input = b"message hashed with both functions";
+    let mut expected_output = Sha512::digest(input).to_vec();
+    expected_output.extend_from_slice(blake2b_simd::blake2b(input).as_bytes());
+    let circuit = SharedSha512Circuit::<Fq>::new(input.iter().map(|x| value_for(*x)).collect());
+    verify_circuit(&circuit, &expected_output);
+}
+
+#[test]
+#[should_panic]
+fn test_sha512_fails_with_a_wrong_digest() {
+    let mut expected_output = hex::decode(FIPS_180_2_ABC_DIGEST).unwrap();
+    expected_output[63] ^= 1;
+    verify_circuit(&sha512_circuit(b"abc"), &expected_output);
+}
+
+#[test]
+#[should_panic]
+fn test_sha512_fails_with_an_input_byte_out_of_range() {
+    let mut input: Vec<Value<Fq>> = b"abc".iter().map(|x| value_for(*x)).collect();
+    input[0] = value_for(256u64);
+    let expected_output = hex::decode(FIPS_180_2_ABC_DIGEST).unwrap();
+    verify_circuit(&Sha512Circuit::new(input), &expected_output);
+}
+
+fn run_sha512_test(input: &[u8]) {
+    let expected_output = Sha512::digest(input).to_vec();
+    verify_circuit(&sha512_circuit(input), &expected_output);
+}
+
+fn sha512_circuit(input: &[u8]) -> Sha512Circuit<Fq> {
+    Sha512Circuit::new(input.iter().map(|x| value_for(*x)).collect())
+}
@@ -0,0 +1,4 @@
+use super::*;
+
+mod substrate_circuit;
+mod substrate_tests;
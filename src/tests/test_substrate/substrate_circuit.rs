@@ -0,0 +1,86 @@
+use super::*;
+use crate::substrate::substrate_chip::{
+    StorageHasher, SubstrateChip, SubstrateConfig, STORAGE_PREFIX_SIZE,
+};
+use midnight_proofs::circuit::SimpleFloorPlanner;
+use midnight_proofs::plonk::{Circuit, Instance};
+use std::array;
+
+/// Circuit that hashes a private key with a Substrate hasher, optionally prefixed to build the
+/// storage key of a map item, and constrains the result to equal the public inputs.
+#[derive(Clone, Debug)]
+pub(crate) struct SubstrateCircuit<F: PrimeField> {
+    prefix: Option<[u8; STORAGE_PREFIX_SIZE]>,
+    hasher: StorageHasher,
+    key: Vec<Value<F>>,
+}
+
+impl<F: PrimeField> SubstrateCircuit<F> {
+    pub(crate) fn new(
+        prefix: Option<[u8; STORAGE_PREFIX_SIZE]>,
+        hasher: StorageHasher,
+        key: Vec<Value<F>>,
+    ) -> Self {
+        Self {
+            prefix,
+            hasher,
+            key,
+        }
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for SubstrateCircuit<F> {
+    type Config = (SubstrateConfig, Column<Instance>);
+    type Params = ();
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::new(self.prefix, self.hasher, vec![Value::unknown(); self.key.len()])
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let full_number_u64 = meta.advice_column();
+        let limbs: [Column<Advice>; 8] = array::from_fn(|_| meta.advice_column());
+        let constant_col = meta.fixed_column();
+        let expected_output = meta.instance_column();
+        meta.enable_equality(expected_output);
+        (SubstrateChip::configure(meta, constant_col, full_number_u64, limbs), expected_output)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let chip = SubstrateChip::new(&config.0);
+        chip.load(&mut layouter)?;
+
+        // The key is stored in the limb columns, eight bytes per row
+        let key = layouter.assign_region(
+            || "Inputs",
+            |mut region| {
+                self.key
+                    .iter()
+                    .enumerate()
+                    .map(|(index, key_byte)| {
+                        region.assign_advice(
+                            || "Key byte",
+                            config.0.limbs[index % 8],
+                            index / 8,
+                            || *key_byte,
+                        )
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+            },
+        )?;
+
+        let output = match &self.prefix {
+            Some(prefix) => chip.storage_map_key(&mut layouter, prefix, self.hasher, &key)?,
+            None => chip.hash_key(&mut layouter, self.hasher, &key)?,
+        };
+        for (i, output_cell) in output.iter().enumerate() {
+            layouter.constrain_instance(output_cell.cell(), config.1, i)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,115 @@
+use super::*;
+use crate::substrate::substrate_chip::{StorageHasher, STORAGE_PREFIX_SIZE};
+use crate::tests::test_substrate::substrate_circuit::SubstrateCircuit;
+use blake2b_simd::Params;
+use midnight_proofs::dev::MockProver;
+
+// twox_128("System") followed by twox_128("Account")
+const SYSTEM_ACCOUNT_PREFIX: &str =
+    "26aa394eea5630e07c48ae0c9558cef7b99d880ec681799c0cf30e8886371da9";
+
+// Account ids of the development accounts
+const ALICE: &str = "d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d";
+const BOB: &str = "8eaf04151687736326c9fea17e25fc5287613693c912909cb226aa4794f26a48";
+
+// Storage keys of the System.Account items of the development accounts
+const ALICE_ACCOUNT_KEY: &str = "26aa394eea5630e07c48ae0c9558cef7b99d880ec681799c0cf30e8886371da9de1e86a9a8c739864cf3cc5ec2bea59fd43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d";
+const BOB_ACCOUNT_KEY: &str = "26aa394eea5630e07c48ae0c9558cef7b99d880ec681799c0cf30e8886371da94f9aea1afa791265fae359272badc1cf8eaf04151687736326c9fea17e25fc5287613693c912909cb226aa4794f26a48";
+
+// blake2_256 of the empty input
+const EMPTY_BLAKE2_256: &str = "0e5751c026e543b2e8ab2eb06099daa1d1e5df47778f7787faab45cdf12fe3a8";
+
+#[test]
+fn test_system_account_storage_key_of_alice() {
+    run_substrate_test(
+        Some(system_account_prefix()),
+        StorageHasher::Blake2_128Concat,
+        &hex::decode(ALICE).unwrap(),
+        &hex::decode(ALICE_ACCOUNT_KEY).unwrap(),
+    );
+}
+
+#[test]
+fn test_system_account_storage_key_of_bob() {
+    run_substrate_test(
+        Some(system_account_prefix()),
+        StorageHasher::Blake2_128Concat,
+        &hex::decode(BOB).unwrap(),
+        &hex::decode(BOB_ACCOUNT_KEY).unwrap(),
+    );
+}
+
+#[test]
+fn test_blake2_128_is_the_hash_of_the_concat_hasher() {
+    let expected_hash = &hex::decode(ALICE_ACCOUNT_KEY).unwrap()[STORAGE_PREFIX_SIZE..][..16];
+    run_substrate_test(
+        None,
+        StorageHasher::Blake2_128,
+        &hex::decode(ALICE).unwrap(),
+        expected_hash,
+    );
+}
+
+#[test]
+fn test_blake2_256_of_the_empty_input() {
+    run_substrate_test(
+        None,
+        StorageHasher::Blake2_256,
+        &[],
+        &hex::decode(EMPTY_BLAKE2_256).unwrap(),
+    );
+}
+
+#[test]
+fn test_blake2_256_storage_key_of_a_scale_encoded_integer() {
+    // The SCALE encoding of a u32 is little-endian
+    let key = 1_000_000u32.to_le_bytes();
+    let mut expected_key = system_account_prefix().to_vec();
+    expected_key.extend_from_slice(Params::new().hash_length(32).hash(&key).as_bytes());
+    run_substrate_test(
+        Some(system_account_prefix()),
+        StorageHasher::Blake2_256,
+        &key,
+        &expected_key,
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_storage_key_fails_with_another_account() {
+    run_substrate_test(
+        Some(system_account_prefix()),
+        StorageHasher::Blake2_128Concat,
+        &hex::decode(ALICE).unwrap(),
+        &hex::decode(BOB_ACCOUNT_KEY).unwrap(),
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_storage_key_fails_with_another_hasher() {
+    run_substrate_test(
+        Some(system_account_prefix()),
+        StorageHasher::Blake2_256,
+        &hex::decode(ALICE).unwrap(),
+        &hex::decode(ALICE_ACCOUNT_KEY).unwrap(),
+    );
+}
+
+fn system_account_prefix() -> [u8; STORAGE_PREFIX_SIZE] {
+    hex::decode(SYSTEM_ACCOUNT_PREFIX).unwrap().try_into().unwrap()
+}
+
+fn run_substrate_test(
+    prefix: Option<[u8; STORAGE_PREFIX_SIZE]>,
+    hasher: StorageHasher,
+    key: &[u8],
+    expected_output: &[u8],
+) {
+    let key = key.iter().map(|byte| value_for(*byte)).collect();
+    let circuit = SubstrateCircuit::<Fq>::new(prefix, hasher, key);
+    let expected_output: Vec<Fq> =
+        expected_output.iter().map(|byte| Fq::from(*byte as u64)).collect();
+    let prover = MockProver::run(17, &circuit, vec![expected_output]).unwrap();
+    prover.verify().unwrap();
+}
@@ -0,0 +1,6 @@
+use super::*;
+
+mod rotation_circuit;
+mod rotation_tests;
+mod uint64_circuit;
+mod uint64_tests;
@@ -0,0 +1,69 @@
+use super::*;
+use crate::uint64::uint64_chip::{Uint64Chip, Uint64Config};
+use midnight_proofs::circuit::SimpleFloorPlanner;
+use midnight_proofs::plonk::{Circuit, Instance};
+use std::array;
+
+/// Rotation or shift of the [Uint64Chip] tested by the circuit.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum RotationKind {
+    RotateRight,
+    RotateLeft,
+    ShiftRight,
+    ShiftLeft,
+}
+
+/// Circuit that rotates or shifts a private integer by every amount between 0 and 63 bits, and
+/// constrains the results, in order of the amount, to equal the public inputs.
+#[derive(Clone, Debug)]
+pub(crate) struct RotationCircuit<F: PrimeField> {
+    kind: RotationKind,
+    input: Value<F>,
+}
+
+impl<F: PrimeField> RotationCircuit<F> {
+    pub(crate) fn new(kind: RotationKind, input: Value<F>) -> Self {
+        Self { kind, input }
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for RotationCircuit<F> {
+    type Config = (Uint64Config, Column<Instance>);
+    type Params = ();
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::new(self.kind, Value::unknown())
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let full_number_u64 = meta.advice_column();
+        let limbs: [Column<Advice>; 8] = array::from_fn(|_| meta.advice_column());
+        let constant_col = meta.fixed_column();
+        let expected_results = meta.instance_column();
+        meta.enable_equality(expected_results);
+        (Uint64Chip::configure(meta, constant_col, full_number_u64, limbs), expected_results)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let chip = Uint64Chip::new(&config.0);
+        chip.load(&mut layouter)?;
+
+        let input = chip
+            .assign(&mut layouter, self.input.map(|input| Blake2bWord::new_from_field(input).0))?;
+        for amount in 0..64 {
+            let result = match self.kind {
+                RotationKind::RotateRight => chip.rotate_right(&mut layouter, &input, amount)?,
+                RotationKind::RotateLeft => chip.rotate_left(&mut layouter, &input, amount)?,
+                RotationKind::ShiftRight => chip.shift_right(&mut layouter, &input, amount)?,
+                RotationKind::ShiftLeft => chip.shift_left(&mut layouter, &input, amount)?,
+            };
+            layouter.constrain_instance(result.cell(), config.1, amount)?;
+        }
+        Ok(())
+    }
+}
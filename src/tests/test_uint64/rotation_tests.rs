@@ -0,0 +1,50 @@
+use super::*;
+use crate::tests::test_uint64::rotation_circuit::{RotationCircuit, RotationKind};
+use midnight_proofs::dev::MockProver;
+
+const INPUT: u64 = 0x8123_4567_89ab_cdef;
+
+#[test]
+fn test_rotations_to_the_right_by_every_amount() {
+    run_rotation_test(RotationKind::RotateRight, INPUT, |amount| INPUT.rotate_right(amount));
+}
+
+#[test]
+fn test_rotations_to_the_left_by_every_amount() {
+    run_rotation_test(RotationKind::RotateLeft, INPUT, |amount| INPUT.rotate_left(amount));
+}
+
+#[test]
+fn test_shifts_to_the_right_by_every_amount() {
+    run_rotation_test(RotationKind::ShiftRight, INPUT, |amount| INPUT >> amount);
+}
+
+#[test]
+fn test_shifts_to_the_left_by_every_amount() {
+    run_rotation_test(RotationKind::ShiftLeft, INPUT, |amount| INPUT << amount);
+}
+
+#[test]
+fn test_rotations_and_shifts_of_all_ones() {
+    run_rotation_test(RotationKind::RotateRight, u64::MAX, |_| u64::MAX);
+    run_rotation_test(RotationKind::ShiftLeft, u64::MAX, |amount| u64::MAX << amount);
+}
+
+#[test]
+#[should_panic]
+fn test_rotations_fail_with_the_results_of_shifts() {
+    run_rotation_test(RotationKind::RotateRight, INPUT, |amount| INPUT >> amount);
+}
+
+#[test]
+#[should_panic]
+fn test_shifts_to_the_left_fail_without_dropping_the_overflow() {
+    run_rotation_test(RotationKind::ShiftLeft, INPUT, |amount| INPUT.rotate_left(amount));
+}
+
+fn run_rotation_test(kind: RotationKind, input: u64, expected_result: impl Fn(u32) -> u64) {
+    let circuit = RotationCircuit::<Fq>::new(kind, value_for(input));
+    let expected_results = (0..64).map(|amount| Fq::from(expected_result(amount))).collect();
+    let prover = MockProver::run(17, &circuit, vec![expected_results]).unwrap();
+    prover.verify().unwrap();
+}
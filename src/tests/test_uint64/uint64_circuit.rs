@@ -0,0 +1,105 @@
+use super::*;
+use crate::uint64::uint64_chip::{Uint64Chip, Uint64Config};
+use midnight_proofs::circuit::SimpleFloorPlanner;
+use midnight_proofs::plonk::{Circuit, Instance};
+use std::array;
+
+/// Instruction of the [Uint64Chip] tested by the circuit.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Uint64Operation {
+    Xor,
+    And,
+    Or,
+    AndNot,
+    Add,
+    Not,
+    RotateRight(usize),
+    RotateLeft(usize),
+    /// Builds the integer from its bytes and decomposes it back.
+    Bytes,
+}
+
+/// Circuit that takes two private native inputs, converts them into [AssignedU64] values and
+/// applies an instruction of the [Uint64Chip] to them. The result, as a native cell, is
+/// constrained to equal the public input.
+///
+/// [AssignedU64]: crate::types::uint64::AssignedU64
+#[derive(Clone, Debug)]
+pub(crate) struct Uint64Circuit<F: PrimeField> {
+    operation: Uint64Operation,
+    lhs: Value<F>,
+    rhs: Value<F>,
+}
+
+impl<F: PrimeField> Uint64Circuit<F> {
+    pub(crate) fn new(operation: Uint64Operation, lhs: Value<F>, rhs: Value<F>) -> Self {
+        Self {
+            operation,
+            lhs,
+            rhs,
+        }
+    }
+
+    fn assign_native(
+        config: &Uint64Config,
+        layouter: &mut impl Layouter<F>,
+        value: Value<F>,
+    ) -> Result<AssignedNative<F>, Error> {
+        layouter.assign_region(
+            || "Input",
+            |mut region| region.assign_advice(|| "Input", config.limbs[0], 0, || value),
+        )
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for Uint64Circuit<F> {
+    type Config = (Uint64Config, Column<Instance>);
+    type Params = ();
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::new(self.operation, Value::unknown(), Value::unknown())
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let full_number_u64 = meta.advice_column();
+        let limbs: [Column<Advice>; 8] = array::from_fn(|_| meta.advice_column());
+        let constant_col = meta.fixed_column();
+        let expected_result = meta.instance_column();
+        meta.enable_equality(expected_result);
+        (Uint64Chip::configure(meta, constant_col, full_number_u64, limbs), expected_result)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let chip = Uint64Chip::new(&config.0);
+        chip.load(&mut layouter)?;
+
+        let lhs = Self::assign_native(&config.0, &mut layouter, self.lhs)?;
+        let lhs = chip.from_native(&mut layouter, &lhs)?;
+        let rhs = Self::assign_native(&config.0, &mut layouter, self.rhs)?;
+        let rhs = chip.from_native(&mut layouter, &rhs)?;
+
+        let result = match self.operation {
+            Uint64Operation::Xor => chip.xor(&mut layouter, &lhs, &rhs)?,
+            Uint64Operation::And => chip.and(&mut layouter, &lhs, &rhs)?,
+            Uint64Operation::Or => chip.or(&mut layouter, &lhs, &rhs)?,
+            Uint64Operation::AndNot => chip.and_not(&mut layouter, &lhs, &rhs)?,
+            Uint64Operation::Add => chip.add(&mut layouter, &lhs, &rhs)?,
+            Uint64Operation::Not => chip.not(&mut layouter, &lhs)?,
+            Uint64Operation::RotateRight(amount) => {
+                chip.rotate_right(&mut layouter, &lhs, amount)?
+            }
+            Uint64Operation::RotateLeft(amount) => chip.rotate_left(&mut layouter, &lhs, amount)?,
+            Uint64Operation::Bytes => {
+                let bytes = chip.to_le_bytes(&mut layouter, &lhs)?;
+                chip.from_le_bytes(&mut layouter, &bytes.map(|byte| byte.to_native()))?
+            }
+        };
+
+        layouter.constrain_instance(result.cell(), config.1, 0)
+    }
+}
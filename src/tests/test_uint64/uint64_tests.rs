@@ -0,0 +1,99 @@
+use super::*;
+use crate::tests::test_uint64::uint64_circuit::{Uint64Circuit, Uint64Operation};
+use midnight_proofs::dev::MockProver;
+
+const LHS: u64 = 0x0123_4567_89ab_cdef;
+const RHS: u64 = 0xfedc_ba98_7654_3210;
+
+#[test]
+fn test_xor() {
+    run_uint64_test(Uint64Operation::Xor, LHS, RHS, LHS ^ RHS);
+}
+
+#[test]
+fn test_and_or_and_not() {
+    const MASK: u64 = 0x00ff_f0f0_0f0f_ff00;
+    run_uint64_test(Uint64Operation::And, LHS, MASK, LHS & MASK);
+    run_uint64_test(Uint64Operation::Or, LHS, MASK, LHS | MASK);
+    run_uint64_test(Uint64Operation::AndNot, LHS, MASK, !LHS & MASK);
+    run_uint64_test(Uint64Operation::AndNot, MASK, LHS, !MASK & LHS);
+}
+
+#[test]
+#[should_panic]
+fn test_and_not_fails_with_the_operands_swapped() {
+    run_uint64_test(Uint64Operation::AndNot, LHS, RHS, !RHS & LHS);
+}
+
+#[test]
+#[should_panic]
+fn test_or_fails_with_the_result_of_and() {
+    run_uint64_test(Uint64Operation::Or, LHS, RHS, LHS & RHS);
+}
+
+#[test]
+fn test_addition_wraps_around() {
+    run_uint64_test(Uint64Operation::Add, u64::MAX, RHS, RHS.wrapping_sub(1));
+    run_uint64_test(Uint64Operation::Add, LHS, RHS, LHS.wrapping_add(RHS));
+}
+
+#[test]
+fn test_not() {
+    run_uint64_test(Uint64Operation::Not, LHS, 0, !LHS);
+}
+
+#[test]
+fn test_rotations_by_whole_limbs_and_by_63_bits() {
+    for amount in [0, 8, 16, 24, 32, 40, 48, 56, 63] {
+        run_uint64_test(
+            Uint64Operation::RotateRight(amount),
+            LHS,
+            0,
+            LHS.rotate_right(amount as u32),
+        );
+    }
+    for amount in [1, 8, 32] {
+        run_uint64_test(
+            Uint64Operation::RotateLeft(amount),
+            LHS,
+            0,
+            LHS.rotate_left(amount as u32),
+        );
+    }
+}
+
+#[test]
+fn test_bytes_round_trip() {
+    run_uint64_test(Uint64Operation::Bytes, RHS, 0, RHS);
+}
+
+#[test]
+#[should_panic]
+fn test_from_native_fails_with_a_value_out_of_range() {
+    let circuit =
+        Uint64Circuit::<Fq>::new(Uint64Operation::Xor, value_for(1u128 << 64), value_for(0u64));
+    let prover = MockProver::run(17, &circuit, vec![vec![Fq::from_u128(1 << 64)]]).unwrap();
+    prover.verify().unwrap();
+}
+
+#[test]
+#[should_panic]
+fn test_addition_fails_without_wrapping_around() {
+    let circuit =
+        Uint64Circuit::<Fq>::new(Uint64Operation::Add, value_for(u64::MAX), value_for(2u64));
+    let prover =
+        MockProver::run(17, &circuit, vec![vec![Fq::from_u128(u64::MAX as u128 + 2)]]).unwrap();
+    prover.verify().unwrap();
+}
+
+#[test]
+#[should_panic(expected = "Rotations must be smaller than 64 bits")]
+fn test_rotation_by_64_bits_is_rejected() {
+    run_uint64_test(Uint64Operation::RotateRight(64), LHS, 0, LHS);
+}
+
+fn run_uint64_test(operation: Uint64Operation, lhs: u64, rhs: u64, expected_result: u64) {
+    let circuit = Uint64Circuit::<Fq>::new(operation, value_for(lhs), value_for(rhs));
+    let prover = MockProver::run(17, &circuit, vec![vec![Fq::from(expected_result)]]).unwrap();
+    prover.verify().unwrap();
+}
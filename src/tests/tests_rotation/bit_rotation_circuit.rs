@@ -0,0 +1,72 @@
+use super::*;
+use crate::base_operations::bit_rotation::BitShift;
+use crate::tests::Decompose8Config;
+use midnight_proofs::circuit::SimpleFloorPlanner;
+use midnight_proofs::plonk::Circuit;
+use std::array;
+
+/// Circuit that places a trace of a rotation or shift by less than a limb: the decomposed input
+/// in the first row, and the output, the parts of the split limb and the powers in the second.
+pub(crate) struct BitRotationCircuit<F: PrimeField> {
+    operation: BitShift,
+    trace: [[Value<F>; 9]; 2],
+}
+
+impl<F: PrimeField> BitRotationCircuit<F> {
+    pub(crate) fn new_for_trace(operation: BitShift, trace: [[Value<F>; 9]; 2]) -> Self {
+        Self { operation, trace }
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for BitRotationCircuit<F> {
+    type Config = BitRotationCircuitConfig<F>;
+    type Params = ();
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::new_for_trace(self.operation, [[Value::unknown(); 9]; 2])
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let full_number_u64 = meta.advice_column();
+        let limbs: [Column<Advice>; 8] = array::from_fn(|_| meta.advice_column());
+
+        let decompose_8_config = Decompose8Config::configure(meta, full_number_u64, limbs);
+        let bit_rotation_config = BitRotationConfig::configure(
+            meta,
+            full_number_u64,
+            limbs,
+            decompose_8_config.q_range,
+            decompose_8_config.t_range,
+        );
+
+        Self::Config {
+            _ph: PhantomData,
+            decompose_8_config,
+            bit_rotation_config,
+        }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        config.decompose_8_config.populate_lookup_table(&mut layouter)?;
+        layouter.assign_region(
+            || "bit rotation",
+            |mut region| {
+                let decompose_config = &config.decompose_8_config;
+                decompose_config.populate_row_from_values(&mut region, &self.trace[0], 0, true)?;
+                decompose_config.populate_row_from_values(&mut region, &self.trace[1], 1, false)?;
+                decompose_config.q_range.enable(&mut region, 1)?;
+                let selector = match self.operation {
+                    BitShift::RotateRight => config.bit_rotation_config.q_rotate_right,
+                    BitShift::ShiftRight => config.bit_rotation_config.q_shift_right,
+                    BitShift::ShiftLeft => config.bit_rotation_config.q_shift_left,
+                };
+                selector.enable(&mut region, 1)
+            },
+        )
+    }
+}
@@ -1,5 +1,6 @@
 use super::*;
 use crate::tests::Decompose8Config;
+use crate::base_operations::bit_rotation::BitRotationConfig;
 use crate::base_operations::generic_limb_rotation::LimbRotation;
 use crate::base_operations::rotate_63::Rotate63Config;
 
@@ -36,6 +37,19 @@ pub(crate) struct LimbRotationCircuitConfig<F: PrimeField> {
     limb_rotation_config: LimbRotation,
 }
 
+// -------- Rotations and shifts by less than a limb ------------
+mod bit_rotation_circuit;
+mod test_bit_rotation;
+
+#[derive(Clone)]
+pub(crate) struct BitRotationCircuitConfig<F: PrimeField> {
+    _ph: PhantomData<F>,
+    decompose_8_config: Decompose8Config,
+    bit_rotation_config: BitRotationConfig,
+}
+
+// --------------------------------------------------
+
 impl Rotate63Config {
     /// Receives a trace and populates the rows for the rotation of 63 bits to the right
     fn populate_rotation_rows<F: PrimeField>(
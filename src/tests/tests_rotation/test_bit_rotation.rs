@@ -0,0 +1,84 @@
+use super::*;
+use crate::base_operations::bit_rotation::BitShift;
+use crate::tests::tests_rotation::bit_rotation_circuit::BitRotationCircuit;
+use midnight_proofs::dev::MockProver;
+
+const INPUT: u64 = 0x8123_4567_89ab_cdef;
+
+#[test]
+fn test_positive_bit_rotations_and_shifts() {
+    for bits in 1..8 {
+        for operation in [BitShift::RotateRight, BitShift::ShiftRight, BitShift::ShiftLeft] {
+            run_bit_rotation_test(operation, valid_trace(operation, bits));
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_negative_bit_rotation_with_a_wrong_output() {
+    let mut trace = valid_trace(BitShift::RotateRight, 3);
+    trace[1][0] = trace[1][0] + one();
+    run_bit_rotation_test(BitShift::RotateRight, trace);
+}
+
+#[test]
+#[should_panic]
+fn test_negative_bit_rotation_with_a_low_part_that_exceeds_its_power() {
+    // 0xef = 0b1110_1111 split as 0b111 + 2^3 * 0b11101 can also be written as 0b1111 + 2^3 *
+    // 0b11100, but 0b1111 doesn't fit in 3 bits
+    let mut trace = valid_trace(BitShift::ShiftRight, 3);
+    trace[1][1] = value_for(0b1111u64);
+    trace[1][2] = value_for(0b11100u64);
+    trace[1][0] = value_for((INPUT - 0b1111) / 8);
+    run_bit_rotation_test(BitShift::ShiftRight, trace);
+}
+
+#[test]
+#[should_panic]
+fn test_negative_bit_rotation_with_a_fractional_high_part() {
+    // With a low part of 0, the high part must be 0xef / 2, which is not an integer, so the
+    // output is the field element INPUT / 2 instead of a 64-bit number
+    let mut trace = valid_trace(BitShift::ShiftRight, 1);
+    let half = Fq::from(2).invert().unwrap();
+    trace[1][1] = zero();
+    trace[1][2] = Value::known(Fq::from(0xef) * half);
+    trace[1][0] = Value::known(Fq::from(INPUT) * half);
+    run_bit_rotation_test(BitShift::ShiftRight, trace);
+}
+
+#[test]
+#[should_panic]
+fn test_negative_bit_rotation_with_wrong_powers() {
+    let mut trace = valid_trace(BitShift::ShiftLeft, 2);
+    trace[1][4] = value_for(128u64);
+    run_bit_rotation_test(BitShift::ShiftLeft, trace);
+}
+
+fn valid_trace(operation: BitShift, bits: u32) -> [[Value<Fq>; 9]; 2] {
+    let (output, split_limb, power) = match operation {
+        BitShift::RotateRight => (INPUT.rotate_right(bits), INPUT & 0xff, 1 << bits),
+        BitShift::ShiftRight => (INPUT >> bits, INPUT & 0xff, 1 << bits),
+        BitShift::ShiftLeft => (INPUT << bits, INPUT >> 56, 1 << (8 - bits)),
+    };
+    [
+        generate_row_8bits(INPUT),
+        [
+            value_for(output),
+            value_for(split_limb % power),
+            value_for(split_limb / power),
+            value_for(power),
+            value_for(256 / power),
+            zero(),
+            zero(),
+            zero(),
+            zero(),
+        ],
+    ]
+}
+
+fn run_bit_rotation_test(operation: BitShift, trace: [[Value<Fq>; 9]; 2]) {
+    let circuit = BitRotationCircuit::<Fq>::new_for_trace(operation, trace);
+    let prover = MockProver::run(17, &circuit, vec![]).unwrap();
+    prover.verify().unwrap();
+}
@@ -1,12 +1,12 @@
 use super::*;
 use ff::PrimeField;
-use midnight_proofs::circuit::{Region, Value};
+use midnight_proofs::circuit::{Cell, Region, Value};
 use midnight_proofs::plonk::{Advice, Column, Error};
 use midnight_proofs::utils::rational::Rational;
 
 /// The inner type of AssignedBit. A wrapper around `bool`
 #[derive(Copy, Clone, Debug)]
-struct Bit(bool);
+pub(crate) struct Bit(pub bool);
 
 impl Bit {
     /// Creates a new [Bit] element. When the byte is created, it is constrained to be in the
@@ -30,8 +30,8 @@ impl<F: PrimeField> From<&Bit> for Rational<F> {
 /// This wrapper type on `AssignedNative<F>` is designed to enforce type safety
 /// on assigned bits. It is used in the addition chip to enforce that the
 /// carry value is 0 or 1
-#[must_use]
-pub(crate) struct AssignedBit<F: PrimeField>(#[allow(dead_code)] AssignedCell<Bit, F>);
+#[derive(Clone, Debug)]
+pub(crate) struct AssignedBit<F: PrimeField>(AssignedCell<Bit, F>);
 
 impl<F: PrimeField> AssignedBit<F> {
     /// This method assigns a bit in the trace. The bit is range-checked both in
@@ -54,12 +54,37 @@ impl<F: PrimeField> AssignedBit<F> {
             Self(region.assign_advice(|| annotation, column, offset, || bit_value)?);
         Ok(assigned_bit)
     }
-}
 
-#[cfg(test)]
-use midnight_proofs::circuit::Cell;
-#[cfg(test)]
-impl<F: PrimeField> AssignedBit<F> {
+    /// Method that assigns a fixed bit in the trace. It's safe to use because it's a constant,
+    /// therefore it's constrained to a fixed value known by everyone.
+    pub(crate) fn assign_fixed_bit(
+        region: &mut Region<'_, F>,
+        annotation: &str,
+        column: Column<Advice>,
+        offset: usize,
+        bit_value: bool,
+    ) -> Result<Self, Error> {
+        let result =
+            region.assign_advice_from_constant(|| annotation, column, offset, Bit(bit_value))?;
+        Ok(Self(result))
+    }
+
+    /// Method that copies an [AssignedBit] in the trace into another cell.
+    pub(crate) fn copy_advice_bit(
+        &self,
+        region: &mut Region<'_, F>,
+        column: Column<Advice>,
+        offset: usize,
+        annotation: &str,
+    ) -> Result<Self, Error> {
+        let result = self.0.copy_advice(|| annotation, region, column, offset)?;
+        Ok(Self(result))
+    }
+
+    pub(crate) fn value(&self) -> Value<Bit> {
+        self.0.value().cloned()
+    }
+
     pub(crate) fn cell(&self) -> Cell {
         self.0.cell()
     }
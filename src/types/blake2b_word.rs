@@ -131,4 +131,9 @@ impl<F: PrimeField> AssignedBlake2bWord<F> {
     pub(crate) fn cell(&self) -> Cell {
         self.0.cell()
     }
+
+    /// Gets the assigned word as an [AssignedNative] of the same cell.
+    pub(crate) fn to_native(&self) -> AssignedNative<F> {
+        self.0.convert_to_native()
+    }
 }
@@ -108,6 +108,12 @@ impl<F: PrimeField> AssignedByte<F> {
     pub fn value(&self) -> Value<Byte> {
         self.0.value().cloned()
     }
+
+    /// Gets the assigned byte as an [AssignedNative] of the same cell, so that it can be used as
+    /// an input of the chip, for instance to hash a digest again.
+    pub fn to_native(&self) -> AssignedNative<F> {
+        self.0.convert_to_native()
+    }
 }
 
 impl<F: PrimeField> From<AssignedByte<F>> for AssignedCell<Byte, F> {
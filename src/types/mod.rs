@@ -22,6 +22,8 @@ pub mod byte;
 pub mod blake2b_word;
 /// Module for assigned blake2b rows.
 pub mod row;
+/// Module for assigned 64-bit unsigned integers.
+pub mod uint64;
 
 /// Given a field element and a limb index in little endian form, this function checks that the
 /// field element is in range [0, 2^64-1]. If it's not, it will fail.
@@ -40,3 +42,15 @@ fn get_word_biguint_from_le_field<F: PrimeField>(fe: F) -> BigUint {
         BigUint::from_bytes_le(bytes)
     }
 }
+
+/// Checks that the internal representation of the field is in little endian form, which is
+/// assumed by [get_word_biguint_from_le_field] and by the conversions between bytes and field
+/// elements across the chip.
+pub(crate) fn enforce_little_endian_representation<F: PrimeField>() {
+    let one_representation = F::ONE.to_repr();
+    let (first_byte, rest) = one_representation.as_ref().split_at(1);
+    assert!(
+        first_byte[0] == 1 && rest.iter().all(|&byte| byte == 0),
+        "Field representation must be in little endian form"
+    );
+}
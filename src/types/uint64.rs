@@ -0,0 +1,30 @@
+use crate::types::blake2b_word::AssignedBlake2bWord;
+use crate::types::AssignedNative;
+use ff::PrimeField;
+use midnight_proofs::circuit::{Cell, Value};
+
+/// This wrapper type on an [AssignedBlake2bWord] is the public face of the 64-bit words of the
+/// chips, and it's designed to enforce type safety on assigned 64-bit unsigned integers. It can
+/// only be obtained from the [Uint64Chip], whose constructors and operations guarantee (with
+/// constraints) that the assigned value is indeed in the range [0, 2^64 - 1].
+///
+/// [Uint64Chip]: crate::uint64::uint64_chip::Uint64Chip
+#[derive(Clone, Debug)]
+pub struct AssignedU64<F: PrimeField>(pub(crate) AssignedBlake2bWord<F>);
+
+impl<F: PrimeField> AssignedU64<F> {
+    /// Value of the integer, known at proof generation time.
+    pub fn value(&self) -> Value<u64> {
+        self.0.value().map(|word| word.0)
+    }
+
+    /// Cell of the integer, which allows to constrain it to other cells.
+    pub fn cell(&self) -> Cell {
+        self.0.cell()
+    }
+
+    /// Gets the assigned integer as an [AssignedNative] of the same cell.
+    pub fn to_native(&self) -> AssignedNative<F> {
+        self.0.to_native()
+    }
+}
@@ -0,0 +1,13 @@
+//! A chip for 64-bit unsigned integers, which exposes the base operations of the Blake2b chip to
+//! other algorithms over 64-bit words. This interface works with [AssignedU64] values, which are
+//! always range checked, and it has constructors that range check native cells.
+//!
+//! * [Uint64Chip] This chip uses the columns, gates and lookup table of size `2**16` of the
+//!   Blake2b chip, and it can share them with a Blake2b chip of the same circuit. It adds a
+//!   lookup table of size `2**16` of its own for the and, or and and-not operations.
+//!
+//! [AssignedU64]: crate::types::uint64::AssignedU64
+//! [Uint64Chip]: crate::uint64::uint64_chip::Uint64Chip
+
+/// The 64-bit unsigned integer chip.
+pub mod uint64_chip;
@@ -0,0 +1,429 @@
+use crate::base_operations::bit_rotation::{BitRotationConfig, BitShift};
+use crate::base_operations::bitwise::{BitwiseConfig, BitwiseOperation};
+use crate::base_operations::generic_limb_rotation::LimbRotation;
+use crate::blake2b::blake2b_chip::{Blake2bChip, Blake2bConfig};
+use crate::blake2b::NB_BLAKE2B_ADVICE_COLS;
+use crate::types::blake2b_word::{AssignedBlake2bWord, Blake2bWord};
+use crate::types::byte::AssignedByte;
+use crate::types::uint64::AssignedU64;
+use crate::types::AssignedNative;
+use ff::PrimeField;
+use midnight_proofs::circuit::{Chip, Layouter, Region, Value};
+use midnight_proofs::plonk::{Advice, Column, ConstraintSystem, Error, Fixed};
+
+/// Selectors and columns for the 64-bit unsigned integer chip implementation.
+#[derive(Clone, Debug)]
+pub struct Uint64Config {
+    /// Config of the Blake2b chip, which holds the base operations over 64-bit words.
+    blake2b_config: Blake2bConfig,
+    /// Base operations configs that the Blake2b chip doesn't use
+    limb_rotation_config: LimbRotation,
+    bit_rotation_config: BitRotationConfig,
+    bitwise_config: BitwiseConfig,
+    /// Columns for the limbs, shared with the Blake2b chip.
+    pub limbs: [Column<Advice>; 8],
+}
+
+/// This is the chip for 64-bit unsigned integers, which exposes the base operations of the
+/// Blake2b chip as instructions over [AssignedU64] values, so other ARX algorithms over 64-bit
+/// words can be built with the same columns, gates and tables. Every [AssignedU64] is range
+/// checked, either by the decomposition of its value into 8-bit limbs or by the gate of the
+/// operation that produced it.
+///
+/// Every instruction is laid out in its own region, so the rows saved by the Blake2b chip when an
+/// operand is the last row of the trace aren't saved here.
+#[derive(Clone, Debug)]
+pub struct Uint64Chip<F: PrimeField> {
+    config: Uint64Config,
+    blake2b_chip: Blake2bChip<F>,
+}
+
+impl<F: PrimeField> Chip<F> for Uint64Chip<F> {
+    type Config = Uint64Config;
+    type Loaded = ();
+
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+impl<F: PrimeField> Uint64Chip<F> {
+    /// Generation of a fresh circuit from a configuration.
+    pub fn new(config: &Uint64Config) -> Self {
+        Self {
+            config: config.clone(),
+            blake2b_chip: Blake2bChip::new(&config.blake2b_config),
+        }
+    }
+
+    /// Configuration of the circuit, which is the configuration of the Blake2b chip plus the gates
+    /// for rotations and shifts by any amount and the lookups for and, or and and-not. It should be
+    /// called in the configuration of the user circuit before instantiating the chip.
+    ///
+    /// Note: as in the Blake2b chip, this function enables equality on all necessary columns,
+    /// i.e., it should not be done manually before calling this function.
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        constants: Column<Fixed>,
+        full_number_u64: Column<Advice>,
+        limbs: [Column<Advice>; NB_BLAKE2B_ADVICE_COLS - 1],
+    ) -> <Self as Chip<F>>::Config {
+        let blake2b_config = Blake2bChip::configure(meta, constants, full_number_u64, limbs);
+        Self::configure_with_blake2b(meta, &blake2b_config)
+    }
+
+    /// Configuration of the chip over the configuration of a Blake2b chip of the same circuit,
+    /// which shares its columns, gates and tables with it. Only the gates that the Blake2b chip
+    /// doesn't have are added to the circuit.
+    pub fn configure_with_blake2b(
+        meta: &mut ConstraintSystem<F>,
+        blake2b_config: &Blake2bConfig,
+    ) -> <Self as Chip<F>>::Config {
+        let limb_rotation_config = LimbRotation::configure(blake2b_config.q_decompose);
+        let bit_rotation_config = BitRotationConfig::configure(
+            meta,
+            blake2b_config.full_number_u64,
+            blake2b_config.limbs,
+            blake2b_config.q_range,
+            blake2b_config.t_range,
+        );
+        let bitwise_config = BitwiseConfig::configure(
+            meta,
+            blake2b_config.full_number_u64,
+            blake2b_config.limbs,
+            blake2b_config.q_decompose,
+        );
+        Uint64Config {
+            blake2b_config: blake2b_config.clone(),
+            limb_rotation_config,
+            bit_rotation_config,
+            bitwise_config,
+            limbs: blake2b_config.limbs,
+        }
+    }
+
+    /// Loading the tables used in the chip, which are the ones of the Blake2b chip plus the table
+    /// of the and, or and and-not operations. When the configuration is shared with a Blake2b
+    /// chip, the tables of the Blake2b chip must be loaded only once, by either of the chips, so
+    /// this method loads all of them and [Self::load_bitwise_table] only loads the new one.
+    pub fn load(&self, layouter: &mut impl Layouter<F>) -> Result<(), Error> {
+        self.blake2b_chip.load(layouter)?;
+        self.load_bitwise_table(layouter)
+    }
+
+    /// Loading the table of the and, or and and-not operations, for circuits in which the tables
+    /// of the Blake2b chip are loaded by a Blake2b chip sharing the configuration.
+    pub fn load_bitwise_table(&self, layouter: &mut impl Layouter<F>) -> Result<(), Error> {
+        self.config.bitwise_config.populate_bitwise_lookup_table(layouter)
+    }
+
+    /// Assigns a value known at proof generation time. The value is decomposed into 8-bit limbs,
+    /// which range checks it.
+    pub fn assign(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        value: Value<u64>,
+    ) -> Result<AssignedU64<F>, Error> {
+        self.in_region(layouter, "uint64 assign", |chip, region, offset| {
+            let row = chip.blake2b_chip.new_row_from_word_value(
+                value.map(Blake2bWord),
+                region,
+                offset,
+            )?;
+            Ok(AssignedU64(row.full_number))
+        })
+    }
+
+    /// Assigns a constant, known at circuit building time.
+    pub fn assign_constant(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        constant: u64,
+    ) -> Result<AssignedU64<F>, Error> {
+        layouter.assign_region(
+            || "uint64 constant",
+            |mut region| {
+                let word = AssignedBlake2bWord::assign_fixed_word(
+                    &mut region,
+                    "uint64 constant",
+                    self.config.limbs[0],
+                    0,
+                    Blake2bWord(constant),
+                )?;
+                Ok(AssignedU64(word))
+            },
+        )
+    }
+
+    /// Converts a native cell into an [AssignedU64], constraining it to hold a 64-bit value by
+    /// decomposing a copy of it into 8-bit limbs. The constraints fail if the value is out of
+    /// range.
+    pub fn from_native(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        native: &AssignedNative<F>,
+    ) -> Result<AssignedU64<F>, Error> {
+        self.in_region(layouter, "uint64 from native", |chip, region, offset| {
+            let row = chip.blake2b_chip.new_row_from_native_word(native, region, offset)?;
+            Ok(AssignedU64(row.full_number))
+        })
+    }
+
+    /// Builds an [AssignedU64] from its 8 bytes, in little-endian order. The bytes are constrained
+    /// to be in the range [0, 255].
+    pub fn from_le_bytes(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        bytes: &[AssignedNative<F>; 8],
+    ) -> Result<AssignedU64<F>, Error> {
+        self.in_region(layouter, "uint64 from bytes", |chip, region, offset| {
+            let row = chip.blake2b_chip.new_row_from_assigned_bytes(bytes, region, offset)?;
+            Ok(AssignedU64(row.full_number))
+        })
+    }
+
+    /// Decomposes an [AssignedU64] into its 8 bytes, in little-endian order.
+    pub fn to_le_bytes(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        word: &AssignedU64<F>,
+    ) -> Result<[AssignedByte<F>; 8], Error> {
+        self.in_region(layouter, "uint64 to bytes", |chip, region, offset| {
+            Ok(chip.blake2b_chip.new_row_from_word(&word.0, region, offset)?.limbs)
+        })
+    }
+
+    /// Bitwise xor of two integers, looked up limb by limb in the xor table.
+    pub fn xor(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        lhs: &AssignedU64<F>,
+        rhs: &AssignedU64<F>,
+    ) -> Result<AssignedU64<F>, Error> {
+        self.in_region(layouter, "uint64 xor", |chip, region, offset| {
+            Ok(AssignedU64(chip.blake2b_chip.xor(&lhs.0, &rhs.0, region, offset)?.full_number))
+        })
+    }
+
+    /// Bitwise and of two integers, looked up limb by limb in the bitwise table.
+    pub fn and(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        lhs: &AssignedU64<F>,
+        rhs: &AssignedU64<F>,
+    ) -> Result<AssignedU64<F>, Error> {
+        self.bitwise(layouter, "uint64 and", BitwiseOperation::And, lhs, rhs)
+    }
+
+    /// Bitwise or of two integers, looked up limb by limb in the bitwise table.
+    pub fn or(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        lhs: &AssignedU64<F>,
+        rhs: &AssignedU64<F>,
+    ) -> Result<AssignedU64<F>, Error> {
+        self.bitwise(layouter, "uint64 or", BitwiseOperation::Or, lhs, rhs)
+    }
+
+    /// Bitwise and of the negation of the first integer and the second one, '!lhs & rhs', looked
+    /// up limb by limb in the bitwise table. This is the operation of the choice functions of
+    /// SHA-2 and of the chi step of Keccak, without the extra rows of a negation.
+    pub fn and_not(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        lhs: &AssignedU64<F>,
+        rhs: &AssignedU64<F>,
+    ) -> Result<AssignedU64<F>, Error> {
+        self.bitwise(layouter, "uint64 and not", BitwiseOperation::AndNot, lhs, rhs)
+    }
+
+    /// Addition of two integers mod 2^64.
+    pub fn add(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        lhs: &AssignedU64<F>,
+        rhs: &AssignedU64<F>,
+    ) -> Result<AssignedU64<F>, Error> {
+        self.in_region(layouter, "uint64 add", |chip, region, offset| {
+            Ok(AssignedU64(chip.blake2b_chip.add(&lhs.0, &rhs.0, region, offset)?.full_number))
+        })
+    }
+
+    /// Bitwise negation of an integer.
+    pub fn not(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        word: &AssignedU64<F>,
+    ) -> Result<AssignedU64<F>, Error> {
+        self.in_region(layouter, "uint64 not", |chip, region, offset| {
+            Ok(AssignedU64(chip.blake2b_chip.not(&word.0, region, offset)?))
+        })
+    }
+
+    /// Bitwise rotation of an integer to the right by any amount smaller than 64 bits. The
+    /// integer is rotated by whole limbs and then by the remaining bits, splitting the limb at the
+    /// boundary of the rotation. The rotation by 63 bits has its own gate.
+    pub fn rotate_right(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        word: &AssignedU64<F>,
+        amount: usize,
+    ) -> Result<AssignedU64<F>, Error> {
+        assert!(amount < 64, "Rotations must be smaller than 64 bits");
+        self.in_region(layouter, "uint64 rotate right", |chip, region, offset| {
+            let row = chip.blake2b_chip.new_row_from_word(&word.0, region, offset)?;
+            if amount == 63 {
+                let rotated = chip.blake2b_chip.rotate_right_63(row.full_number, region, offset)?;
+                return Ok(AssignedU64(rotated));
+            }
+            let rotated = chip.config.limb_rotation_config.generate_rotation_rows_from_input_row(
+                region,
+                offset,
+                row,
+                amount / 8,
+                chip.full_number_u64(),
+                chip.config.limbs,
+            )?;
+            chip.bit_rotation(&rotated, BitShift::RotateRight, amount % 8, region, offset)
+        })
+    }
+
+    /// Bitwise rotation of an integer to the left, which is the rotation to the right by the
+    /// complementary amount.
+    pub fn rotate_left(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        word: &AssignedU64<F>,
+        amount: usize,
+    ) -> Result<AssignedU64<F>, Error> {
+        assert!(amount < 64, "Rotations must be smaller than 64 bits");
+        self.rotate_right(layouter, word, (64 - amount) % 64)
+    }
+
+    /// Logical shift of an integer to the right by any amount smaller than 64 bits. The integer
+    /// is shifted by whole limbs and then by the remaining bits, like in the rotations.
+    pub fn shift_right(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        word: &AssignedU64<F>,
+        amount: usize,
+    ) -> Result<AssignedU64<F>, Error> {
+        assert!(amount < 64, "Shifts must be smaller than 64 bits");
+        self.in_region(layouter, "uint64 shift right", |chip, region, offset| {
+            let row = chip.blake2b_chip.new_row_from_word(&word.0, region, offset)?;
+            let shifted =
+                chip.config.limb_rotation_config.generate_right_shift_rows_from_input_row(
+                    region,
+                    offset,
+                    row,
+                    amount / 8,
+                    chip.full_number_u64(),
+                    chip.config.limbs,
+                )?;
+            chip.bit_rotation(&shifted, BitShift::ShiftRight, amount % 8, region, offset)
+        })
+    }
+
+    /// Shift of an integer to the left by any amount smaller than 64 bits, dropping the bits that
+    /// overflow.
+    pub fn shift_left(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        word: &AssignedU64<F>,
+        amount: usize,
+    ) -> Result<AssignedU64<F>, Error> {
+        assert!(amount < 64, "Shifts must be smaller than 64 bits");
+        self.in_region(layouter, "uint64 shift left", |chip, region, offset| {
+            let row = chip.blake2b_chip.new_row_from_word(&word.0, region, offset)?;
+            let shifted =
+                chip.config.limb_rotation_config.generate_left_shift_rows_from_input_row(
+                    region,
+                    offset,
+                    row,
+                    amount / 8,
+                    chip.full_number_u64(),
+                    chip.config.limbs,
+                )?;
+            chip.bit_rotation(&shifted, BitShift::ShiftLeft, amount % 8, region, offset)
+        })
+    }
+}
+
+impl<F: PrimeField> Uint64Chip<F> {
+    /// Lays out an instruction of the chip in a region of its own.
+    fn in_region<T>(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        name: &str,
+        instruction: impl Fn(&Self, &mut Region<'_, F>, &mut usize) -> Result<T, Error>,
+    ) -> Result<T, Error> {
+        layouter.assign_region(
+            || name,
+            |mut region| {
+                let mut offset: usize = 0;
+                instruction(self, &mut region, &mut offset)
+            },
+        )
+    }
+
+    /// Lays out a bitwise operation of the [BitwiseConfig] in a region of its own.
+    fn bitwise(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        name: &str,
+        operation: BitwiseOperation,
+        lhs: &AssignedU64<F>,
+        rhs: &AssignedU64<F>,
+    ) -> Result<AssignedU64<F>, Error> {
+        self.in_region(layouter, name, |chip, region, offset| {
+            let row = chip
+                .config
+                .bitwise_config
+                .generate_bitwise_rows_from_cells(region, offset, operation, &lhs.0, &rhs.0)?;
+            Ok(AssignedU64(row.full_number))
+        })
+    }
+
+    /// Rotates or shifts the word in the last row of the trace by less than a limb, or returns it
+    /// as is when there are no bits to rotate.
+    fn bit_rotation(
+        &self,
+        word: &AssignedBlake2bWord<F>,
+        operation: BitShift,
+        bits: usize,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+    ) -> Result<AssignedU64<F>, Error> {
+        if bits == 0 {
+            return Ok(AssignedU64(word.clone()));
+        }
+        let rotated = self.config.bit_rotation_config.generate_bit_rotation_row_from_input(
+            region,
+            offset,
+            word,
+            operation,
+            bits,
+            self.full_number_u64(),
+            self.config.limbs,
+        )?;
+        Ok(AssignedU64(rotated))
+    }
+
+    /// Assigns the given bytes, known at circuit building time, as constants in the limb columns,
+    /// like [Blake2bChip::assign_constant_bytes] does.
+    pub(crate) fn assign_constant_bytes(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        bytes: &[u8],
+    ) -> Result<Vec<AssignedNative<F>>, Error> {
+        self.blake2b_chip.assign_constant_bytes(layouter, bytes)
+    }
+
+    fn full_number_u64(&self) -> Column<Advice> {
+        self.config.blake2b_config.full_number_u64
+    }
+}
@@ -1,6 +1,7 @@
 //! This is an example circuit of how you should use the Blake2b chip
 
 use crate::blake2b::blake2b_chip::{Blake2bChip, Blake2bConfig};
+use crate::blake2b::packing::DigestPacking;
 use crate::types::AssignedNative;
 use ff::PrimeField;
 use midnight_proofs::circuit::{Layouter, SimpleFloorPlanner, Value};
@@ -18,11 +19,13 @@ pub struct Blake2bCircuit<F: PrimeField> {
     input_size: usize,
     key_size: usize,
     output_size: usize,
+    /// Encoding of the digest in the public inputs.
+    digest_packing: DigestPacking,
 }
 
 impl<F: PrimeField> Circuit<F> for Blake2bCircuit<F> {
     type Config = (Blake2bConfig, Column<Instance>);
-    type Params = ();
+    type Params = DigestPacking;
     type FloorPlanner = SimpleFloorPlanner;
 
     fn without_witnesses(&self) -> Self {
@@ -35,16 +38,33 @@ impl<F: PrimeField> Circuit<F> for Blake2bCircuit<F> {
             key: vec![Value::unknown(); key_size],
             key_size,
             output_size,
+            digest_packing: self.digest_packing,
         }
     }
 
+    fn params(&self) -> Self::Params {
+        self.digest_packing
+    }
+
     fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        Self::configure_with_params(meta, DigestPacking::default())
+    }
+
+    // The gate that packs words into field elements is only added to the circuits that need it
+    fn configure_with_params(
+        meta: &mut ConstraintSystem<F>,
+        digest_packing: Self::Params,
+    ) -> Self::Config {
         let full_number_u64 = meta.advice_column();
         let limbs: [Column<Advice>; 8] = array::from_fn(|_| meta.advice_column());
         let constant_col = meta.fixed_column();
         let expected_final_state = meta.instance_column();
         meta.enable_equality(expected_final_state);
-        (Blake2bChip::configure(meta, constant_col, full_number_u64, limbs), expected_final_state)
+        let mut config = Blake2bChip::configure(meta, constant_col, full_number_u64, limbs);
+        if digest_packing == DigestPacking::FieldElements {
+            config = Blake2bChip::configure_digest_packing(meta, &config);
+        }
+        (config, expected_final_state)
     }
 
     fn synthesize(
@@ -64,11 +84,14 @@ impl<F: PrimeField> Circuit<F> for Blake2bCircuit<F> {
         // Initialising the chip and calling the hash.
         let chip = Blake2bChip::new(&config.0);
         chip.load(&mut layouter)?;
-        let result = chip.hash(&mut layouter, &assigned_input, &assigned_key, self.output_size)?;
+        let result =
+            chip.hash(&mut layouter, &assigned_input, &assigned_key, self.output_size, None, None)?;
 
-        // Assert results
-        for (i, global_state_byte_cell) in result.iter().enumerate().take(self.output_size) {
-            layouter.constrain_instance(global_state_byte_cell.cell(), config.1, i)?;
+        // Assert results, packed as chosen for the public inputs
+        let packed_result =
+            chip.pack_digest(&mut layouter, &result[..self.output_size], self.digest_packing)?;
+        for (i, packed_result_cell) in packed_result.iter().enumerate() {
+            layouter.constrain_instance(packed_result_cell.cell(), config.1, i)?;
         }
         Ok(())
     }
@@ -89,12 +112,20 @@ impl<F: PrimeField> Blake2bCircuit<F> {
             key,
             key_size,
             output_size,
+            digest_packing: DigestPacking::Bytes,
         }
     }
 
+    /// Sets how the digest is packed in the public inputs. By default, every byte of the digest
+    /// is a public input, see [DigestPacking].
+    pub fn with_digest_packing(mut self, digest_packing: DigestPacking) -> Self {
+        self.digest_packing = digest_packing;
+        self
+    }
+
     /// Here the inputs are stored in the trace. It doesn't really matter how they're stored, this
     /// specific circuit uses the limb columns to do it but that's arbitrary.
-    fn assign_inputs_to_the_trace(
+    pub(crate) fn assign_inputs_to_the_trace(
         config: Blake2bConfig,
         layouter: &mut impl Layouter<F>,
         input: &[Value<F>],
@@ -1,71 +1,116 @@
 //! Circuit runner module for creating Blake2bCircuit, synthesizing, proving and verifying it.
-//! It can work with both Mock Prover and Real Prover.
+//! It can work with both Mock Prover and Real Prover, over any pairing-friendly curve whose scalar
+//! field is supported by the chip. [CircuitRunner] works over BLS12-381 and `Bn256CircuitRunner`
+//! over BN254, with the `dev-curves` feature.
 
-use midnight_proofs::dev::MockProver;
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::marker::PhantomData;
+use blake2b_simd::State as TranscriptHashState;
+use ff::{FromUniformBytes, WithSmallOrderMulGroup};
 use midnight_curves::bls12_381::{Bls12, Fq};
+#[cfg(feature = "dev-curves")]
+use midnight_curves::bn256::Bn256;
+use midnight_curves::pairing::MultiMillerLoop;
+use midnight_curves::{CurveAffine, CurveExt};
+use midnight_proofs::dev::MockProver;
 use midnight_proofs::{
     plonk::{create_proof, keygen_pk, keygen_vk_with_k, prepare, ProvingKey, VerifyingKey},
     poly::{
         commitment::Guard,
         kzg::{params::ParamsKZG, KZGCommitmentScheme},
     },
-    transcript::{CircuitTranscript, Transcript},
+    transcript::{CircuitTranscript, Hashable, Sampleable, Transcript},
+    utils::helpers::ProcessedSerdeObject,
 };
 use midnight_proofs::circuit::Value;
 use midnight_proofs::plonk::Error;
+use crate::blake2b::packing::DigestPacking;
 use crate::usage_utils::blake2b_circuit::Blake2bCircuit;
 
 /// The inputs for the Blake2bCircuit. This helps us to avoid passing multiple parameters to the
 /// methods that create circuits
-pub type Blake2bCircuitInputs = (Vec<Value<Fq>>, usize, Vec<Value<Fq>>, usize, [Fq; 64], usize);
+pub type Blake2bCircuitInputs<F = Fq> =
+    (Vec<Value<F>>, usize, Vec<Value<F>>, usize, [F; 64], usize);
 
-/// Circuit runner struct
+/// Circuit runner struct, generic over the pairing engine used by the KZG commitment scheme. The
+/// circuit is built over the scalar field of the engine.
 #[derive(Debug)]
-pub struct CircuitRunner;
+pub struct KzgCircuitRunner<E>(PhantomData<E>);
+
+/// Circuit runner over BLS12-381
+pub type CircuitRunner = KzgCircuitRunner<Bls12>;
+
+/// Circuit runner over BN254, which needs the `dev-curves` feature
+#[cfg(feature = "dev-curves")]
+pub type Bn256CircuitRunner = KzgCircuitRunner<Bn256>;
 
 /// Circuit runner methods for Mock Prover
-impl CircuitRunner {
+impl<E: MultiMillerLoop> KzgCircuitRunner<E>
+where
+    E::Fr: FromUniformBytes<64> + Ord,
+{
     /// Preprocess inputs, synthesize, prove and verify the circuit using Mock Prover
     pub fn mocked_preprocess_inputs_synthesize_prove_and_verify(
         input: &String,
         key: &String,
         expected: &String,
+    ) {
+        Self::mocked_preprocess_inputs_synthesize_prove_and_verify_with_digest_packing(
+            input,
+            key,
+            expected,
+            DigestPacking::Bytes,
+        )
+    }
+
+    /// Same as [KzgCircuitRunner::mocked_preprocess_inputs_synthesize_prove_and_verify], but the
+    /// digest is published with the given [DigestPacking]
+    pub fn mocked_preprocess_inputs_synthesize_prove_and_verify_with_digest_packing(
+        input: &String,
+        key: &String,
+        expected: &String,
+        digest_packing: DigestPacking,
     ) {
         let circuit_inputs = Self::prepare_parameters_for_test(input, key, expected);
+        let expected_output_fields = Self::packed_output_for(expected, digest_packing);
 
-        let circuit = Self::create_circuit_for_packed_inputs(circuit_inputs.clone());
-        let prover = Self::mock_prove_with_public_inputs_ref(&circuit_inputs.4, &circuit);
+        let circuit = Self::create_circuit_for_packed_inputs(circuit_inputs)
+            .with_digest_packing(digest_packing);
+        let prover = Self::mock_prove_with_public_inputs_ref(&expected_output_fields, &circuit);
         Self::verify_mock_prover(prover);
     }
 
     /// Verify the circuit using Mock Prover
-    pub fn verify_mock_prover(prover: MockProver<Fq>) {
+    pub fn verify_mock_prover(prover: MockProver<E::Fr>) {
         prover.verify().unwrap()
     }
 
     /// Create and run the Mock Prover using public inputs
     pub fn mock_prove_with_public_inputs_ref(
-        expected_output_fields: &[Fq],
-        circuit: &Blake2bCircuit<Fq>,
-    ) -> MockProver<Fq> {
+        expected_output_fields: &[E::Fr],
+        circuit: &Blake2bCircuit<E::Fr>,
+    ) -> MockProver<E::Fr> {
         MockProver::run(17, circuit, vec![expected_output_fields.to_vec()]).unwrap()
     }
 
     /// Create circuit for the given inputs
     pub fn create_circuit_for_inputs(
-        input_values: Vec<Value<Fq>>,
+        input_values: Vec<Value<E::Fr>>,
         input_size: usize,
-        key_values: Vec<Value<Fq>>,
+        key_values: Vec<Value<E::Fr>>,
         key_size: usize,
         output_size: usize,
-    ) -> Blake2bCircuit<Fq> {
-        Blake2bCircuit::<Fq>::new(input_values, input_size, key_values, key_size, output_size)
+    ) -> Blake2bCircuit<E::Fr> {
+        Blake2bCircuit::<E::Fr>::new(input_values, input_size, key_values, key_size, output_size)
     }
 
     /// Create circuit for the given inputs. In this function the inputs are packed in a
     /// Blake2bCircuitInputs struct to avoid passing multiple parameters to the function
-    pub fn create_circuit_for_packed_inputs(ci: Blake2bCircuitInputs) -> Blake2bCircuit<Fq> {
-        Blake2bCircuit::<Fq>::new(ci.0, ci.1, ci.2, ci.3, ci.5)
+    pub fn create_circuit_for_packed_inputs(
+        ci: Blake2bCircuitInputs<E::Fr>,
+    ) -> Blake2bCircuit<E::Fr> {
+        Blake2bCircuit::<E::Fr>::new(ci.0, ci.1, ci.2, ci.3, ci.5)
     }
 
     /// Convert the input, key and expected output in byte blocks
@@ -75,18 +120,18 @@ impl CircuitRunner {
         input: &String,
         key: &String,
         expected: &String,
-    ) -> Blake2bCircuitInputs {
+    ) -> Blake2bCircuitInputs<E::Fr> {
         // INPUT
         let input_size = input.len() / 2; // Amount of bytes
         let input_bytes = hex::decode(input).expect("Invalid hex string");
         let input_values =
-            input_bytes.iter().map(|x| Value::known(Fq::from(*x as u64))).collect::<Vec<_>>();
+            input_bytes.iter().map(|x| Value::known(E::Fr::from(*x as u64))).collect::<Vec<_>>();
 
         // OUTPUT
         let (expected_output, output_size) = Self::formed_output_block_for(expected);
-        let expected_output_fields: [Fq; 64] = expected_output
+        let expected_output_fields: [E::Fr; 64] = expected_output
             .iter()
-            .map(|x| Fq::from(*x as u64))
+            .map(|x| E::Fr::from(*x as u64))
             .collect::<Vec<_>>()
             .try_into()
             .unwrap();
@@ -95,11 +140,18 @@ impl CircuitRunner {
         let key_size = key.len() / 2; // Amount of bytes
         let key_bytes = hex::decode(key).expect("Invalid hex string");
         let key_values =
-            key_bytes.iter().map(|x| Value::known(Fq::from(*x as u64))).collect::<Vec<_>>();
+            key_bytes.iter().map(|x| Value::known(E::Fr::from(*x as u64))).collect::<Vec<_>>();
 
         (input_values, input_size, key_values, key_size, expected_output_fields, output_size)
     }
 
+    /// Convert the expected output of the circuit in the public inputs of its digest, packed with
+    /// the given [DigestPacking]
+    pub fn packed_output_for(output: &String, digest_packing: DigestPacking) -> Vec<E::Fr> {
+        let output_bytes = hex::decode(output).expect("Invalid hex string");
+        digest_packing.pack(&output_bytes)
+    }
+
     /// Convert the expected output of the circuit in byte blocks
     pub fn formed_output_block_for(output: &String) -> ([u8; 64], usize) {
         let output_block_size = output.len() / 2; // Amount of bytes
@@ -109,49 +161,80 @@ impl CircuitRunner {
 }
 
 /// Circuit runner methods for Real Prover
-impl CircuitRunner {
+impl<E> KzgCircuitRunner<E>
+where
+    E: MultiMillerLoop + Debug,
+    E::Fr: WithSmallOrderMulGroup<3>
+        + FromUniformBytes<64>
+        + Ord
+        + Hash
+        + Hashable<TranscriptHashState>
+        + Sampleable<TranscriptHashState>,
+    E::G1: Default
+        + CurveExt<ScalarExt = E::Fr>
+        + ProcessedSerdeObject
+        + Hashable<TranscriptHashState>,
+    E::G1Affine: Default + CurveAffine<ScalarExt = E::Fr, CurveExt = E::G1>,
+{
     /// Preprocess inputs, synthesize, prove and verify the circuit using a real prover
     pub fn real_preprocess_inputs_synthesize_prove_and_verify(
         input: String,
         out: String,
         key: String,
+    ) -> Result<(), Error> {
+        Self::real_preprocess_inputs_synthesize_prove_and_verify_with_digest_packing(
+            input,
+            out,
+            key,
+            DigestPacking::Bytes,
+        )
+    }
+
+    /// Same as [KzgCircuitRunner::real_preprocess_inputs_synthesize_prove_and_verify], but the
+    /// digest is published with the given [DigestPacking]
+    pub fn real_preprocess_inputs_synthesize_prove_and_verify_with_digest_packing(
+        input: String,
+        out: String,
+        key: String,
+        digest_packing: DigestPacking,
     ) -> Result<(), Error> {
         let circuit_inputs = Self::prepare_parameters_for_test(&input, &key, &out);
+        let expected_output_fields = Self::packed_output_for(&out, digest_packing);
 
-        let circuit: Blake2bCircuit<Fq> =
-            Self::create_circuit_for_packed_inputs(circuit_inputs.clone());
+        let circuit: Blake2bCircuit<E::Fr> = Self::create_circuit_for_packed_inputs(circuit_inputs)
+            .with_digest_packing(digest_packing);
 
-        let params = ParamsKZG::<Bls12>::unsafe_setup(17, &mut rand::thread_rng());
-        let vk: VerifyingKey<Fq, KZGCommitmentScheme<Bls12>> = Self::create_vk(&circuit, &params);
-        let pk: ProvingKey<Fq, KZGCommitmentScheme<Bls12>> = Self::create_pk(&circuit, vk);
-        let proof = Self::create_proof(&circuit_inputs.4, circuit, &params, &pk);
-        Self::verify(&circuit_inputs.4, &params, pk, &proof)
+        let params = ParamsKZG::<E>::unsafe_setup(17, &mut rand::thread_rng());
+        let vk: VerifyingKey<E::Fr, KZGCommitmentScheme<E>> = Self::create_vk(&circuit, &params);
+        let pk: ProvingKey<E::Fr, KZGCommitmentScheme<E>> = Self::create_pk(&circuit, vk);
+        let proof = Self::create_proof(&expected_output_fields, circuit, &params, &pk);
+        Self::verify(&expected_output_fields, &params, pk, &proof)
     }
 
     /// Create the verifying key for the given circuit and parameters
     pub fn create_vk(
-        circuit: &Blake2bCircuit<Fq>,
-        params: &ParamsKZG<Bls12>,
-    ) -> VerifyingKey<Fq, KZGCommitmentScheme<Bls12>> {
+        circuit: &Blake2bCircuit<E::Fr>,
+        params: &ParamsKZG<E>,
+    ) -> VerifyingKey<E::Fr, KZGCommitmentScheme<E>> {
         keygen_vk_with_k(params, circuit, 17).expect("Verifying key should be created")
     }
 
     /// Create the proving key for the given circuit and parameters
     pub fn create_pk(
-        circuit: &Blake2bCircuit<Fq>,
-        vk: VerifyingKey<Fq, KZGCommitmentScheme<Bls12>>,
-    ) -> ProvingKey<Fq, KZGCommitmentScheme<Bls12>> {
+        circuit: &Blake2bCircuit<E::Fr>,
+        vk: VerifyingKey<E::Fr, KZGCommitmentScheme<E>>,
+    ) -> ProvingKey<E::Fr, KZGCommitmentScheme<E>> {
         keygen_pk(vk.clone(), circuit).expect("Proving key should be created")
     }
 
     /// Create the proof for the given circuit and parameters
     pub fn create_proof(
-        expected_output_fields: &[Fq],
-        circuit: Blake2bCircuit<Fq>,
-        params: &ParamsKZG<Bls12>,
-        pk: &ProvingKey<Fq, KZGCommitmentScheme<Bls12>>,
+        expected_output_fields: &[E::Fr],
+        circuit: Blake2bCircuit<E::Fr>,
+        params: &ParamsKZG<E>,
+        pk: &ProvingKey<E::Fr, KZGCommitmentScheme<E>>,
     ) -> Vec<u8> {
-        let mut transcript = CircuitTranscript::init();
+        let mut transcript = CircuitTranscript::<TranscriptHashState>::init();
         create_proof(
             params,
             pk,
@@ -167,14 +250,14 @@ impl CircuitRunner {
 
     /// Verify the proof for the given circuit and parameters
     pub fn verify(
-        expected_output_fields: &[Fq],
-        params: &ParamsKZG<Bls12>,
-        pk: ProvingKey<Fq, KZGCommitmentScheme<Bls12>>,
+        expected_output_fields: &[E::Fr],
+        params: &ParamsKZG<E>,
+        pk: ProvingKey<E::Fr, KZGCommitmentScheme<E>>,
         proof: &[u8],
     ) -> Result<(), Error> {
-        let mut transcript = CircuitTranscript::init_from_bytes(proof);
+        let mut transcript = CircuitTranscript::<TranscriptHashState>::init_from_bytes(proof);
 
-        assert!(prepare::<Fq, KZGCommitmentScheme<Bls12>, _>(
+        assert!(prepare::<E::Fr, KZGCommitmentScheme<E>, _>(
             pk.get_vk(),
             &[&[]],
             &[&[expected_output_fields]],